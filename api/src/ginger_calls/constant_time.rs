@@ -0,0 +1,56 @@
+use super::serialization::serialize_to_buffer;
+use algebra::serialize::*;
+use std::ops::{Deref, DerefMut};
+use subtle::ConstantTimeEq;
+
+/// Byte buffer (e.g. `Vec<u8>` or `[u8; N]`) overwritten with zeros when dropped, to hold
+/// secret data such as serialized secret keys and seeds.
+pub struct Zeroizing<T: AsMut<[u8]>>(T);
+
+impl<T: AsMut<[u8]>> Zeroizing<T> {
+    pub fn new(bytes: T) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<T: AsMut<[u8]>> Deref for Zeroizing<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: AsMut<[u8]>> DerefMut for Zeroizing<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: AsMut<[u8]>> Drop for Zeroizing<T> {
+    fn drop(&mut self) {
+        // Volatile writes prevent the compiler from optimizing away the erasure
+        for b in self.0.as_mut().iter_mut() {
+            unsafe { std::ptr::write_volatile(b, 0) };
+        }
+    }
+}
+
+/// Compare `a` and `b` in constant time with respect to their content.
+/// Both objects are serialized (uncompressed) and the resulting byte sequences are compared
+/// without short-circuiting on the first differing byte, so that the execution time doesn't
+/// leak the length of their common prefix.
+/// To be used for secret data (e.g. secret keys and signatures) in place of `PartialEq`.
+/// The serialized copies are erased before returning.
+pub fn ct_eq<T: CanonicalSerialize>(a: &T, b: &T) -> Result<bool, SerializationError> {
+    let a_bytes = Zeroizing::new(serialize_to_buffer(a, None)?);
+    let b_bytes = Zeroizing::new(serialize_to_buffer(b, None)?);
+
+    Ok(ct_eq_bytes(a_bytes.as_slice(), b_bytes.as_slice()))
+}
+
+/// Compare `a` and `b` in constant time with respect to their content.
+/// Slices of different length are considered different: in this case only the length is leaked.
+pub fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    bool::from(a.ct_eq(b))
+}
@@ -0,0 +1,42 @@
+//! Registry of the domain separation tags used across the crate as first element of a
+//! Poseidon personalization, or of a domain tag given to `schnorr_sign_with_domain_tag`.
+//! All the tags live in this module so that no two hashes share the same one: always pick
+//! a new value when adding a tag. Values are part of the hashed data: never change them.
+
+/// Hash of a Schnorr message made of multiple field elements
+pub const SCHNORR_FIELD_ELEMENTS_MSG_DOMAIN_TAG: u64 = 1;
+/// Hash of a Schnorr message made of bytes
+pub const SCHNORR_BYTES_MSG_DOMAIN_TAG: u64 = 2;
+/// Challenge of a Schnorr signature made under a domain tag
+pub const SCHNORR_CUSTOM_DOMAIN_CHALLENGE_DOMAIN_TAG: u64 = 3;
+/// Hash of a VRF message made of multiple field elements
+pub const VRF_FIELD_ELEMENTS_MSG_DOMAIN_TAG: u64 = 4;
+/// Hash of a VRF message made of bytes
+pub const VRF_BYTES_MSG_DOMAIN_TAG: u64 = 5;
+/// Schnorr domain tag derived from bytes
+pub const SCHNORR_BYTES_DOMAIN_TAG_DOMAIN_TAG: u64 = 6;
+/// Absorption of a VRF output into the randomness beacon accumulator
+pub const BEACON_ABSORB_DOMAIN_TAG: u64 = 7;
+/// Derivation of the nonce of the next randomness beacon epoch
+pub const BEACON_EPOCH_DOMAIN_TAG: u64 = 8;
+/// Schnorr domain tag of key rotation certificates
+pub const KEY_ROTATION_DOMAIN_TAG: u64 = 9;
+/// Schnorr domain tag of forward secure signatures
+pub const FORWARD_SECURE_DOMAIN_TAG: u64 = 10;
+/// VRF output derived with a label
+pub const VRF_OUTPUT_LABEL_DOMAIN_TAG: u64 = 11;
+
+/// All the tags above, to check they are distinct
+pub const ALL_DOMAIN_TAGS: [u64; 11] = [
+    SCHNORR_FIELD_ELEMENTS_MSG_DOMAIN_TAG,
+    SCHNORR_BYTES_MSG_DOMAIN_TAG,
+    SCHNORR_CUSTOM_DOMAIN_CHALLENGE_DOMAIN_TAG,
+    VRF_FIELD_ELEMENTS_MSG_DOMAIN_TAG,
+    VRF_BYTES_MSG_DOMAIN_TAG,
+    SCHNORR_BYTES_DOMAIN_TAG_DOMAIN_TAG,
+    BEACON_ABSORB_DOMAIN_TAG,
+    BEACON_EPOCH_DOMAIN_TAG,
+    KEY_ROTATION_DOMAIN_TAG,
+    FORWARD_SECURE_DOMAIN_TAG,
+    VRF_OUTPUT_LABEL_DOMAIN_TAG,
+];
@@ -24,6 +24,17 @@ pub fn read_field_element_from_buffer_with_padding<F: PrimeField>(
     algebra::serialize::CanonicalDeserialize::deserialize(new_buffer.as_slice())
 }
 
+/// Pack `bytes` into field elements, FIELD_CAPACITY / 8 bytes at time.
+/// The last chunk, if shorter, is padded with zeros.
+pub fn read_field_elements_from_bytes(
+    bytes: &[u8],
+) -> Result<Vec<FieldElement>, SerializationError> {
+    bytes
+        .chunks(FIELD_CAPACITY / 8)
+        .map(read_field_element_from_buffer_with_padding::<FieldElement>)
+        .collect()
+}
+
 //*******************************Generic functions**********************************************
 
 pub fn get_secure_random_field_element() -> FieldElement {
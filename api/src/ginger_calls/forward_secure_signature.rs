@@ -0,0 +1,564 @@
+use super::{
+    constant_time::Zeroizing, domain_tags::*, merkle_tree::*, poseidon_hash::*,
+    schnorr_signature::*, serialization::DeserializationChecks, *,
+};
+use algebra::{
+    serialize::*, AffineCurve, Field, SemanticallyValid, ToConstraintField, UniformRand,
+};
+use blake2s_simd::Params as Blake2sParams;
+use primitives::merkle_tree::*;
+use rand::{rngs::OsRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use std::fmt;
+
+/// BLAKE2s personalization used to derive the seed of the next period from the current one
+const FORWARD_SECURE_NEXT_SEED_PERSONALIZATION: &[u8; 8] = b"ZenFsNxt";
+/// BLAKE2s personalization used to derive the Schnorr secret key of a period from its seed
+const FORWARD_SECURE_PERIOD_SK_PERSONALIZATION: &[u8; 8] = b"ZenFsSk_";
+
+/// Maximum height of the tree of period keys, i.e. at most 2^20 periods
+pub const FORWARD_SECURE_MAX_HEIGHT: u8 = 20;
+
+pub const FORWARD_SECURE_SEED_SIZE: usize = 32;
+
+pub const FORWARD_SECURE_PK_SIZE: usize = FIELD_SIZE + 1;
+
+/// Public key of the forward secure signature scheme: the root of a Poseidon Merkle tree
+/// whose i-th leaf is the hash of the Schnorr public key of period i.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardSecurePk {
+    pub root: FieldElement,
+    pub height: u8,
+}
+
+/// Secret key of the forward secure signature scheme at `period`.
+/// Only the seed of the current period is kept: the seed of the next period is derived
+/// from it through a one-way function, and overwrites it on update, thus the secret keys
+/// of the past periods can't be recovered from the current secret key.
+/// The root of the tree of period keys and the siblings of the Merkle path of the current
+/// period, both public, are kept as well, so that signing costs O(height) hashes. They are
+/// updated together with the seed.
+/// Neither `Clone` nor `PartialEq` are implemented, so that the seed isn't copied around:
+/// compare secret keys with `ct_eq`.
+pub struct ForwardSecureSk {
+    pub period: u64,
+    pub height: u8,
+    seed: Zeroizing<[u8; FORWARD_SECURE_SEED_SIZE]>,
+    pub root: FieldElement,
+    /// Siblings of the Merkle path of the leaf of `period`, from the leaf to the root
+    pub auth_path: Vec<FieldElement>,
+}
+
+impl fmt::Debug for ForwardSecureSk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ForwardSecureSk")
+            .field("period", &self.period)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+/// Signature of the forward secure signature scheme: a Schnorr signature under the key of
+/// `period`, together with the Merkle path proving that the key belongs to the tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardSecureSig {
+    pub period: u64,
+    pub period_pk: SchnorrPk,
+    pub signature: SchnorrSig,
+    pub path: GingerMHTPath,
+}
+
+impl CanonicalSerialize for ForwardSecurePk {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.root, &mut writer)?;
+        CanonicalSerialize::serialize(&self.height, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.root.serialized_size() + self.height.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize(writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        self.serialized_size()
+    }
+}
+
+impl CanonicalDeserialize for ForwardSecurePk {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let root = CanonicalDeserialize::deserialize(&mut reader)?;
+        let height = CanonicalDeserialize::deserialize(&mut reader)?;
+        Ok(Self { root, height })
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+}
+
+impl SemanticallyValid for ForwardSecurePk {
+    fn is_valid(&self) -> bool {
+        self.root.is_valid() && self.height > 0 && self.height <= FORWARD_SECURE_MAX_HEIGHT
+    }
+}
+
+impl DeserializationChecks for ForwardSecurePk {}
+
+impl CanonicalSerialize for ForwardSecureSk {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.period, &mut writer)?;
+        CanonicalSerialize::serialize(&self.height, &mut writer)?;
+        writer.write_all(&self.seed[..])?;
+        CanonicalSerialize::serialize(&self.root, &mut writer)?;
+        CanonicalSerialize::serialize(&self.auth_path, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.period.serialized_size()
+            + self.height.serialized_size()
+            + FORWARD_SECURE_SEED_SIZE
+            + self.root.serialized_size()
+            + self.auth_path.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize(writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        self.serialized_size()
+    }
+}
+
+impl CanonicalDeserialize for ForwardSecureSk {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let period = CanonicalDeserialize::deserialize(&mut reader)?;
+        let height = CanonicalDeserialize::deserialize(&mut reader)?;
+        // The seed is erased on failure as well
+        let mut seed = Zeroizing::new([0u8; FORWARD_SECURE_SEED_SIZE]);
+        reader.read_exact(&mut seed[..])?;
+        let root = CanonicalDeserialize::deserialize(&mut reader)?;
+        let auth_path = CanonicalDeserialize::deserialize(&mut reader)?;
+        Ok(Self {
+            period,
+            height,
+            seed,
+            root,
+            auth_path,
+        })
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+}
+
+impl SemanticallyValid for ForwardSecureSk {
+    /// Besides the bounds, check that the root is the one reached from the key of the
+    /// current period through the stored path.
+    fn is_valid(&self) -> bool {
+        self.height > 0
+            && self.height <= FORWARD_SECURE_MAX_HEIGHT
+            && forward_secure_num_periods(self.height)
+                .map(|num_periods| self.period < num_periods)
+                .unwrap_or(false)
+            && self.auth_path.len() == self.height as usize
+            && self.root.is_valid()
+            && self.auth_path.is_valid()
+            && period_leaf(&self.seed)
+                .map(|leaf| get_root_from_path(&current_path(self), &leaf) == self.root)
+                .unwrap_or(false)
+    }
+}
+
+impl DeserializationChecks for ForwardSecureSk {}
+
+impl CanonicalSerialize for ForwardSecureSig {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.period, &mut writer)?;
+        CanonicalSerialize::serialize(&self.period_pk, &mut writer)?;
+        CanonicalSerialize::serialize(&self.signature, &mut writer)?;
+        CanonicalSerialize::serialize(&self.path, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.period.serialized_size()
+            + self.period_pk.serialized_size()
+            + self.signature.serialized_size()
+            + self.path.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.period, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&self.period_pk, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&self.signature, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&self.path, &mut writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        self.period.serialized_size()
+            + self.period_pk.uncompressed_size()
+            + self.signature.uncompressed_size()
+            + self.path.uncompressed_size()
+    }
+}
+
+impl CanonicalDeserialize for ForwardSecureSig {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let period = CanonicalDeserialize::deserialize(&mut reader)?;
+        let period_pk = CanonicalDeserialize::deserialize(&mut reader)?;
+        let signature = CanonicalDeserialize::deserialize(&mut reader)?;
+        let path = CanonicalDeserialize::deserialize(&mut reader)?;
+        Ok(Self {
+            period,
+            period_pk,
+            signature,
+            path,
+        })
+    }
+
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let period = CanonicalDeserialize::deserialize(&mut reader)?;
+        let period_pk = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        let signature = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        let path = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        Ok(Self {
+            period,
+            period_pk,
+            signature,
+            path,
+        })
+    }
+
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let period = CanonicalDeserialize::deserialize(&mut reader)?;
+        let period_pk = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let signature = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let path = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        Ok(Self {
+            period,
+            period_pk,
+            signature,
+            path,
+        })
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(
+        mut reader: R,
+    ) -> Result<Self, SerializationError> {
+        let period = CanonicalDeserialize::deserialize(&mut reader)?;
+        let period_pk = CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?;
+        let signature = CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?;
+        let path = CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?;
+        Ok(Self {
+            period,
+            period_pk,
+            signature,
+            path,
+        })
+    }
+}
+
+impl SemanticallyValid for ForwardSecureSig {
+    fn is_valid(&self) -> bool {
+        self.period_pk.is_valid() && self.signature.is_valid() && self.path.is_valid()
+    }
+}
+
+impl DeserializationChecks for ForwardSecureSig {}
+
+fn blake2s_with_personalization(
+    personalization: &[u8; 8],
+    input: &[u8],
+) -> [u8; FORWARD_SECURE_SEED_SIZE] {
+    let mut output = [0u8; FORWARD_SECURE_SEED_SIZE];
+    output.copy_from_slice(
+        Blake2sParams::new()
+            .hash_length(FORWARD_SECURE_SEED_SIZE)
+            .personal(personalization)
+            .hash(input)
+            .as_bytes(),
+    );
+    output
+}
+
+/// Replace `seed` with the seed of the next period, erasing the current one.
+fn evolve_seed(seed: &mut [u8; FORWARD_SECURE_SEED_SIZE]) {
+    let next_seed = Zeroizing::new(blake2s_with_personalization(
+        FORWARD_SECURE_NEXT_SEED_PERSONALIZATION,
+        seed,
+    ));
+    seed.copy_from_slice(&next_seed[..]);
+}
+
+/// Derive the Schnorr key pair of the period whose seed is `seed`.
+fn period_key_pair(seed: &[u8; FORWARD_SECURE_SEED_SIZE]) -> (SchnorrPk, SchnorrSk) {
+    let rng_seed = Zeroizing::new(blake2s_with_personalization(
+        FORWARD_SECURE_PERIOD_SK_PERSONALIZATION,
+        seed,
+    ));
+    let sk = SchnorrSk::rand(&mut ChaCha20Rng::from_seed(*rng_seed));
+    (schnorr_get_public_key(&sk), sk)
+}
+
+/// Leaf of the tree of period keys corresponding to `period_pk`, i.e. H(period_pk.x, period_pk.y).
+fn period_pk_to_leaf(period_pk: &SchnorrPk) -> Result<FieldElement, Error> {
+    let coords = period_pk.into_projective().to_field_elements()?;
+    compute_poseidon_hash(coords.as_slice(), None)
+}
+
+/// Leaf of the tree of period keys for the period whose seed is `seed`.
+fn period_leaf(seed: &[u8; FORWARD_SECURE_SEED_SIZE]) -> Result<FieldElement, Error> {
+    let (period_pk, _) = period_key_pair(seed);
+    period_pk_to_leaf(&period_pk)
+}
+
+fn build_period_keys_tree(height: usize, leaves: &[FieldElement]) -> Result<GingerMHT, Error> {
+    let mut tree = new_ginger_mht(height, leaves.len())?;
+    for leaf in leaves {
+        append_leaf_to_ginger_mht(&mut tree, leaf)?;
+    }
+    finalize_ginger_mht_in_place(&mut tree)?;
+    Ok(tree)
+}
+
+/// Root of the subtree whose leaves are `leaves`, 2^height of them.
+fn subtree_root(height: usize, leaves: &[FieldElement]) -> Result<FieldElement, Error> {
+    if height == 0 {
+        return Ok(leaves[0]);
+    }
+    get_ginger_mht_root(&build_period_keys_tree(height, leaves)?)
+}
+
+/// Merkle path of the first `len` levels of the leaf of the current period of `sk`.
+fn path_prefix(sk: &ForwardSecureSk, len: usize) -> GingerMHTPath {
+    GingerMHTPath::new(
+        sk.auth_path[..len]
+            .iter()
+            .enumerate()
+            .map(|(level, sibling)| (*sibling, (sk.period >> level) & 1 == 1))
+            .collect(),
+    )
+}
+
+/// Merkle path of the leaf of the current period of `sk`.
+fn current_path(sk: &ForwardSecureSk) -> GingerMHTPath {
+    path_prefix(sk, sk.auth_path.len())
+}
+
+/// Roots of the consecutive subtrees of 1, 2, ..., 2^(k - 1) leaves following the leaf whose
+/// seed is `seed`. `seed` is evolved in place: the caller must erase it.
+fn right_subtree_roots(
+    seed: &mut [u8; FORWARD_SECURE_SEED_SIZE],
+    k: usize,
+) -> Result<Vec<FieldElement>, Error> {
+    let mut roots = Vec::with_capacity(k);
+    for level in 0..k {
+        let mut leaves = Vec::with_capacity(1 << level);
+        for _ in 0..1usize << level {
+            evolve_seed(seed);
+            leaves.push(period_leaf(seed)?);
+        }
+        roots.push(subtree_root(level, leaves.as_slice())?);
+    }
+    Ok(roots)
+}
+
+/// Move `sk` to the next period, updating its path: if the current period ends with k
+/// ones, the node at level k containing the current leaf becomes the left sibling at
+/// level k, while the siblings below it become the roots of the subtrees right after
+/// the new leaf, of 1, 2, ..., 2^(k - 1) leaves, derived from the following seeds.
+/// The cost is O(2^k) key derivations, i.e. O(height) on average over all the periods.
+fn next_period(sk: &mut ForwardSecureSk) -> Result<(), Error> {
+    let k = sk.period.trailing_ones() as usize;
+
+    let leaf = period_leaf(&sk.seed)?;
+    let left_sibling = get_root_from_path(&path_prefix(sk, k), &leaf);
+
+    let mut new_auth_path = sk.auth_path.clone();
+    new_auth_path[k] = left_sibling;
+
+    evolve_seed(&mut sk.seed);
+
+    let mut seed = Zeroizing::new(*sk.seed);
+    for (level, root) in right_subtree_roots(&mut seed, k)?.into_iter().enumerate() {
+        new_auth_path[level] = root;
+    }
+
+    sk.auth_path = new_auth_path;
+    sk.period += 1;
+    Ok(())
+}
+
+/// Domain tag of the messages signed with the Schnorr key of `period`: binding the period
+/// to the signature prevents it from being presented as made in another period.
+fn period_domain_tag(period: u64) -> Vec<FieldElement> {
+    vec![
+        FieldElement::from(FORWARD_SECURE_DOMAIN_TAG),
+        FieldElement::from(period),
+    ]
+}
+
+/// Generate a forward secure key pair supporting 2^`height` periods, starting at period 0.
+/// The Schnorr keys of all the periods are derived, one after the other, to build the tree;
+/// only the seed of period 0 and the Merkle path of its key are kept in the secret key.
+pub fn forward_secure_generate_key(
+    height: u8,
+) -> Result<(ForwardSecurePk, ForwardSecureSk), Error> {
+    let num_periods = forward_secure_num_periods(height)? as usize;
+
+    let mut seed = Zeroizing::new([0u8; FORWARD_SECURE_SEED_SIZE]);
+    OsRng.fill_bytes(&mut seed[..]);
+
+    let mut leaves = Vec::with_capacity(num_periods);
+    let mut period_seed = Zeroizing::new(*seed);
+    for _ in 0..num_periods {
+        leaves.push(period_leaf(&period_seed)?);
+        evolve_seed(&mut period_seed);
+    }
+
+    let mut sk = ForwardSecureSk {
+        period: 0,
+        height,
+        seed,
+        root: FieldElement::zero(),
+        auth_path: Vec::new(),
+    };
+
+    let tree = build_period_keys_tree(height as usize, leaves.as_slice())?;
+    sk.root = get_ginger_mht_root(&tree)?;
+    sk.auth_path = get_ginger_mht_path(&tree, 0)?
+        .get_raw_path()
+        .iter()
+        .map(|(sibling, _)| *sibling)
+        .collect();
+
+    Ok((forward_secure_get_public_key(&sk)?, sk))
+}
+
+/// Return the public key corresponding to `sk`.
+pub fn forward_secure_get_public_key(sk: &ForwardSecureSk) -> Result<ForwardSecurePk, Error> {
+    Ok(ForwardSecurePk {
+        root: sk.root,
+        height: sk.height,
+    })
+}
+
+/// Return the number of periods supported by a key of height `height`.
+/// Fails if `height` is not in [1, FORWARD_SECURE_MAX_HEIGHT], e.g. for a key read without
+/// semantic checks.
+pub fn forward_secure_num_periods(height: u8) -> Result<u64, Error> {
+    if height == 0 || height > FORWARD_SECURE_MAX_HEIGHT {
+        Err(format!(
+            "Height must be in [1, {}]. Found {}",
+            FORWARD_SECURE_MAX_HEIGHT, height
+        ))?
+    }
+    Ok(1u64 << height)
+}
+
+/// Evolve `sk` to the next period, erasing the secret of the current one.
+/// Fails if `sk` is already at the last period.
+pub fn forward_secure_update(sk: &mut ForwardSecureSk) -> Result<(), Error> {
+    let next_period = sk
+        .period
+        .checked_add(1)
+        .ok_or("Secret key already at the last period")?;
+    forward_secure_update_to(sk, next_period)
+}
+
+/// Evolve `sk` to `period`, erasing the secrets of all the periods before it.
+/// Fails if `period` is before the current period of `sk`, or beyond the last one.
+pub fn forward_secure_update_to(sk: &mut ForwardSecureSk, period: u64) -> Result<(), Error> {
+    if period < sk.period {
+        Err(format!(
+            "Unable to update secret key to past period {}. Current period: {}",
+            period, sk.period
+        ))?
+    }
+
+    let num_periods = forward_secure_num_periods(sk.height)?;
+    if period >= num_periods {
+        Err(format!(
+            "Unable to update secret key to period {}. Last period: {}",
+            period,
+            num_periods - 1
+        ))?
+    }
+
+    while sk.period < period {
+        next_period(sk)?;
+    }
+
+    Ok(())
+}
+
+/// Sign `msg` with `sk`, at its current period.
+pub fn forward_secure_sign(
+    msg: &FieldElement,
+    sk: &ForwardSecureSk,
+) -> Result<ForwardSecureSig, Error> {
+    let (period_pk, period_sk) = period_key_pair(&sk.seed);
+    let signature = schnorr_sign_with_domain_tag(
+        msg,
+        period_domain_tag(sk.period).as_slice(),
+        &period_sk,
+        &period_pk,
+    )?;
+
+    Ok(ForwardSecureSig {
+        period: sk.period,
+        period_pk,
+        signature,
+        path: current_path(sk),
+    })
+}
+
+/// Verify `signature` of `msg` under `pk`, made at `period`.
+pub fn forward_secure_verify(
+    msg: &FieldElement,
+    pk: &ForwardSecurePk,
+    signature: &ForwardSecureSig,
+    period: u64,
+) -> Result<bool, Error> {
+    if signature.period != period
+        || forward_secure_num_periods(pk.height)
+            .map(|num_periods| period >= num_periods)
+            .unwrap_or(true)
+        || get_leaf_index_from_path(&signature.path) != period
+        || !schnorr_verify_public_key(&signature.period_pk)
+    {
+        return Ok(false);
+    }
+
+    let leaf = period_pk_to_leaf(&signature.period_pk)?;
+    if !verify_ginger_merkle_path(&signature.path, pk.height as usize, &leaf, &pk.root)? {
+        return Ok(false);
+    }
+
+    schnorr_verify_with_domain_tag(
+        msg,
+        period_domain_tag(period).as_slice(),
+        &signature.period_pk,
+        &signature.signature,
+    )
+}
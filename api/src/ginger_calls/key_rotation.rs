@@ -0,0 +1,268 @@
+use super::{
+    domain_tags::*, poseidon_hash::*, schnorr_signature::*, serialization::DeserializationChecks, *,
+};
+use algebra::{serialize::*, AffineCurve, SemanticallyValid, ToConstraintField};
+
+/// Kind of the key being rotated. Part of the signed message, so that a certificate
+/// rotating a key of one kind can't be used to rotate a key of the other one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RotatedKeyType {
+    Schnorr = 0,
+    VRF = 1,
+}
+
+impl RotatedKeyType {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(RotatedKeyType::Schnorr),
+            1 => Some(RotatedKeyType::VRF),
+            _ => None,
+        }
+    }
+}
+
+/// Certificate stating that `new_pk` replaces `old_pk` starting from `epoch`,
+/// signed with the secret key of `old_pk`.
+/// Schnorr and VRF keys belong to the same group, thus a VRF secret key can sign the
+/// certificate rotating it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyRotationCertificate {
+    pub key_type: RotatedKeyType,
+    pub old_pk: SchnorrPk,
+    pub new_pk: SchnorrPk,
+    pub epoch: u64,
+    pub signature: SchnorrSig,
+}
+
+pub const KEY_ROTATION_CERTIFICATE_SIZE: usize =
+    1 + 2 * GROUP_COMPRESSED_SIZE + 8 + SCHNORR_SIG_SIZE;
+
+impl CanonicalSerialize for KeyRotationCertificate {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&(self.key_type as u8), &mut writer)?;
+        CanonicalSerialize::serialize(&self.old_pk, &mut writer)?;
+        CanonicalSerialize::serialize(&self.new_pk, &mut writer)?;
+        CanonicalSerialize::serialize(&self.epoch, &mut writer)?;
+        CanonicalSerialize::serialize(&self.signature, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        1 + self.old_pk.serialized_size()
+            + self.new_pk.serialized_size()
+            + self.epoch.serialized_size()
+            + self.signature.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&(self.key_type as u8), &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&self.old_pk, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&self.new_pk, &mut writer)?;
+        CanonicalSerialize::serialize(&self.epoch, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&self.signature, &mut writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        1 + self.old_pk.uncompressed_size()
+            + self.new_pk.uncompressed_size()
+            + self.epoch.serialized_size()
+            + self.signature.uncompressed_size()
+    }
+}
+
+fn read_key_type<R: Read>(reader: R) -> Result<RotatedKeyType, SerializationError> {
+    let key_type: u8 = CanonicalDeserialize::deserialize(reader)?;
+    RotatedKeyType::from_u8(key_type).ok_or(SerializationError::InvalidData)
+}
+
+impl CanonicalDeserialize for KeyRotationCertificate {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let key_type = read_key_type(&mut reader)?;
+        let old_pk = CanonicalDeserialize::deserialize(&mut reader)?;
+        let new_pk = CanonicalDeserialize::deserialize(&mut reader)?;
+        let epoch = CanonicalDeserialize::deserialize(&mut reader)?;
+        let signature = CanonicalDeserialize::deserialize(&mut reader)?;
+        Ok(Self {
+            key_type,
+            old_pk,
+            new_pk,
+            epoch,
+            signature,
+        })
+    }
+
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let key_type = read_key_type(&mut reader)?;
+        let old_pk = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        let new_pk = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        let epoch = CanonicalDeserialize::deserialize(&mut reader)?;
+        let signature = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        Ok(Self {
+            key_type,
+            old_pk,
+            new_pk,
+            epoch,
+            signature,
+        })
+    }
+
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let key_type = read_key_type(&mut reader)?;
+        let old_pk = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let new_pk = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let epoch = CanonicalDeserialize::deserialize(&mut reader)?;
+        let signature = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        Ok(Self {
+            key_type,
+            old_pk,
+            new_pk,
+            epoch,
+            signature,
+        })
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(
+        mut reader: R,
+    ) -> Result<Self, SerializationError> {
+        let key_type = read_key_type(&mut reader)?;
+        let old_pk = CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?;
+        let new_pk = CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?;
+        let epoch = CanonicalDeserialize::deserialize(&mut reader)?;
+        let signature = CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?;
+        Ok(Self {
+            key_type,
+            old_pk,
+            new_pk,
+            epoch,
+            signature,
+        })
+    }
+}
+
+impl SemanticallyValid for KeyRotationCertificate {
+    fn is_valid(&self) -> bool {
+        self.old_pk.is_valid() && self.new_pk.is_valid() && self.signature.is_valid()
+    }
+}
+
+impl DeserializationChecks for KeyRotationCertificate {}
+
+/// Compute the message signed by a key rotation certificate, i.e. H(new_pk.x, new_pk.y, epoch),
+/// and the domain tag identifying the kind of the rotated key.
+fn key_rotation_msg(
+    key_type: RotatedKeyType,
+    new_pk: &SchnorrPk,
+    epoch: u64,
+) -> Result<(FieldElement, Vec<FieldElement>), Error> {
+    let mut msg = new_pk.into_projective().to_field_elements()?;
+    msg.push(FieldElement::from(epoch));
+
+    let domain_tag = vec![
+        FieldElement::from(KEY_ROTATION_DOMAIN_TAG),
+        FieldElement::from(key_type as u64),
+    ];
+
+    Ok((compute_poseidon_hash(msg.as_slice(), None)?, domain_tag))
+}
+
+fn create_key_rotation_certificate(
+    key_type: RotatedKeyType,
+    old_sk: &SchnorrSk,
+    old_pk: &SchnorrPk,
+    new_pk: &SchnorrPk,
+    epoch: u64,
+) -> Result<KeyRotationCertificate, Error> {
+    if !schnorr_verify_public_key(new_pk) {
+        Err("Invalid new public key")?
+    }
+
+    let (msg, domain_tag) = key_rotation_msg(key_type, new_pk, epoch)?;
+    let signature = schnorr_sign_with_domain_tag(&msg, domain_tag.as_slice(), old_sk, old_pk)?;
+
+    Ok(KeyRotationCertificate {
+        key_type,
+        old_pk: *old_pk,
+        new_pk: *new_pk,
+        epoch,
+        signature,
+    })
+}
+
+/// Certify that the Schnorr key `new_pk` replaces `old_pk`, starting from `epoch`.
+pub fn create_schnorr_key_rotation_certificate(
+    old_sk: &SchnorrSk,
+    old_pk: &SchnorrPk,
+    new_pk: &SchnorrPk,
+    epoch: u64,
+) -> Result<KeyRotationCertificate, Error> {
+    create_key_rotation_certificate(RotatedKeyType::Schnorr, old_sk, old_pk, new_pk, epoch)
+}
+
+/// Certify that the VRF key `new_pk` replaces `old_pk`, starting from `epoch`.
+pub fn create_vrf_key_rotation_certificate(
+    old_sk: &VRFSk,
+    old_pk: &VRFPk,
+    new_pk: &VRFPk,
+    epoch: u64,
+) -> Result<KeyRotationCertificate, Error> {
+    create_key_rotation_certificate(RotatedKeyType::VRF, old_sk, old_pk, new_pk, epoch)
+}
+
+/// Verify the signature of `certificate` and the validity of the keys in it.
+pub fn verify_key_rotation_certificate(
+    certificate: &KeyRotationCertificate,
+) -> Result<bool, Error> {
+    if !schnorr_verify_public_key(&certificate.old_pk)
+        || !schnorr_verify_public_key(&certificate.new_pk)
+    {
+        return Ok(false);
+    }
+
+    let (msg, domain_tag) =
+        key_rotation_msg(certificate.key_type, &certificate.new_pk, certificate.epoch)?;
+
+    schnorr_verify_with_domain_tag(
+        &msg,
+        domain_tag.as_slice(),
+        &certificate.old_pk,
+        &certificate.signature,
+    )
+}
+
+/// Validate a chain of rotations of a key of kind `key_type`, starting from `initial_pk`, and
+/// return the current key. Each certificate must be valid, rotate the key certified by the
+/// previous one (or `initial_pk` for the first one), and have an epoch strictly greater than
+/// the previous one.
+pub fn verify_key_rotation_chain(
+    key_type: RotatedKeyType,
+    initial_pk: &SchnorrPk,
+    certificates: &[KeyRotationCertificate],
+) -> Result<SchnorrPk, Error> {
+    let mut current_pk = *initial_pk;
+    let mut last_epoch = None;
+
+    for (i, certificate) in certificates.iter().enumerate() {
+        if certificate.key_type != key_type {
+            Err(format!(
+                "Certificate {} rotates a key of a different kind",
+                i
+            ))?
+        }
+
+        if certificate.old_pk != current_pk {
+            Err(format!("Certificate {} doesn't rotate the current key", i))?
+        }
+
+        if last_epoch.map_or(false, |epoch| certificate.epoch <= epoch) {
+            Err(format!("Certificate {} has a non increasing epoch", i))?
+        }
+
+        if !verify_key_rotation_certificate(certificate)? {
+            Err(format!("Certificate {} is not valid", i))?
+        }
+
+        current_pk = certificate.new_pk;
+        last_epoch = Some(certificate.epoch);
+    }
+
+    Ok(current_pk)
+}
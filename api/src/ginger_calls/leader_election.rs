@@ -0,0 +1,136 @@
+use super::*;
+use algebra::{BigInteger, FpParameters, PrimeField};
+
+/// Number of fractional bits of the fixed-point numbers used to compute the election threshold
+pub const THRESHOLD_FRAC_BITS: u32 = 48;
+
+/// Fixed-point representation of 1
+const ONE: i128 = 1 << THRESHOLD_FRAC_BITS;
+
+/// Number of most significant bits of the VRF output compared against the threshold
+const OUTPUT_BITS: usize = 64;
+
+/// Maximum number of terms evaluated for each power series: a series not converging within
+/// this bound means that the active slot coefficient is too close to 1.
+const MAX_SERIES_TERMS: i128 = 10000;
+
+fn fixed_mul(a: i128, b: i128) -> i128 {
+    (a * b) >> THRESHOLD_FRAC_BITS
+}
+
+/// ln(1 - f) for 0 < f < 1, computed as -2 * atanh(f / (2 - f)).
+fn fixed_ln_one_minus(f: i128) -> Result<i128, Error> {
+    let z = (f << THRESHOLD_FRAC_BITS) / (2 * ONE - f);
+    let z_squared = fixed_mul(z, z);
+
+    let mut sum = 0;
+    let mut pow = z;
+    let mut k = 1;
+    while pow != 0 {
+        if k > 2 * MAX_SERIES_TERMS {
+            Err("Active slot coefficient too close to 1")?
+        }
+        sum += pow / k;
+        pow = fixed_mul(pow, z_squared);
+        k += 2;
+    }
+
+    Ok(-2 * sum)
+}
+
+/// exp(x), computed through its Taylor expansion.
+fn fixed_exp(x: i128) -> Result<i128, Error> {
+    let mut sum = ONE;
+    let mut term = ONE;
+    let mut k = 1;
+    while term != 0 {
+        if k > MAX_SERIES_TERMS {
+            Err("Active slot coefficient too close to 1")?
+        }
+        term = fixed_mul(term, x) / k;
+        sum += term;
+        k += 1;
+    }
+
+    Ok(sum)
+}
+
+/// Read the OUTPUT_BITS most significant bits of `repr`, interpreted as a MODULUS_BITS long integer.
+fn top_bits<B: BigInteger>(repr: &B) -> u128 {
+    let bits = repr.to_bits();
+    let skip = bits.len() - <FieldElement as PrimeField>::Params::MODULUS_BITS as usize;
+
+    bits.into_iter()
+        .skip(skip)
+        .take(OUTPUT_BITS)
+        .fold(0u128, |acc, b| (acc << 1) | b as u128)
+}
+
+/// Compute the probability of being elected slot leader as defined by Ouroboros Praos, i.e.
+/// phi(alpha) = 1 - (1 - f)^alpha, with alpha = `stake`/`total_stake` and f the active slot coefficient,
+/// expressed as the fraction `active_slot_coeff_num`/`active_slot_coeff_den`.
+/// The result is a fixed-point number with THRESHOLD_FRAC_BITS fractional bits.
+/// Only integer arithmetic is used, thus the result is the same on all platforms.
+pub fn vrf_leader_threshold(
+    stake: u64,
+    total_stake: u64,
+    active_slot_coeff_num: u64,
+    active_slot_coeff_den: u64,
+) -> Result<u64, Error> {
+    if total_stake == 0 || stake > total_stake {
+        Err(format!(
+            "Invalid stake: {}, total stake: {}",
+            stake, total_stake
+        ))?
+    }
+
+    if active_slot_coeff_num == 0 || active_slot_coeff_num >= active_slot_coeff_den {
+        Err(format!(
+            "Active slot coefficient must be in (0, 1). Found {}/{}",
+            active_slot_coeff_num, active_slot_coeff_den
+        ))?
+    }
+
+    if stake == 0 {
+        return Ok(0);
+    }
+
+    let alpha = ((stake as i128) << THRESHOLD_FRAC_BITS) / total_stake as i128;
+    let f =
+        ((active_slot_coeff_num as i128) << THRESHOLD_FRAC_BITS) / active_slot_coeff_den as i128;
+
+    // (1 - f)^alpha = exp(alpha * ln(1 - f))
+    let not_elected = fixed_exp(fixed_mul(alpha, fixed_ln_one_minus(f)?))?;
+
+    Ok((ONE - not_elected).max(0).min(ONE) as u64)
+}
+
+/// Return true if `vrf_output`, normalized in [0, 1) by dividing it by the field modulus, is
+/// below `threshold`, a fixed-point number computed through `vrf_leader_threshold`.
+pub fn vrf_is_below_threshold(vrf_output: &FieldElement, threshold: u64) -> bool {
+    let output = top_bits(&vrf_output.into_repr());
+    let modulus = top_bits(&<FieldElement as PrimeField>::Params::MODULUS);
+
+    (output << THRESHOLD_FRAC_BITS) < threshold as u128 * modulus
+}
+
+/// Return true if the forger owning `stake` out of `total_stake`, who computed `vrf_output` for
+/// the current slot, is eligible as slot leader given the active slot coefficient
+/// `active_slot_coeff_num`/`active_slot_coeff_den`.
+/// The VRF output is expected to be verified beforehand, e.g. through `vrf_proof_to_hash`.
+pub fn vrf_is_slot_leader(
+    vrf_output: &FieldElement,
+    stake: u64,
+    total_stake: u64,
+    active_slot_coeff_num: u64,
+    active_slot_coeff_den: u64,
+) -> Result<bool, Error> {
+    let threshold = vrf_leader_threshold(
+        stake,
+        total_stake,
+        active_slot_coeff_num,
+        active_slot_coeff_den,
+    )?;
+
+    Ok(vrf_is_below_threshold(vrf_output, threshold))
+}
@@ -1,5 +1,33 @@
-use super::*;
+use super::{
+    serialization::{check_no_trailing_bytes, serialize_to_buffer, DeserializationCheckError},
+    *,
+};
+use algebra::{serialize::*, SemanticallyValid, SerializationError};
 use primitives::merkle_tree::*;
+use std::{
+    io::{Cursor, Read},
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+/// Default maximum height of a GingerMHT accepted by `validate_ginger_mht` and by the checked
+/// deserialization. The nodes of a tree of this height take 16 MiB once serialized, and
+/// about as much in memory, plus the copy rebuilt by `validate_ginger_mht`.
+pub const DEFAULT_MAX_GINGER_MHT_HEIGHT: usize = 18;
+
+/// Upper bound on the serialized size of all the fields of a GingerMHT other than its nodes
+const GINGER_MHT_METADATA_MAX_SIZE: usize = 1 << 12;
+
+/// Maximum number of bytes read by the checked `deserialize_ginger_mht`: the serialized size
+/// of a tree of height `max_height`
+pub fn ginger_mht_max_serialized_size(max_height: usize) -> u64 {
+    let num_nodes = 1u64
+        .checked_shl(max_height.saturating_add(1) as u32)
+        .map_or(u64::max_value(), |n| n - 1);
+    num_nodes
+        .saturating_mul(FIELD_SIZE as u64)
+        .saturating_add(GINGER_MHT_METADATA_MAX_SIZE as u64)
+}
+
 pub fn new_ginger_mht(height: usize, processing_step: usize) -> Result<GingerMHT, Error> {
     GingerMHT::init(height, processing_step)
 }
@@ -84,3 +112,216 @@ pub fn get_leaf_index_from_path(path: &GingerMHTPath) -> u64 {
 pub fn get_root_from_path(path: &GingerMHTPath, leaf: &FieldElement) -> FieldElement {
     path.compute_root(leaf)
 }
+
+/// Check the internal consistency of `tree`, e.g. after reading it from an untrusted source:
+/// its height must be between 1 and `max_height` and backed by the stored nodes, its processing
+/// step must be between 1 and 2^height, it must hold at most 2^height leaves, and its state must
+/// be the one obtained by appending its leaves to an empty tree with the same height and
+/// processing step (finalizing it, if `tree` is finalized). As ginger-lib doesn't expose the nodes of the
+/// tree, the two trees are compared through their serialization: the cost is therefore
+/// comparable to the one of building the tree from scratch.
+/// The accessors of ginger-lib assume a consistent tree: a panic raised by any of them on a
+/// malformed tree is caught and returned as an error.
+pub fn validate_ginger_mht(tree: &GingerMHT, max_height: usize) -> Result<(), Error> {
+    catch_unwind(AssertUnwindSafe(|| check_ginger_mht(tree, max_height))).unwrap_or_else(|panic| {
+        let msg = panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".to_owned());
+        Err(format!("Malformed tree: {}", msg).into())
+    })
+}
+
+fn check_ginger_mht(tree: &GingerMHT, max_height: usize) -> Result<(), Error> {
+    // Heights are also bounded by the size of usize, for the shifts below not to overflow
+    let max_height = max_height.min(8 * std::mem::size_of::<usize>() - 2);
+    let height = tree.height();
+    if height == 0 || height > max_height {
+        Err(format!(
+            "Invalid tree height {}. Must be between 1 and {}",
+            height, max_height
+        ))?
+    }
+
+    let max_leaves = 1usize << height;
+    let processing_step = tree.processing_step();
+    if processing_step == 0 || processing_step > max_leaves {
+        Err(format!(
+            "Invalid processing step {}. Must be between 1 and {}",
+            processing_step, max_leaves
+        ))?
+    }
+
+    // A tree of height h stores 2^(h + 1) - 1 nodes: check they are actually there
+    // before allocating a tree of the same height.
+    let num_nodes = (max_leaves << 1) - 1;
+    if num_nodes
+        .checked_mul(FIELD_SIZE)
+        .map_or(true, |nodes_size| tree.serialized_size() < nodes_size)
+    {
+        Err(format!(
+            "Tree of height {} must store at least {} nodes",
+            height, num_nodes
+        ))?
+    }
+
+    let leaves = tree.get_leaves();
+    if leaves.len() > max_leaves {
+        Err(format!(
+            "Tree of height {} holds {} leaves. Max is {}",
+            height,
+            leaves.len(),
+            max_leaves
+        ))?
+    }
+
+    let mut rebuilt = new_ginger_mht(height, processing_step)?;
+    for leaf in leaves {
+        append_leaf_to_ginger_mht(&mut rebuilt, leaf)?;
+    }
+    if tree.root().is_some() {
+        finalize_ginger_mht_in_place(&mut rebuilt)?;
+    }
+
+    if serialize_to_buffer(tree, None)? != serialize_to_buffer(&rebuilt, None)? {
+        Err("Tree nodes don't match the leaves")?
+    }
+
+    Ok(())
+}
+
+/// Deserialize a GingerMHT from `reader`. If `max_height` is set, the tree is validated with
+/// `validate_ginger_mht`, an inconsistent tree being reported as failed semantic checks, and
+/// at most `ginger_mht_max_serialized_size(max_height)` bytes are read: the memory allocated
+/// for the tree is then bounded whatever the length prefixes of the serialized data.
+/// Otherwise, the tree is read as is, as for trusted sources.
+pub fn deserialize_ginger_mht<R: Read>(
+    reader: R,
+    max_height: Option<usize>,
+) -> Result<GingerMHT, SerializationError> {
+    let max_height = match max_height {
+        Some(max_height) => max_height,
+        None => return <GingerMHT as CanonicalDeserialize>::deserialize(reader),
+    };
+
+    // Running out of the allowed bytes means the tree is higher than max_height
+    let mut limited_reader = reader.take(ginger_mht_max_serialized_size(max_height));
+    let tree =
+        <GingerMHT as CanonicalDeserialize>::deserialize(&mut limited_reader).map_err(|e| {
+            if limited_reader.limit() == 0 {
+                DeserializationCheckError::SemanticChecksFailed(format!(
+                    "Tree larger than the ones of height {}",
+                    max_height
+                ))
+                .into()
+            } else {
+                e
+            }
+        })?;
+    validate_ginger_mht(&tree, max_height)
+        .map_err(|e| DeserializationCheckError::SemanticChecksFailed(e.to_string()))?;
+    Ok(tree)
+}
+
+/// Maximum length of a GingerMHTPath encoded by `encode_ginger_mht_path_compact`, bounded by
+/// the size of the leaf index
+pub const MAX_COMPACT_PATH_LENGTH: usize = 64;
+
+/// Root of an empty subtree of height `level`, if precomputed in ZERO_NODE_CST
+fn empty_subtree_root(level: usize) -> Option<FieldElement> {
+    GingerMHTParams::ZERO_NODE_CST.and_then(|cst| cst.nodes.get(level).copied())
+}
+
+/// Encode `path` as:
+/// length (1 byte) || leaf index (8 bytes, little endian) ||
+/// omitted siblings bitmap (ceil(length / 8) bytes) || siblings not omitted (FIELD_SIZE bytes each),
+/// where bit i of the bitmap (LSB first) is set if the sibling at level i, counting from the
+/// leaf, is the root of an empty subtree, as precomputed in ZERO_NODE_CST, and thus omitted.
+/// The position of the node at each level is not stored, being given by the leaf index.
+/// In a partially filled tree most of the siblings on the right are empty subtrees, so the
+/// encoding is considerably smaller than the canonical serialization.
+pub fn encode_ginger_mht_path_compact(path: &GingerMHTPath) -> Result<Vec<u8>, SerializationError> {
+    let raw_path = path.get_raw_path();
+    if raw_path.len() > MAX_COMPACT_PATH_LENGTH {
+        return Err(SerializationError::NotEnoughSpace);
+    }
+
+    let mut leaf_index = 0u64;
+    let mut bitmap = vec![0u8; (raw_path.len() + 7) / 8];
+    let mut siblings = Vec::new();
+    for (level, (sibling, is_right)) in raw_path.iter().enumerate() {
+        if *is_right {
+            leaf_index |= 1 << level;
+        }
+        if empty_subtree_root(level) == Some(*sibling) {
+            bitmap[level / 8] |= 1 << (level % 8);
+        } else {
+            sibling.serialize(&mut siblings)?;
+        }
+    }
+
+    let mut buffer = Vec::with_capacity(9 + bitmap.len() + siblings.len());
+    buffer.push(raw_path.len() as u8);
+    buffer.extend_from_slice(&leaf_index.to_le_bytes());
+    buffer.extend_from_slice(bitmap.as_slice());
+    buffer.extend_from_slice(siblings.as_slice());
+    Ok(buffer)
+}
+
+/// Decode a GingerMHTPath from `buffer`, produced by `encode_ginger_mht_path_compact`, and
+/// perform semantic checks on it if `semantic_checks` is set.
+/// `buffer` must contain exactly the encoded path.
+pub fn decode_ginger_mht_path_compact(
+    buffer: &[u8],
+    semantic_checks: bool,
+) -> Result<GingerMHTPath, SerializationError> {
+    let buff_len = buffer.len() as u64;
+    let mut reader = Cursor::new(buffer);
+
+    let mut header = [0u8; 9];
+    reader
+        .read_exact(&mut header)
+        .map_err(SerializationError::IoError)?;
+    let length = header[0] as usize;
+    let mut leaf_index = [0u8; 8];
+    leaf_index.copy_from_slice(&header[1..]);
+    let leaf_index = u64::from_le_bytes(leaf_index);
+
+    if length > MAX_COMPACT_PATH_LENGTH
+        || (length < MAX_COMPACT_PATH_LENGTH && leaf_index >> length != 0)
+    {
+        return Err(SerializationError::InvalidData);
+    }
+
+    let mut bitmap = vec![0u8; (length + 7) / 8];
+    reader
+        .read_exact(bitmap.as_mut_slice())
+        .map_err(SerializationError::IoError)?;
+    // Padding bits must be unset, so that each path has a single encoding
+    if length % 8 != 0 && bitmap[length / 8] >> (length % 8) != 0 {
+        return Err(SerializationError::InvalidData);
+    }
+
+    let mut raw_path = Vec::with_capacity(length);
+    for level in 0..length {
+        let sibling = if bitmap[level / 8] & (1 << (level % 8)) != 0 {
+            empty_subtree_root(level).ok_or(SerializationError::InvalidData)?
+        } else {
+            FieldElement::deserialize(&mut reader)?
+        };
+        raw_path.push((sibling, leaf_index & (1 << level) != 0));
+    }
+
+    check_no_trailing_bytes(reader.position(), buff_len)?;
+
+    let path = GingerMHTPath::new(raw_path);
+    if semantic_checks && !path.is_valid() {
+        return Err(DeserializationCheckError::SemanticChecksFailed(
+            "Invalid GingerMHTPath".to_owned(),
+        )
+        .into());
+    }
+
+    Ok(path)
+}
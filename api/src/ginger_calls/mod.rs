@@ -1,12 +1,40 @@
 use super::*;
 
+pub mod constant_time;
+pub mod domain_tags;
 pub mod field_element;
+pub mod forward_secure_signature;
+pub mod key_rotation;
+pub mod leader_election;
 pub mod merkle_tree;
 pub mod poseidon_hash;
+pub mod randomness_beacon;
 pub mod schnorr_signature;
 pub mod serialization;
+#[cfg(test)]
+pub mod test_vectors;
+pub mod text_encoding;
 pub mod vrf;
 
+/// Compute sum_i scalars[i] * bases[i] through a variable base multi-scalar multiplication.
+pub(crate) fn multi_scalar_mul<G: algebra::AffineCurve>(
+    bases: &[G],
+    scalars: &[G::ScalarField],
+) -> Result<G::Projective, Error> {
+    use algebra::{msm::VariableBaseMSM, PrimeField};
+
+    if bases.len() != scalars.len() {
+        Err(format!(
+            "Bases and scalars must have the same length. Found {} and {}",
+            bases.len(),
+            scalars.len()
+        ))?
+    }
+
+    let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(bases, scalars.as_slice())
+}
+
 pub(crate) fn into_i8(v: Vec<u8>) -> Vec<i8> {
     // first, make sure v's destructor doesn't free the data
     // it thinks it owns when it goes out of scope
@@ -56,7 +84,7 @@ mod test {
         assert_eq!(sk_serialized.len(), SCHNORR_SK_SIZE);
         println!("sk bytes: {:?}", into_i8(sk_serialized.clone()));
         let sk_deserialized = deserialize_from_buffer(&sk_serialized, None, None).unwrap();
-        assert_eq!(sk, sk_deserialized);
+        assert!(constant_time::ct_eq(&sk, &sk_deserialized).unwrap());
 
         let sig = schnorr_sign(&msg, &sk, &pk).unwrap(); //Sign msg
         assert!(is_valid(&sig));
@@ -75,6 +103,178 @@ mod test {
         assert!(!schnorr_verify_signature(&wrong_msg, &pk, &sig).unwrap());
     }
 
+    #[test]
+    fn sample_calls_schnorr_sig_bytes_and_field_elements() {
+        use schnorr_signature::*;
+
+        let mut rng = OsRng;
+        let (pk, sk) = schnorr_generate_key();
+
+        // Bytes message
+        let msg = b"Some arbitrary message to be signed";
+        let sig = schnorr_sign_bytes(msg, &sk, &pk).unwrap();
+        assert!(schnorr_verify_bytes(msg, &pk, &sig).unwrap());
+        assert!(!schnorr_verify_bytes(b"Some other message", &pk, &sig).unwrap());
+
+        // Trailing zeros must not be ignored
+        let padded_msg = [msg.as_ref(), &[0u8]].concat();
+        assert!(!schnorr_verify_bytes(padded_msg.as_slice(), &pk, &sig).unwrap());
+
+        // Multi field elements message
+        let fe_msg = vec![FieldElement::rand(&mut rng), FieldElement::rand(&mut rng)];
+        let sig = schnorr_sign_field_elements(fe_msg.as_slice(), &sk, &pk).unwrap();
+        assert!(schnorr_verify_field_elements(fe_msg.as_slice(), &pk, &sig).unwrap());
+        assert!(!schnorr_verify_field_elements(&fe_msg[..1], &pk, &sig).unwrap());
+
+        // Signatures over a single field element are domain separated from the ones above
+        assert!(!schnorr_verify_signature(
+            &fe_msg[0],
+            &pk,
+            &schnorr_sign_field_elements(&fe_msg[..1], &sk, &pk).unwrap()
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn sample_calls_schnorr_sig_domain_tag() {
+        use schnorr_signature::*;
+
+        let mut rng = OsRng;
+        let (pk, sk) = schnorr_generate_key();
+        let msg = FieldElement::rand(&mut rng);
+
+        let tag = vec![FieldElement::rand(&mut rng)];
+        let other_tag = schnorr_domain_tag_from_bytes(b"Some other protocol").unwrap();
+
+        let sig = schnorr_sign_with_domain_tag(&msg, tag.as_slice(), &sk, &pk).unwrap();
+        assert!(schnorr_verify_with_domain_tag(&msg, tag.as_slice(), &pk, &sig).unwrap());
+
+        // Signature can't be replayed in another context
+        assert!(!schnorr_verify_with_domain_tag(&msg, other_tag.as_slice(), &pk, &sig).unwrap());
+        assert!(!schnorr_verify_with_domain_tag(&msg, &[], &pk, &sig).unwrap());
+        assert!(!schnorr_verify_signature(&msg, &pk, &sig).unwrap());
+
+        // Nor can a plain signature be used in a context with a tag
+        let plain_sig = schnorr_sign(&msg, &sk, &pk).unwrap();
+        assert!(!schnorr_verify_with_domain_tag(&msg, &[], &pk, &plain_sig).unwrap());
+        assert!(!schnorr_verify_with_domain_tag(&msg, tag.as_slice(), &pk, &plain_sig).unwrap());
+
+        // Tags differing only by trailing zeros are different contexts
+        let empty_tag_sig = schnorr_sign_with_domain_tag(&msg, &[], &sk, &pk).unwrap();
+        let zero_tag = vec![FieldElement::zero()];
+        assert!(schnorr_verify_with_domain_tag(&msg, &[], &pk, &empty_tag_sig).unwrap());
+        assert!(
+            !schnorr_verify_with_domain_tag(&msg, zero_tag.as_slice(), &pk, &empty_tag_sig)
+                .unwrap()
+        );
+        let tag_and_zero = vec![tag[0], FieldElement::zero()];
+        assert!(!schnorr_verify_with_domain_tag(&msg, tag_and_zero.as_slice(), &pk, &sig).unwrap());
+
+        // Tags from bytes differ from the hash of the bytes given as field element tag
+        let bytes_tag = schnorr_domain_tag_from_bytes(b"Some protocol").unwrap();
+        let hash_tag =
+            vec![poseidon_hash::compute_poseidon_hash_from_bytes(b"Some protocol", None).unwrap()];
+        let bytes_tag_sig =
+            schnorr_sign_with_domain_tag(&msg, bytes_tag.as_slice(), &sk, &pk).unwrap();
+        assert!(
+            schnorr_verify_with_domain_tag(&msg, bytes_tag.as_slice(), &pk, &bytes_tag_sig)
+                .unwrap()
+        );
+        assert!(
+            !schnorr_verify_with_domain_tag(&msg, hash_tag.as_slice(), &pk, &bytes_tag_sig)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn sample_calls_schnorr_adaptor_sig() {
+        use schnorr_signature::*;
+
+        let mut rng = OsRng;
+        let (pk, sk) = schnorr_generate_key();
+        let (adaptor_point, adaptor_secret) = schnorr_adaptor_generate_key();
+        let (wrong_adaptor_point, wrong_adaptor_secret) = schnorr_adaptor_generate_key();
+        let msg = FieldElement::rand(&mut rng);
+
+        //Pre-sign and pre-verify
+        let pre_sig = schnorr_adaptor_pre_sign(&msg, &sk, &pk, &adaptor_point).unwrap();
+        assert!(is_valid(&pre_sig));
+        assert!(schnorr_adaptor_pre_verify(&msg, &pk, &adaptor_point, &pre_sig).unwrap());
+        assert!(!schnorr_adaptor_pre_verify(&msg, &pk, &wrong_adaptor_point, &pre_sig).unwrap());
+        assert!(!schnorr_adaptor_pre_verify(
+            &FieldElement::rand(&mut rng),
+            &pk,
+            &adaptor_point,
+            &pre_sig
+        )
+        .unwrap());
+
+        //Serialize/deserialize pre-sig
+        let pre_sig_serialized = serialize_to_buffer(&pre_sig, None).unwrap();
+        assert_eq!(pre_sig_serialized.len(), SCHNORR_PRE_SIG_SIZE);
+        let pre_sig_deserialized: SchnorrPreSig =
+            deserialize_from_buffer(&pre_sig_serialized, Some(true), None).unwrap();
+        assert_eq!(pre_sig, pre_sig_deserialized);
+
+        //Adapt: the result is a regular signature
+        let sig = schnorr_adaptor_adapt(&pre_sig, &adaptor_secret).unwrap();
+        assert!(schnorr_verify_signature(&msg, &pk, &sig).unwrap());
+        let wrong_sig = schnorr_adaptor_adapt(&pre_sig, &wrong_adaptor_secret).unwrap();
+        assert!(!schnorr_verify_signature(&msg, &pk, &wrong_sig).unwrap());
+
+        //Extract adaptor secret
+        assert_eq!(
+            schnorr_adaptor_extract_secret(&pre_sig, &sig, &adaptor_point).unwrap(),
+            adaptor_secret
+        );
+        assert!(schnorr_adaptor_extract_secret(&pre_sig, &sig, &wrong_adaptor_point).is_err());
+    }
+
+    #[test]
+    fn sample_calls_schnorr_compact_sig() {
+        use schnorr_signature::*;
+
+        let mut rng = OsRng;
+        let samples = 10;
+
+        let mut items = Vec::with_capacity(samples);
+        for _ in 0..samples {
+            let (pk, sk) = schnorr_generate_key();
+            let msg = FieldElement::rand(&mut rng);
+
+            //Conversions
+            let sig = schnorr_sign(&msg, &sk, &pk).unwrap();
+            let compact_sig = schnorr_sig_to_compact(&sig, &pk).unwrap();
+            assert!(is_valid(&compact_sig));
+            assert_eq!(
+                schnorr_compact_to_sig(&compact_sig, &msg, &pk).unwrap(),
+                sig
+            );
+
+            //Verify
+            assert!(schnorr_verify_compact(&msg, &pk, &compact_sig).unwrap());
+            assert!(
+                !schnorr_verify_compact(&FieldElement::rand(&mut rng), &pk, &compact_sig).unwrap()
+            );
+
+            //Serialize/deserialize compact sig
+            let compact_sig_serialized = serialize_to_buffer(&compact_sig, Some(true)).unwrap();
+            assert_eq!(compact_sig_serialized.len(), SCHNORR_COMPACT_SIG_SIZE);
+            let compact_sig_deserialized: SchnorrCompactSig =
+                deserialize_from_buffer(&compact_sig_serialized, Some(true), Some(true)).unwrap();
+            assert_eq!(compact_sig, compact_sig_deserialized);
+
+            items.push((msg, pk, schnorr_sign_compact(&msg, &sk, &pk).unwrap()));
+        }
+
+        //Batch verify
+        assert!(schnorr_batch_verify_compact(items.as_slice()).unwrap());
+
+        //Negative case
+        items[samples / 2].0 = FieldElement::rand(&mut rng);
+        assert!(!schnorr_batch_verify_compact(items.as_slice()).unwrap());
+    }
+
     #[test]
     fn sample_calls_merkle_path() {
         use merkle_tree::*;
@@ -188,7 +388,7 @@ mod test {
         assert_eq!(sk_serialized.len(), VRF_SK_SIZE);
         println!("sk bytes: {:?}", into_i8(sk_serialized.clone()));
         let sk_deserialized = deserialize_from_buffer(&sk_serialized, None, None).unwrap();
-        assert_eq!(sk, sk_deserialized);
+        assert!(constant_time::ct_eq(&sk, &sk_deserialized).unwrap());
 
         let (vrf_proof, vrf_out) = vrf_prove(&msg, &sk, &pk).unwrap(); //Create vrf proof for msg
         assert!(is_valid(&vrf_proof));
@@ -213,6 +413,1184 @@ mod test {
 
         //Negative case
         let wrong_msg = FieldElement::rand(&mut rng);
-        assert!(vrf_proof_to_hash(&wrong_msg, &pk, &vrf_proof).is_err());
+        assert_eq!(
+            vrf_proof_to_hash(&wrong_msg, &pk, &vrf_proof).unwrap_err(),
+            VRFError::InvalidProof
+        );
+    }
+
+    #[test]
+    fn sample_calls_constant_time_eq() {
+        use constant_time::*;
+        use schnorr_signature::*;
+
+        let (_, sk) = schnorr_generate_key();
+        let (_, other_sk) = schnorr_generate_key();
+
+        assert!(ct_eq(&sk, &sk.clone()).unwrap());
+        assert!(!ct_eq(&sk, &other_sk).unwrap());
+
+        assert!(ct_eq_bytes(&[1, 2, 3], &[1, 2, 3]));
+        assert!(!ct_eq_bytes(&[1, 2, 3], &[1, 2, 4]));
+        assert!(!ct_eq_bytes(&[1, 2, 3], &[1, 2]));
+    }
+
+    /// Welch's t-statistic between the times taken by `compare(false)`, comparing secrets that
+    /// differ in their first byte, and by `compare(true)`, comparing equal secrets.
+    /// The classes are interleaved randomly to avoid systematic bias.
+    fn ct_eq_timing_t_statistic<F: FnMut(bool) -> bool>(mut compare: F) -> f64 {
+        use rand::Rng;
+        use std::time::Instant;
+
+        const SAMPLES: usize = 100_000;
+
+        let mut rng = OsRng;
+        let mut timings = [Vec::with_capacity(SAMPLES), Vec::with_capacity(SAMPLES)];
+
+        for _ in 0..SAMPLES {
+            let class = rng.gen::<bool>() as usize;
+
+            let start = Instant::now();
+            let res = compare(class == 1);
+            let elapsed = start.elapsed().as_nanos() as f64;
+
+            assert_eq!(res, class == 1);
+            timings[class].push(elapsed);
+        }
+
+        let mean_var = |v: &[f64]| {
+            let n = v.len() as f64;
+            let mean = v.iter().sum::<f64>() / n;
+            let var = v.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            (mean, var, n)
+        };
+
+        let (m0, v0, n0) = mean_var(timings[0].as_slice());
+        let (m1, v1, n1) = mean_var(timings[1].as_slice());
+        let t = (m0 - m1) / (v0 / n0 + v1 / n1).sqrt();
+
+        println!("mean differing: {}ns, mean equal: {}ns, t: {}", m0, m1, t);
+        t
+    }
+
+    /// Statistical check, in the spirit of dudect, that the time taken by `ct_eq_bytes`, and by
+    /// `ct_eq` on secret keys and signatures, doesn't depend on the position of the first
+    /// differing byte between the compared secrets.
+    /// Being a timing measurement it's sensitive to the machine load, so it's meant to be run
+    /// manually on a quiet machine: `cargo test --release -- --ignored ct_eq_timing_leakage`.
+    #[ignore]
+    #[test]
+    fn ct_eq_timing_leakage() {
+        use algebra::serialize::{CanonicalDeserialize, CanonicalSerialize};
+        use constant_time::*;
+        use schnorr_signature::*;
+        use vrf::*;
+
+        // Welch's t-statistic threshold above which we consider the two classes distinguishable
+        const T_THRESHOLD: f64 = 4.5;
+
+        // Flip the lowest bit of the first serialized byte, keeping the element canonical
+        fn flip_first_bit<T: CanonicalSerialize + CanonicalDeserialize>(t: &T) -> T {
+            let mut bytes = serialize_to_buffer(t, None).unwrap();
+            bytes[0] ^= 1;
+            CanonicalDeserialize::deserialize_uncompressed_unchecked(bytes.as_slice()).unwrap()
+        }
+
+        let check = |name: &str, t: f64| {
+            assert!(
+                t.abs() < T_THRESHOLD,
+                "Timing leak detected comparing {}: t-statistic {} exceeds {}",
+                name,
+                t,
+                T_THRESHOLD
+            )
+        };
+
+        let (pk, sk) = schnorr_generate_key();
+        let sk_bytes = serialize_to_buffer(&sk, None).unwrap();
+        let mut differing_bytes = sk_bytes.clone();
+        differing_bytes[0] ^= 1;
+        check(
+            "bytes",
+            ct_eq_timing_t_statistic(|equal| {
+                let other = if equal { &sk_bytes } else { &differing_bytes };
+                ct_eq_bytes(sk_bytes.as_slice(), other.as_slice())
+            }),
+        );
+
+        let differing_sk = flip_first_bit(&sk);
+        check(
+            "Schnorr secret keys",
+            ct_eq_timing_t_statistic(|equal| {
+                ct_eq(&sk, if equal { &sk } else { &differing_sk }).unwrap()
+            }),
+        );
+
+        let sig = schnorr_sign(&FieldElement::from(1u64), &sk, &pk).unwrap();
+        let differing_sig = flip_first_bit(&sig);
+        check(
+            "Schnorr signatures",
+            ct_eq_timing_t_statistic(|equal| {
+                ct_eq(&sig, if equal { &sig } else { &differing_sig }).unwrap()
+            }),
+        );
+
+        let (_, vrf_sk) = vrf_generate_key();
+        let differing_vrf_sk = flip_first_bit(&vrf_sk);
+        check(
+            "VRF secret keys",
+            ct_eq_timing_t_statistic(|equal| {
+                ct_eq(&vrf_sk, if equal { &vrf_sk } else { &differing_vrf_sk }).unwrap()
+            }),
+        );
+    }
+
+    #[test]
+    fn sample_calls_vrf_output_with_label() {
+        use vrf::*;
+
+        let mut rng = OsRng;
+        let (pk, sk) = vrf_generate_key();
+        let msg = FieldElement::rand(&mut rng);
+        let (proof, output) = vrf_prove(&msg, &sk, &pk).unwrap();
+
+        let label_1 = vec![FieldElement::from(1u64)];
+        let label_2 = vec![FieldElement::from(2u64)];
+
+        // Empty label gives the default output
+        assert_eq!(vrf_output_with_label(&msg, &proof, &[]).unwrap(), output);
+
+        // Different labels give different outputs
+        let output_1 = vrf_output_with_label(&msg, &proof, label_1.as_slice()).unwrap();
+        let output_2 = vrf_output_with_label(&msg, &proof, label_2.as_slice()).unwrap();
+        assert_ne!(output_1, output);
+        assert_ne!(output_2, output);
+        assert_ne!(output_1, output_2);
+
+        // Labels differing only by trailing zeros give different outputs
+        let label_1_and_zero = vec![FieldElement::from(1u64), FieldElement::zero()];
+        assert_ne!(
+            vrf_output_with_label(&msg, &proof, label_1_and_zero.as_slice()).unwrap(),
+            output_1
+        );
+
+        // Prover and verifier sides are consistent
+        assert_eq!(
+            vrf_proof_to_hash_with_labels(
+                &msg,
+                &pk,
+                &proof,
+                &[&[], label_1.as_slice(), label_2.as_slice()]
+            )
+            .unwrap(),
+            vec![output, output_1, output_2]
+        );
+
+        // Labeled outputs are not returned for an invalid proof
+        let wrong_msg = FieldElement::rand(&mut rng);
+        assert_eq!(
+            vrf_proof_to_hash_with_labels(&wrong_msg, &pk, &proof, &[label_1.as_slice()])
+                .unwrap_err(),
+            VRFError::InvalidProof
+        );
+    }
+
+    #[test]
+    fn sample_calls_vrf_batch_proof_to_hash() {
+        use vrf::*;
+
+        let mut rng = OsRng;
+        let samples = 10;
+
+        let mut items = Vec::with_capacity(samples);
+        let mut expected_outputs = Vec::with_capacity(samples);
+        for _ in 0..samples {
+            let (pk, sk) = vrf_generate_key();
+            let msg = FieldElement::rand(&mut rng);
+            let (proof, output) = vrf_prove(&msg, &sk, &pk).unwrap();
+            items.push((msg, pk, proof));
+            expected_outputs.push(output);
+        }
+        let expected_msg_1 = items[1].0;
+        let expected_msg_last = items[samples - 1].0;
+
+        assert_eq!(
+            vrf_batch_proof_to_hash(items.as_slice()).unwrap().unwrap(),
+            expected_outputs
+        );
+
+        //Negative case
+        items[1].0 = FieldElement::rand(&mut rng);
+        items[samples - 1].0 = FieldElement::rand(&mut rng);
+        assert_eq!(
+            vrf_batch_proof_to_hash(items.as_slice())
+                .unwrap()
+                .unwrap_err(),
+            vec![1, samples - 1]
+        );
+
+        //Public key not on curve, found by the per item checks after the batched ones fail
+        items[1].0 = expected_msg_1;
+        items[samples - 1].0 = expected_msg_last;
+        let mut pk_uncompressed = serialize_to_buffer(&items[3].1, Some(false)).unwrap();
+        pk_uncompressed[0] ^= 1;
+        items[3].1 =
+            deserialize_from_buffer::<VRFPk>(&pk_uncompressed, Some(false), Some(false)).unwrap();
+        assert_eq!(
+            vrf_batch_proof_to_hash(items.as_slice())
+                .unwrap()
+                .unwrap_err(),
+            vec![3]
+        );
+    }
+
+    #[test]
+    fn sample_calls_vrf_leader_election() {
+        use leader_election::*;
+
+        let mut rng = OsRng;
+
+        // (stake, total_stake, active_slot_coeff_num, active_slot_coeff_den, threshold)
+        let test_vectors: [(u64, u64, u64, u64, u64); 8] = [
+            (1, 1, 1, 2, 140737488355321),
+            (1, 2, 1, 2, 82442111944221),
+            (1, 10, 1, 20, 1440081400974),
+            (3, 4, 1, 20, 10622696671062),
+            (1, 1, 1, 20, 14073748835530),
+            (0, 5, 1, 20, 0),
+            (1000, 1000000, 1, 20, 14437408570),
+            (5, 5, 99, 100, 278660226943544),
+        ];
+
+        for (stake, total_stake, f_num, f_den, threshold) in test_vectors.iter() {
+            assert_eq!(
+                vrf_leader_threshold(*stake, *total_stake, *f_num, *f_den).unwrap(),
+                *threshold
+            );
+        }
+
+        // Invalid parameters
+        assert!(vrf_leader_threshold(1, 0, 1, 20).is_err());
+        assert!(vrf_leader_threshold(2, 1, 1, 20).is_err());
+        assert!(vrf_leader_threshold(1, 1, 0, 20).is_err());
+        assert!(vrf_leader_threshold(1, 1, 20, 20).is_err());
+
+        // Extreme VRF outputs
+        let lowest = FieldElement::zero();
+        let highest = -FieldElement::one();
+        assert!(vrf_is_slot_leader(&lowest, 1, 100, 1, 20).unwrap());
+        assert!(!vrf_is_slot_leader(&lowest, 0, 100, 1, 20).unwrap());
+        assert!(!vrf_is_slot_leader(&highest, 100, 100, 99, 100).unwrap());
+
+        // With the whole stake and f = 1/2 about half of the slots must be won
+        let samples = 10000;
+        let threshold = vrf_leader_threshold(1, 1, 1, 2).unwrap();
+        let elected = (0..samples)
+            .filter(|_| vrf_is_below_threshold(&FieldElement::rand(&mut rng), threshold))
+            .count();
+        assert!(elected > 4700 && elected < 5300);
+    }
+
+    #[test]
+    fn sample_calls_randomness_beacon() {
+        use randomness_beacon::*;
+
+        let mut rng = OsRng;
+        let initial_nonce = FieldElement::rand(&mut rng);
+        let mut beacon = new_randomness_beacon(&initial_nonce);
+        assert_eq!(beacon.epoch, 0);
+        assert_eq!(beacon.epoch_nonce, initial_nonce);
+
+        let outputs = (0..10)
+            .map(|_| FieldElement::rand(&mut rng))
+            .collect::<Vec<_>>();
+
+        let mut rolling_nonces = vec![];
+        for output in outputs.iter() {
+            absorb_vrf_output(&mut beacon, output).unwrap();
+            rolling_nonces.push(get_rolling_nonce(&beacon));
+        }
+        assert_eq!(beacon.outputs_count, 10);
+
+        // Each absorbed output changes the rolling nonce
+        rolling_nonces.dedup();
+        assert_eq!(rolling_nonces.len(), 10);
+
+        // Serialize/deserialize state
+        let beacon_serialized = serialize_to_buffer(&beacon, None).unwrap();
+        assert_eq!(beacon_serialized.len(), RANDOMNESS_BEACON_SIZE);
+        let beacon_deserialized: RandomnessBeacon =
+            deserialize_from_buffer(&beacon_serialized, Some(true), None).unwrap();
+        assert_eq!(beacon, beacon_deserialized);
+
+        // Same outputs give the same epoch nonce, different ones don't
+        let mut same_beacon = new_randomness_beacon(&initial_nonce);
+        let mut other_beacon = new_randomness_beacon(&initial_nonce);
+        for output in outputs.iter() {
+            absorb_vrf_output(&mut same_beacon, output).unwrap();
+        }
+        for output in outputs.iter().rev() {
+            absorb_vrf_output(&mut other_beacon, output).unwrap();
+        }
+
+        let nonce = end_beacon_epoch(&mut beacon).unwrap();
+        assert_eq!(nonce, end_beacon_epoch(&mut same_beacon).unwrap());
+        assert_ne!(nonce, end_beacon_epoch(&mut other_beacon).unwrap());
+        assert_ne!(nonce, initial_nonce);
+
+        assert_eq!(beacon.epoch, 1);
+        assert_eq!(beacon.epoch_nonce, nonce);
+        assert_eq!(beacon.outputs_count, 0);
+
+        // An epoch without outputs still gives a fresh nonce
+        let next_nonce = end_beacon_epoch(&mut beacon).unwrap();
+        assert_ne!(next_nonce, nonce);
+        assert_eq!(beacon.epoch, 2);
+    }
+
+    #[test]
+    fn sample_calls_vrf_params() {
+        use primitives::crh::pedersen::PedersenWindow;
+        use vrf::*;
+
+        let mut rng = OsRng;
+        let (pk, sk) = vrf_generate_key();
+        let msg = FieldElement::rand(&mut rng);
+
+        // Default params are the ones used by vrf_prove and vrf_proof_to_hash
+        let default_params = VRFParams::default();
+        let default_tags = VRF_GROUP_HASH_GENERATORS_TAGS;
+        let generated_default_params = vrf_generate_params(
+            &default_tags,
+            VRF_GROUP_HASH_GENERATORS_PERSONALIZATION,
+            VRFWindow::WINDOW_SIZE,
+        )
+        .unwrap();
+        assert_eq!(default_params.window(), VRFWindowConfig::Default);
+
+        let (proof, output) = vrf_prove_with_params(&msg, &sk, &pk, &default_params).unwrap();
+        assert_eq!(vrf_proof_to_hash(&msg, &pk, &proof).unwrap(), output);
+        let (generated_proof, generated_output) =
+            vrf_prove_with_params(&msg, &sk, &pk, &generated_default_params).unwrap();
+        assert_eq!(generated_output, output);
+        assert_eq!(
+            vrf_proof_to_hash(&msg, &pk, &generated_proof).unwrap(),
+            output
+        );
+
+        // Proofs computed with custom params only verify with the same params
+        let custom_tags: [&[u8]; 2] = [b"Nickel Ni 28", b"Silver Ag 47"];
+        let custom_params =
+            vrf_generate_params(&custom_tags, b"TestVrfP", VRFWindow::WINDOW_SIZE).unwrap();
+
+        let (custom_proof, custom_output) =
+            vrf_prove_with_params(&msg, &sk, &pk, &custom_params).unwrap();
+        assert_eq!(
+            vrf_proof_to_hash_with_params(&msg, &pk, &custom_proof, &custom_params).unwrap(),
+            custom_output
+        );
+        assert_ne!(custom_output, output);
+        assert_eq!(
+            vrf_proof_to_hash(&msg, &pk, &custom_proof).unwrap_err(),
+            VRFError::InvalidProof
+        );
+        assert_eq!(
+            vrf_proof_to_hash_with_params(&msg, &pk, &proof, &custom_params).unwrap_err(),
+            VRFError::InvalidProof
+        );
+
+        // Small window configuration, taking a tag for each of its windows
+        let small_window_tags: [&[u8]; 4] = [
+            b"Nickel Ni 28",
+            b"Silver Ag 47",
+            b"Copper Cu 29",
+            b"Zinc Zn 30",
+        ];
+        let small_window_params =
+            vrf_generate_params(&small_window_tags, b"TestVrfP", VRFSmallWindow::WINDOW_SIZE)
+                .unwrap();
+        assert_eq!(small_window_params.window(), VRFWindowConfig::Small);
+        assert_eq!(small_window_params.window().num_windows(), 4);
+
+        let (small_window_proof, small_window_output) =
+            vrf_prove_with_params(&msg, &sk, &pk, &small_window_params).unwrap();
+        assert_eq!(
+            vrf_proof_to_hash_with_params(&msg, &pk, &small_window_proof, &small_window_params)
+                .unwrap(),
+            small_window_output
+        );
+        assert_eq!(
+            vrf_proof_to_hash(&msg, &pk, &small_window_proof).unwrap_err(),
+            VRFError::InvalidProof
+        );
+
+        // Invalid params
+        let window_size = VRFWindow::WINDOW_SIZE;
+        assert!(vrf_generate_params(&custom_tags[..1], b"TestVrfP", window_size).is_err());
+        assert!(
+            vrf_generate_params(&[custom_tags[0], custom_tags[0]], b"TestVrfP", window_size)
+                .is_err()
+        );
+        assert!(vrf_generate_params(&custom_tags, b"TestVrfP", 100).is_err());
+        assert!(
+            vrf_generate_params(&custom_tags, b"TestVrfP", VRFSmallWindow::WINDOW_SIZE).is_err()
+        );
+    }
+
+    #[test]
+    fn sample_calls_vrf_prove_deterministic() {
+        use vrf::*;
+
+        let mut rng = OsRng;
+        let (pk, sk) = vrf_generate_key();
+        let msg = FieldElement::rand(&mut rng);
+
+        let (proof, output) = vrf_prove_deterministic(&msg, &sk, &pk).unwrap();
+        assert_eq!(vrf_proof_to_hash(&msg, &pk, &proof).unwrap(), output);
+
+        // Same inputs give the same proof
+        let (proof_dup, output_dup) = vrf_prove_deterministic(&msg, &sk, &pk).unwrap();
+        assert_eq!(proof, proof_dup);
+        assert_eq!(output, output_dup);
+
+        // The output doesn't depend on the nonce
+        let (random_proof, random_output) = vrf_prove(&msg, &sk, &pk).unwrap();
+        assert_ne!(proof, random_proof);
+        assert_eq!(output, random_output);
+
+        // Different messages or keys give different proofs
+        let other_msg = FieldElement::rand(&mut rng);
+        let (other_msg_proof, _) = vrf_prove_deterministic(&other_msg, &sk, &pk).unwrap();
+        assert_ne!(proof.c, other_msg_proof.c);
+
+        let (other_pk, other_sk) = vrf_generate_key();
+        let (other_key_proof, _) = vrf_prove_deterministic(&msg, &other_sk, &other_pk).unwrap();
+        assert_ne!(proof.c, other_key_proof.c);
+    }
+
+    #[test]
+    fn sample_calls_vrf_prove_verify_multiple_messages() {
+        use vrf::*;
+
+        let mut rng = OsRng;
+        let (pk, sk) = vrf_generate_key();
+
+        // Field elements message
+        let fes_msg = (0..5)
+            .map(|_| FieldElement::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let (proof, output) = vrf_prove_field_elements(fes_msg.as_slice(), &sk, &pk).unwrap();
+        assert_eq!(
+            vrf_proof_to_hash_field_elements(fes_msg.as_slice(), &pk, &proof).unwrap(),
+            output
+        );
+        assert_eq!(
+            vrf_proof_to_hash_field_elements(&fes_msg[..4], &pk, &proof).unwrap_err(),
+            VRFError::InvalidProof
+        );
+
+        // A single field element message is not mapped to itself
+        assert!(vrf_proof_to_hash(&fes_msg[0], &pk, &proof).is_err());
+        let (single_proof, _) = vrf_prove_field_elements(&fes_msg[..1], &sk, &pk).unwrap();
+        assert!(vrf_proof_to_hash(&fes_msg[0], &pk, &single_proof).is_err());
+
+        // Bytes message, e.g. epoch nonce followed by slot number
+        let mut bytes_msg = vec![7u8; 32];
+        bytes_msg.extend_from_slice(&42u64.to_le_bytes());
+        let (proof, output) = vrf_prove_bytes(bytes_msg.as_slice(), &sk, &pk).unwrap();
+        assert_eq!(
+            vrf_proof_to_hash_bytes(bytes_msg.as_slice(), &pk, &proof).unwrap(),
+            output
+        );
+
+        // Trailing zeros matter
+        let mut padded_msg = bytes_msg.clone();
+        padded_msg.push(0u8);
+        assert_eq!(
+            vrf_proof_to_hash_bytes(padded_msg.as_slice(), &pk, &proof).unwrap_err(),
+            VRFError::InvalidProof
+        );
+
+        // Empty message
+        let (proof, output) = vrf_prove_bytes(&[], &sk, &pk).unwrap();
+        assert_eq!(vrf_proof_to_hash_bytes(&[], &pk, &proof).unwrap(), output);
+    }
+
+    #[test]
+    fn sample_calls_key_rotation() {
+        use key_rotation::*;
+        use schnorr_signature::*;
+        use vrf::*;
+
+        // Schnorr key rotation chain
+        let keys = (0..4).map(|_| schnorr_generate_key()).collect::<Vec<_>>();
+        let certificates = keys
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let (old_pk, old_sk) = &pair[0];
+                let (new_pk, _) = &pair[1];
+                create_schnorr_key_rotation_certificate(old_sk, old_pk, new_pk, 10 * i as u64)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        for certificate in certificates.iter() {
+            assert!(verify_key_rotation_certificate(certificate).unwrap());
+
+            //Serialize/deserialize certificate
+            let certificate_serialized = serialize_to_buffer(certificate, Some(true)).unwrap();
+            assert_eq!(certificate_serialized.len(), KEY_ROTATION_CERTIFICATE_SIZE);
+            let certificate_deserialized: KeyRotationCertificate =
+                deserialize_from_buffer(&certificate_serialized, Some(true), Some(true)).unwrap();
+            assert_eq!(certificate, &certificate_deserialized);
+        }
+
+        assert_eq!(
+            verify_key_rotation_chain(RotatedKeyType::Schnorr, &keys[0].0, &certificates).unwrap(),
+            keys[3].0
+        );
+        assert_eq!(
+            verify_key_rotation_chain(RotatedKeyType::Schnorr, &keys[0].0, &[]).unwrap(),
+            keys[0].0
+        );
+
+        // Negative cases: wrong key type, wrong initial key, missing link, tampered epoch
+        assert!(verify_key_rotation_chain(RotatedKeyType::VRF, &keys[0].0, &certificates).is_err());
+        assert!(
+            verify_key_rotation_chain(RotatedKeyType::Schnorr, &keys[1].0, &certificates).is_err()
+        );
+        assert!(verify_key_rotation_chain(
+            RotatedKeyType::Schnorr,
+            &keys[0].0,
+            &[certificates[0].clone(), certificates[2].clone()]
+        )
+        .is_err());
+
+        let mut tampered = certificates[1].clone();
+        tampered.epoch += 1;
+        assert!(!verify_key_rotation_certificate(&tampered).unwrap());
+
+        let mut tampered_chain = certificates.clone();
+        tampered_chain[1] = tampered;
+        assert!(
+            verify_key_rotation_chain(RotatedKeyType::Schnorr, &keys[0].0, &tampered_chain)
+                .is_err()
+        );
+
+        // Non increasing epochs
+        let (pk_0, sk_0) = &keys[0];
+        let (pk_1, sk_1) = &keys[1];
+        let (pk_2, _) = &keys[2];
+        let same_epoch_chain = vec![
+            create_schnorr_key_rotation_certificate(sk_0, pk_0, pk_1, 5).unwrap(),
+            create_schnorr_key_rotation_certificate(sk_1, pk_1, pk_2, 5).unwrap(),
+        ];
+        assert!(
+            verify_key_rotation_chain(RotatedKeyType::Schnorr, pk_0, &same_epoch_chain).is_err()
+        );
+
+        // VRF key rotation
+        let (vrf_pk_0, vrf_sk_0) = vrf_generate_key();
+        let (vrf_pk_1, _) = vrf_generate_key();
+        let vrf_certificate =
+            create_vrf_key_rotation_certificate(&vrf_sk_0, &vrf_pk_0, &vrf_pk_1, 1).unwrap();
+        assert!(verify_key_rotation_certificate(&vrf_certificate).unwrap());
+        assert_eq!(
+            verify_key_rotation_chain(RotatedKeyType::VRF, &vrf_pk_0, &[vrf_certificate.clone()])
+                .unwrap(),
+            vrf_pk_1
+        );
+
+        // A certificate can't be reused for another key type
+        let mut wrong_type_certificate = vrf_certificate;
+        wrong_type_certificate.key_type = RotatedKeyType::Schnorr;
+        assert!(!verify_key_rotation_certificate(&wrong_type_certificate).unwrap());
+    }
+
+    #[test]
+    fn sample_calls_forward_secure_signature() {
+        use forward_secure_signature::*;
+
+        let height = 3;
+        let (pk, mut sk) = forward_secure_generate_key(height).unwrap();
+        assert_eq!(forward_secure_get_public_key(&sk).unwrap(), pk);
+        assert!(forward_secure_generate_key(0).is_err());
+        assert!(forward_secure_generate_key(FORWARD_SECURE_MAX_HEIGHT + 1).is_err());
+
+        let msg = FieldElement::rand(&mut rand::thread_rng());
+
+        // Sign at period 0
+        let sig_0 = forward_secure_sign(&msg, &sk).unwrap();
+        assert_eq!(sig_0.period, 0);
+        assert!(forward_secure_verify(&msg, &pk, &sig_0, 0).unwrap());
+        assert!(!forward_secure_verify(&msg, &pk, &sig_0, 1).unwrap());
+        assert!(!forward_secure_verify(&(msg + FieldElement::one()), &pk, &sig_0, 0).unwrap());
+
+        // The signature can't be moved to another period
+        let mut moved_sig = sig_0.clone();
+        moved_sig.period = 1;
+        assert!(!forward_secure_verify(&msg, &pk, &moved_sig, 1).unwrap());
+
+        // The Merkle path kept in the secret key is updated at each period
+        let (all_periods_pk, mut all_periods_sk) = forward_secure_generate_key(height).unwrap();
+        for period in 0..forward_secure_num_periods(height).unwrap() {
+            forward_secure_update_to(&mut all_periods_sk, period).unwrap();
+            assert!(is_valid(&all_periods_sk));
+            let sig = forward_secure_sign(&msg, &all_periods_sk).unwrap();
+            assert!(forward_secure_verify(&msg, &all_periods_pk, &sig, period).unwrap());
+        }
+
+        // Update and sign at the following periods
+        forward_secure_update(&mut sk).unwrap();
+        forward_secure_update_to(&mut sk, 5).unwrap();
+        assert_eq!(sk.period, 5);
+        let sig_5 = forward_secure_sign(&msg, &sk).unwrap();
+        assert!(forward_secure_verify(&msg, &pk, &sig_5, 5).unwrap());
+        assert_ne!(sig_5.period_pk, sig_0.period_pk);
+
+        // Past periods can't be reached anymore
+        assert!(forward_secure_update_to(&mut sk, 4).is_err());
+
+        // Serialization round trip
+        let pk_serialized = serialize_to_buffer(&pk, None).unwrap();
+        assert_eq!(pk_serialized.len(), FORWARD_SECURE_PK_SIZE);
+        let pk_deserialized: ForwardSecurePk =
+            deserialize_from_buffer(&pk_serialized, Some(true), None).unwrap();
+        assert_eq!(pk, pk_deserialized);
+
+        let sk_serialized = serialize_to_buffer(&sk, None).unwrap();
+        let sk_deserialized: ForwardSecureSk =
+            deserialize_from_buffer(&sk_serialized, Some(true), None).unwrap();
+        assert!(constant_time::ct_eq(&sk, &sk_deserialized).unwrap());
+
+        let sig_serialized = serialize_to_buffer(&sig_5, Some(true)).unwrap();
+        let sig_deserialized: ForwardSecureSig =
+            deserialize_from_buffer(&sig_serialized, Some(true), Some(true)).unwrap();
+        assert_eq!(sig_5, sig_deserialized);
+
+        // Last period reached
+        forward_secure_update_to(&mut sk, forward_secure_num_periods(height).unwrap() - 1).unwrap();
+        assert!(forward_secure_num_periods(64).is_err());
+        assert!(forward_secure_update(&mut sk).is_err());
+        let sig_last = forward_secure_sign(&msg, &sk).unwrap();
+        assert!(forward_secure_verify(&msg, &pk, &sig_last, 7).unwrap());
+
+        // A signature doesn't verify under another key
+        let (other_pk, _) = forward_secure_generate_key(height).unwrap();
+        assert!(!forward_secure_verify(&msg, &other_pk, &sig_last, 7).unwrap());
+    }
+
+    #[test]
+    fn sample_calls_serialization_envelope() {
+        use schnorr_signature::*;
+
+        let (pk, _) = schnorr_generate_key();
+
+        // Round trip, with and without compression and checksum
+        for &(compressed, with_checksum) in
+            &[(true, true), (true, false), (false, true), (false, false)]
+        {
+            let envelope = serialize_enveloped(
+                &pk,
+                ObjectType::SchnorrPublicKey,
+                Some(compressed),
+                with_checksum,
+            )
+            .unwrap();
+            let payload = serialize_to_buffer(&pk, Some(compressed)).unwrap();
+            let checksum_len = if with_checksum {
+                ENVELOPE_CHECKSUM_SIZE
+            } else {
+                0
+            };
+            assert_eq!(
+                envelope.len(),
+                ENVELOPE_HEADER_SIZE + payload.len() + checksum_len
+            );
+            assert_eq!(
+                &envelope[ENVELOPE_HEADER_SIZE..ENVELOPE_HEADER_SIZE + payload.len()],
+                payload.as_slice()
+            );
+
+            let pk_deserialized: SchnorrPk =
+                deserialize_enveloped(&envelope, ObjectType::SchnorrPublicKey, Some(true)).unwrap();
+            assert_eq!(pk, pk_deserialized);
+        }
+
+        let envelope =
+            serialize_enveloped(&pk, ObjectType::SchnorrPublicKey, Some(true), true).unwrap();
+
+        // Wrong object type
+        assert!(deserialize_enveloped::<SchnorrPk>(
+            &envelope,
+            ObjectType::VRFPublicKey,
+            Some(true)
+        )
+        .is_err());
+
+        // Tampered magic, version, curve and flags
+        for &i in &[0, 4, 5, 7] {
+            let mut tampered = envelope.clone();
+            tampered[i] ^= 0xff;
+            assert!(deserialize_enveloped::<SchnorrPk>(
+                &tampered,
+                ObjectType::SchnorrPublicKey,
+                Some(true)
+            )
+            .is_err());
+        }
+
+        // Tampered payload is caught by the checksum
+        let mut tampered = envelope.clone();
+        tampered[ENVELOPE_HEADER_SIZE] ^= 1;
+        assert!(deserialize_enveloped::<SchnorrPk>(
+            &tampered,
+            ObjectType::SchnorrPublicKey,
+            Some(true)
+        )
+        .is_err());
+
+        // Truncated and oversized envelopes
+        assert!(deserialize_enveloped::<SchnorrPk>(
+            &envelope[..envelope.len() - 1],
+            ObjectType::SchnorrPublicKey,
+            Some(true)
+        )
+        .is_err());
+        assert!(deserialize_enveloped::<SchnorrPk>(
+            &envelope[..ENVELOPE_HEADER_SIZE - 1],
+            ObjectType::SchnorrPublicKey,
+            Some(true)
+        )
+        .is_err());
+        let mut oversized = envelope;
+        oversized.push(0);
+        assert!(deserialize_enveloped::<SchnorrPk>(
+            &oversized,
+            ObjectType::SchnorrPublicKey,
+            Some(true)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn sample_calls_deserialization_error_codes() {
+        use algebra::SerializationError;
+        use schnorr_signature::*;
+
+        let code = |e: SerializationError| deserialization_error_code(&e);
+
+        let (pk, _) = schnorr_generate_key();
+        let pk_bytes = serialize_to_buffer(&pk, Some(true)).unwrap();
+
+        // Not enough bytes
+        let e = deserialize_from_buffer::<SchnorrPk>(
+            &pk_bytes[..pk_bytes.len() - 1],
+            Some(true),
+            Some(true),
+        )
+        .unwrap_err();
+        assert_eq!(code(e), DeserializationErrorCode::NotEnoughBytes);
+
+        // Trailing bytes
+        let mut oversized = pk_bytes.clone();
+        oversized.push(0);
+        let e = deserialize_from_buffer_strict::<SchnorrPk>(&oversized, Some(true), Some(true))
+            .unwrap_err();
+        assert_eq!(code(e), DeserializationErrorCode::TrailingBytes);
+
+        // Non canonical field element
+        let e = deserialize_from_buffer::<FieldElement>(&vec![0xffu8; FIELD_SIZE], None, None)
+            .unwrap_err();
+        assert_eq!(code(e), DeserializationErrorCode::NonCanonicalFieldElement);
+
+        // Uncompressed point not on the curve
+        let mut pk_uncompressed = serialize_to_buffer(&pk, Some(false)).unwrap();
+        pk_uncompressed[0] ^= 1;
+        let e = deserialize_from_buffer::<SchnorrPk>(&pk_uncompressed, Some(true), Some(false))
+            .unwrap_err();
+        assert_eq!(code(e), DeserializationErrorCode::InvalidPoint);
+
+        // Invalid envelope
+        let envelope =
+            serialize_enveloped(&pk, ObjectType::SchnorrPublicKey, Some(true), false).unwrap();
+        let e = deserialize_enveloped::<SchnorrPk>(&envelope, ObjectType::VRFPublicKey, Some(true))
+            .unwrap_err();
+        assert_eq!(code(e), DeserializationErrorCode::InvalidEnvelope);
+
+        // Other I/O errors
+        let e =
+            read_from_file::<SchnorrPk>("./not_existing_file", Some(true), Some(true)).unwrap_err();
+        assert_eq!(code(e), DeserializationErrorCode::Io);
+
+        // Trailing bytes in a file are ignored only by the non strict read
+        let file_path = std::env::temp_dir().join(format!("trailing_bytes_{}", std::process::id()));
+        let mut buffer = serialize_to_buffer(&pk, Some(true)).unwrap();
+        buffer.push(0u8);
+        std::fs::write(&file_path, buffer).unwrap();
+        let file_path_str = file_path.to_str().unwrap();
+        let pk_read = read_from_file::<SchnorrPk>(file_path_str, Some(true), Some(true));
+        let e =
+            read_from_file_strict::<SchnorrPk>(file_path_str, Some(true), Some(true)).unwrap_err();
+        std::fs::remove_file(&file_path).unwrap();
+        assert_eq!(pk_read.unwrap(), pk);
+        assert_eq!(code(e), DeserializationErrorCode::TrailingBytes);
+    }
+
+    #[test]
+    fn sample_calls_write_to_file_atomic() {
+        use schnorr_signature::*;
+
+        let dir = std::env::temp_dir().join(format!("write_to_file_atomic_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file_path = dir.join("pk");
+        let file_path = file_path.to_str().unwrap();
+
+        // Write and overwrite a file
+        let (pk_1, _) = schnorr_generate_key();
+        let (pk_2, _) = schnorr_generate_key();
+        write_to_file(&pk_1, file_path, Some(true)).unwrap();
+        assert_eq!(
+            pk_1,
+            read_from_file::<SchnorrPk>(file_path, Some(true), Some(true)).unwrap()
+        );
+        write_to_file(&pk_2, file_path, Some(false)).unwrap();
+        assert_eq!(
+            pk_2,
+            read_from_file::<SchnorrPk>(file_path, Some(true), Some(false)).unwrap()
+        );
+
+        // No temporary file must be left behind
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        // A failed write must leave no temporary file behind either
+        assert!(write_to_file(
+            &pk_1,
+            dir.join("missing_dir").join("pk").to_str().unwrap(),
+            None
+        )
+        .is_err());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn sample_calls_text_encoding() {
+        use merkle_tree::*;
+        use schnorr_signature::*;
+        use text_encoding::*;
+        use vrf::*;
+
+        // Field element
+        let fe = get_random_field_element(0);
+        let fe_hex = to_hex(&fe).unwrap();
+        assert_eq!(fe_hex.len(), 2 * FIELD_SIZE);
+        assert_eq!(fe, from_hex::<FieldElement>(fe_hex.as_str()).unwrap());
+        assert_eq!(
+            fe,
+            from_hex::<FieldElement>(fe_hex.to_uppercase().as_str()).unwrap()
+        );
+        assert_eq!(format!("\"{}\"", fe_hex), fe.to_json().unwrap());
+        assert_eq!(fe, FieldElement::from_json(&fe.to_json().unwrap()).unwrap());
+
+        // Schnorr public key and signature
+        let (pk, sk) = schnorr_generate_key();
+        let sig = schnorr_sign(&fe, &sk, &pk).unwrap();
+        assert_eq!(pk, from_hex::<SchnorrPk>(&to_hex(&pk).unwrap()).unwrap());
+        assert_eq!(pk, SchnorrPk::from_json(&pk.to_json().unwrap()).unwrap());
+
+        let sig_json = sig.to_json().unwrap();
+        assert_eq!(
+            sig_json,
+            format!(
+                "{{\"e\":\"{}\",\"s\":\"{}\"}}",
+                to_hex(&sig.e).unwrap(),
+                to_hex(&sig.s).unwrap()
+            )
+        );
+        let decoded_sig = SchnorrSig::from_json(&sig_json).unwrap();
+        assert!(schnorr_verify_signature(&fe, &pk, &decoded_sig).unwrap());
+        let decoded_sig = from_hex::<SchnorrSig>(&to_hex(&sig).unwrap()).unwrap();
+        assert!(schnorr_verify_signature(&fe, &pk, &decoded_sig).unwrap());
+
+        // VRF proof
+        let (vrf_pk, vrf_sk) = vrf_generate_key();
+        let (proof, output) = vrf_prove(&fe, &vrf_sk, &vrf_pk).unwrap();
+        let decoded_proof = VRFProof::from_json(&proof.to_json().unwrap()).unwrap();
+        assert_eq!(to_hex(&proof).unwrap(), to_hex(&decoded_proof).unwrap());
+        assert_eq!(
+            output,
+            vrf_proof_to_hash(&fe, &vrf_pk, &decoded_proof).unwrap()
+        );
+
+        // Merkle path
+        let height = 5;
+        let mut mht = new_ginger_mht(height, 1 << height).unwrap();
+        for i in 0..1 << height {
+            append_leaf_to_ginger_mht(&mut mht, &get_random_field_element(i)).unwrap();
+        }
+        finalize_ginger_mht_in_place(&mut mht).unwrap();
+        let path = get_ginger_mht_path(&mht, 3).unwrap();
+        let path_json = path.to_json().unwrap();
+        assert!(path_json.starts_with("{\"path\":[{\"sibling\":\""));
+        let decoded_path = GingerMHTPath::from_json(&path_json).unwrap();
+        assert_eq!(path, decoded_path);
+        assert_eq!(get_leaf_index_from_path(&decoded_path), 3);
+        assert_eq!(
+            path,
+            from_hex::<GingerMHTPath>(&to_hex(&path).unwrap()).unwrap()
+        );
+
+        // Invalid encodings
+        let code = |e: algebra::SerializationError| deserialization_error_code(&e);
+        assert_eq!(
+            code(from_hex::<FieldElement>("not hex").unwrap_err()),
+            DeserializationErrorCode::MalformedData
+        );
+        assert_eq!(
+            code(from_hex::<FieldElement>(&format!("{}00", fe_hex)).unwrap_err()),
+            DeserializationErrorCode::TrailingBytes
+        );
+        assert_eq!(
+            code(SchnorrSig::from_json("{\"e\":\"00\"}").unwrap_err()),
+            DeserializationErrorCode::MalformedData
+        );
+    }
+
+    #[test]
+    fn sample_calls_test_vectors() {
+        use test_vectors::*;
+
+        let vectors = generate_test_vectors().unwrap();
+        assert_eq!(vectors, generate_test_vectors().unwrap());
+        assert_eq!(
+            vectors,
+            decode_test_vectors(&encode_test_vectors(&vectors)).unwrap()
+        );
+
+        // A changed vector is reported
+        let mut changed = vectors.clone();
+        changed[0].data[0] ^= 1;
+        assert!(check_test_vectors(&vectors, &changed).is_err());
+
+        // The corpus is committed: it is (re)generated only with UPDATE_TEST_VECTORS=1, after a
+        // deliberate change bumping TEST_VECTORS_VERSION.
+        let file_path = test_vectors_file_path();
+        if std::env::var("UPDATE_TEST_VECTORS").map_or(false, |v| v == "1") {
+            write_test_vectors(&vectors, &file_path).unwrap();
+            println!("Test vectors written to {}", file_path.display());
+            return;
+        }
+        if !file_path.exists() {
+            panic!(
+                "Missing test vectors file {}: generate it with UPDATE_TEST_VECTORS=1 and commit it",
+                file_path.display()
+            );
+        }
+        let expected = read_test_vectors(&file_path).unwrap();
+        if let Err(e) = check_test_vectors(&expected, &vectors) {
+            panic!("{}", e);
+        }
+
+        // The stored encodings of all the supported versions are read and upgraded
+        for version in MerkleEncodingVersion::ALL.iter() {
+            let fixture = |name: &str| {
+                get_test_vector(
+                    &expected,
+                    &format!("migration/{}/v{}", name, *version as u8),
+                )
+                .unwrap()
+            };
+
+            let (tree, tree_version) = migrate_ginger_mht(
+                fixture("merkle_tree"),
+                merkle_tree::DEFAULT_MAX_GINGER_MHT_HEIGHT,
+            )
+            .unwrap();
+            assert_eq!(tree_version, *version);
+            assert_eq!(
+                serialize_to_buffer(&merkle_tree::get_ginger_mht_root(&tree).unwrap(), None)
+                    .unwrap(),
+                get_test_vector(&expected, "merkle_tree/root").unwrap()
+            );
+
+            let (path, path_version) = migrate_ginger_mht_path(fixture("merkle_path")).unwrap();
+            assert_eq!(path_version, *version);
+            assert_eq!(
+                serialize_to_buffer(&path, None).unwrap(),
+                get_test_vector(&expected, "merkle_tree/path/7").unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn sample_calls_merkle_migration() {
+        use merkle_tree::*;
+
+        let height = 4;
+        let mut mht = new_ginger_mht(height, 1 << height).unwrap();
+        for i in 0..10 {
+            append_leaf_to_ginger_mht(&mut mht, &get_random_field_element(i)).unwrap();
+        }
+        finalize_ginger_mht_in_place(&mut mht).unwrap();
+        let path = get_ginger_mht_path(&mht, 3).unwrap();
+
+        for version in MerkleEncodingVersion::ALL.iter() {
+            let tree_bytes = encode_ginger_mht(&mht, *version).unwrap();
+            let (migrated_tree, tree_version) = migrate_ginger_mht(&tree_bytes, height).unwrap();
+            assert_eq!(tree_version, *version);
+            assert_eq!(
+                serialize_to_buffer(&migrated_tree, None).unwrap(),
+                serialize_to_buffer(&mht, None).unwrap()
+            );
+
+            let path_bytes = encode_ginger_mht_path(&path, *version).unwrap();
+            let (migrated_path, path_version) = migrate_ginger_mht_path(&path_bytes).unwrap();
+            assert_eq!(path_version, *version);
+            assert_eq!(migrated_path, path);
+
+            // Trailing bytes are rejected
+            let mut oversized_tree_bytes = tree_bytes.clone();
+            oversized_tree_bytes.push(0u8);
+            assert!(migrate_ginger_mht(&oversized_tree_bytes, height).is_err());
+        }
+
+        // A path isn't read as a tree, and vice versa
+        assert!(migrate_ginger_mht(
+            &encode_ginger_mht_path(&path, MerkleEncodingVersion::EnvelopeV1).unwrap(),
+            height
+        )
+        .is_err());
+        assert!(migrate_ginger_mht_path(
+            &encode_ginger_mht(&mht, MerkleEncodingVersion::EnvelopeV1).unwrap()
+        )
+        .is_err());
+        assert!(migrate_ginger_mht(
+            &encode_ginger_mht_path(&path, MerkleEncodingVersion::Raw).unwrap()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn sample_calls_validate_ginger_mht() {
+        use merkle_tree::*;
+
+        let height = 5;
+        let mut mht = new_ginger_mht(height, 1 << height).unwrap();
+        for i in 0..20 {
+            append_leaf_to_ginger_mht(&mut mht, &get_random_field_element(i)).unwrap();
+        }
+
+        // Both non finalized and finalized trees are consistent
+        validate_ginger_mht(&mht, height).unwrap();
+        finalize_ginger_mht_in_place(&mut mht).unwrap();
+        validate_ginger_mht(&mht, height).unwrap();
+
+        let mht_bytes = serialize_to_buffer(&mht, None).unwrap();
+        let mht_deserialized = deserialize_ginger_mht(mht_bytes.as_slice(), Some(height)).unwrap();
+        assert_eq!(
+            get_ginger_mht_root(&mht).unwrap(),
+            get_ginger_mht_root(&mht_deserialized).unwrap()
+        );
+
+        // The bound on the height is configurable, and not applied to unchecked reads
+        assert!(validate_ginger_mht(&mht, height - 1).is_err());
+        assert!(deserialize_ginger_mht(mht_bytes.as_slice(), Some(height - 1)).is_err());
+        deserialize_ginger_mht(mht_bytes.as_slice(), None).unwrap();
+
+        // Trees are rebuilt with their own processing step
+        let mut mht_step = new_ginger_mht(height, 4).unwrap();
+        for i in 0..7 {
+            append_leaf_to_ginger_mht(&mut mht_step, &get_random_field_element(i)).unwrap();
+            validate_ginger_mht(&mht_step, height).unwrap();
+        }
+        finalize_ginger_mht_in_place(&mut mht_step).unwrap();
+        validate_ginger_mht(&mht_step, height).unwrap();
+
+        // Replace the first occurrence of the root in the serialized tree
+        let root_bytes = serialize_to_buffer(&get_ginger_mht_root(&mht).unwrap(), None).unwrap();
+        let other_root_bytes = serialize_to_buffer(&get_random_field_element(42), None).unwrap();
+        let root_position = mht_bytes
+            .windows(root_bytes.len())
+            .position(|w| w == root_bytes.as_slice())
+            .unwrap();
+        let mut corrupted_bytes = mht_bytes.clone();
+        corrupted_bytes[root_position..root_position + root_bytes.len()]
+            .copy_from_slice(other_root_bytes.as_slice());
+
+        let corrupted = deserialize_ginger_mht(corrupted_bytes.as_slice(), None).unwrap();
+        assert!(validate_ginger_mht(&corrupted, height).is_err());
+        assert_eq!(
+            deserialization_error_code(
+                &deserialize_ginger_mht(corrupted_bytes.as_slice(), Some(height)).unwrap_err()
+            ),
+            DeserializationErrorCode::SemanticChecksFailed
+        );
+    }
+
+    #[test]
+    fn sample_calls_compact_merkle_path() {
+        use merkle_tree::*;
+
+        let height = 10;
+        let num_leaves = 100;
+        let mut mht = new_ginger_mht(height, 1 << height).unwrap();
+        for i in 0..num_leaves {
+            append_leaf_to_ginger_mht(&mut mht, &get_random_field_element(i)).unwrap();
+        }
+        finalize_ginger_mht_in_place(&mut mht).unwrap();
+
+        for leaf_index in vec![0, 37, num_leaves - 1, num_leaves] {
+            let path = get_ginger_mht_path(&mht, leaf_index).unwrap();
+            let compact_bytes = encode_ginger_mht_path_compact(&path).unwrap();
+            let decoded_path = decode_ginger_mht_path_compact(&compact_bytes, true).unwrap();
+            assert_eq!(path, decoded_path);
+            assert_eq!(get_leaf_index_from_path(&decoded_path), leaf_index);
+
+            // Most of the siblings on the right of the last leaf are empty subtrees
+            assert!(compact_bytes.len() < serialize_to_buffer(&path, None).unwrap().len());
+        }
+
+        let path = get_ginger_mht_path(&mht, 37).unwrap();
+        let compact_bytes = encode_ginger_mht_path_compact(&path).unwrap();
+        let code = |e: algebra::SerializationError| deserialization_error_code(&e);
+
+        // Truncated and oversized encodings
+        assert_eq!(
+            code(
+                decode_ginger_mht_path_compact(&compact_bytes[..compact_bytes.len() - 1], true)
+                    .unwrap_err()
+            ),
+            DeserializationErrorCode::NotEnoughBytes
+        );
+        let mut oversized_bytes = compact_bytes.clone();
+        oversized_bytes.push(0);
+        assert_eq!(
+            code(decode_ginger_mht_path_compact(&oversized_bytes, true).unwrap_err()),
+            DeserializationErrorCode::TrailingBytes
+        );
+
+        // Leaf index not fitting the path length
+        let mut invalid_bytes = compact_bytes.clone();
+        invalid_bytes[1 + 8 - 1] = 0xff;
+        assert_eq!(
+            code(decode_ginger_mht_path_compact(&invalid_bytes, true).unwrap_err()),
+            DeserializationErrorCode::MalformedData
+        );
+    }
+
+    #[test]
+    fn sample_calls_domain_tags_are_unique() {
+        use domain_tags::*;
+
+        for (i, tag) in ALL_DOMAIN_TAGS.iter().enumerate() {
+            assert!(
+                !ALL_DOMAIN_TAGS[..i].contains(tag),
+                "Duplicated tag {}",
+                tag
+            );
+        }
     }
 }
@@ -1,4 +1,4 @@
-use super::*;
+use super::{field_element::read_field_elements_from_bytes, *};
 use primitives::crh::*;
 pub fn get_poseidon_hash_constant_length(
     input_size: usize,
@@ -62,3 +62,29 @@ pub fn finalize_poseidon_hash(hash: &FieldHash) -> Result<FieldElement, Error> {
     let result = hash.finalize()?;
     Ok(result)
 }
+
+/// Compute the hash of `input` using a variable length Poseidon instance,
+/// personalized with `personalization`.
+pub fn compute_poseidon_hash(
+    input: &[FieldElement],
+    personalization: Option<&[FieldElement]>,
+) -> Result<FieldElement, Error> {
+    let mut h = FieldHash::init_variable_length(false, personalization);
+    input.iter().for_each(|fe| {
+        h.update(*fe);
+    });
+    finalize_poseidon_hash(&h)
+}
+
+/// Compute the hash of `input` bytes using a variable length Poseidon instance,
+/// personalized with `personalization`.
+/// The bytes are packed into field elements and prefixed with their length, in order to
+/// avoid collisions between inputs differing only by trailing zeros.
+pub fn compute_poseidon_hash_from_bytes(
+    input: &[u8],
+    personalization: Option<&[FieldElement]>,
+) -> Result<FieldElement, Error> {
+    let mut fes = vec![FieldElement::from(input.len() as u64)];
+    fes.extend(read_field_elements_from_bytes(input)?);
+    compute_poseidon_hash(fes.as_slice(), personalization)
+}
@@ -0,0 +1,135 @@
+use super::{domain_tags::*, serialization::DeserializationChecks, *};
+use algebra::{serialize::*, SemanticallyValid};
+use primitives::crh::FieldBasedHash;
+
+/// Randomness beacon deriving epoch nonces from the (verified) VRF outputs of the blocks.
+/// During an epoch the VRF outputs are absorbed, one by one, into a rolling accumulator;
+/// at the epoch boundary the accumulator, chained with the current nonce, gives the nonce
+/// of the next epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RandomnessBeacon {
+    /// Current epoch, starting from 0
+    pub epoch: u64,
+    /// Nonce of the current epoch
+    pub epoch_nonce: FieldElement,
+    /// Rolling accumulator of the VRF outputs absorbed in the current epoch
+    pub accumulator: FieldElement,
+    /// Number of VRF outputs absorbed in the current epoch
+    pub outputs_count: u64,
+}
+
+pub const RANDOMNESS_BEACON_SIZE: usize = 2 * FIELD_SIZE + 16;
+
+impl CanonicalSerialize for RandomnessBeacon {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.epoch, &mut writer)?;
+        CanonicalSerialize::serialize(&self.epoch_nonce, &mut writer)?;
+        CanonicalSerialize::serialize(&self.accumulator, &mut writer)?;
+        CanonicalSerialize::serialize(&self.outputs_count, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.epoch.serialized_size()
+            + self.epoch_nonce.serialized_size()
+            + self.accumulator.serialized_size()
+            + self.outputs_count.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize(writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        self.serialized_size()
+    }
+}
+
+impl CanonicalDeserialize for RandomnessBeacon {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let epoch = CanonicalDeserialize::deserialize(&mut reader)?;
+        let epoch_nonce = CanonicalDeserialize::deserialize(&mut reader)?;
+        let accumulator = CanonicalDeserialize::deserialize(&mut reader)?;
+        let outputs_count = CanonicalDeserialize::deserialize(&mut reader)?;
+        Ok(Self {
+            epoch,
+            epoch_nonce,
+            accumulator,
+            outputs_count,
+        })
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+}
+
+impl SemanticallyValid for RandomnessBeacon {
+    fn is_valid(&self) -> bool {
+        self.epoch_nonce.is_valid() && self.accumulator.is_valid()
+    }
+}
+
+impl DeserializationChecks for RandomnessBeacon {}
+
+fn hash_with_domain_tag(tag: u64, inputs: &[FieldElement]) -> Result<FieldElement, Error> {
+    let mut h = FieldHash::init_constant_length(inputs.len(), Some(&[FieldElement::from(tag)]));
+    inputs.iter().for_each(|input| {
+        h.update(*input);
+    });
+    h.finalize()
+}
+
+/// Create a beacon at epoch 0, whose nonce is `initial_nonce` (e.g. derived from the genesis block).
+pub fn new_randomness_beacon(initial_nonce: &FieldElement) -> RandomnessBeacon {
+    RandomnessBeacon {
+        epoch: 0,
+        epoch_nonce: *initial_nonce,
+        accumulator: *initial_nonce,
+        outputs_count: 0,
+    }
+}
+
+/// Absorb into the accumulator of `beacon` the VRF output of a block of the current epoch.
+/// The VRF output is expected to be verified beforehand, e.g. through `vrf_proof_to_hash`.
+pub fn absorb_vrf_output(
+    beacon: &mut RandomnessBeacon,
+    vrf_output: &FieldElement,
+) -> Result<(), Error> {
+    beacon.accumulator =
+        hash_with_domain_tag(BEACON_ABSORB_DOMAIN_TAG, &[beacon.accumulator, *vrf_output])?;
+    beacon.outputs_count += 1;
+    Ok(())
+}
+
+/// Return the rolling nonce of `beacon`, i.e. the value of the accumulator after the last absorbed VRF output.
+pub fn get_rolling_nonce(beacon: &RandomnessBeacon) -> FieldElement {
+    beacon.accumulator
+}
+
+/// Close the current epoch of `beacon`, deriving and returning the nonce of the next one as
+/// H(epoch_nonce, accumulator, epoch). The accumulator of the new epoch starts from the new nonce.
+pub fn end_beacon_epoch(beacon: &mut RandomnessBeacon) -> Result<FieldElement, Error> {
+    let next_nonce = hash_with_domain_tag(
+        BEACON_EPOCH_DOMAIN_TAG,
+        &[
+            beacon.epoch_nonce,
+            beacon.accumulator,
+            FieldElement::from(beacon.epoch),
+        ],
+    )?;
+
+    beacon.epoch += 1;
+    beacon.epoch_nonce = next_nonce;
+    beacon.accumulator = next_nonce;
+    beacon.outputs_count = 0;
+
+    Ok(next_nonce)
+}
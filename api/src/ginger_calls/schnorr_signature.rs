@@ -1,7 +1,12 @@
-use super::*;
-use algebra::{AffineCurve, ProjectiveCurve};
-use primitives::{schnorr::field_based_schnorr::*, signature::FieldBasedSignatureScheme};
-use rand::rngs::OsRng;
+use super::{domain_tags::*, poseidon_hash::*, serialization::DeserializationChecks, *};
+use algebra::{
+    convert, serialize::*, AffineCurve, Field, PrimeField, ProjectiveCurve, SemanticallyValid,
+    ToConstraintField, UniformRand,
+};
+use primitives::{
+    crh::FieldBasedHash, schnorr::field_based_schnorr::*, signature::FieldBasedSignatureScheme,
+};
+use rand::{rngs::OsRng, RngCore};
 
 pub fn schnorr_generate_key() -> (SchnorrPk, SchnorrSk) {
     let mut rng = OsRng;
@@ -38,3 +43,589 @@ pub fn schnorr_verify_signature(
 ) -> Result<bool, Error> {
     SchnorrSigScheme::verify(&FieldBasedSchnorrPk(pk.into_projective()), *msg, signature)
 }
+
+fn hash_field_elements_msg(msg: &[FieldElement]) -> Result<FieldElement, Error> {
+    compute_poseidon_hash(
+        msg,
+        Some(&[FieldElement::from(SCHNORR_FIELD_ELEMENTS_MSG_DOMAIN_TAG)]),
+    )
+}
+
+fn hash_bytes_msg(msg: &[u8]) -> Result<FieldElement, Error> {
+    compute_poseidon_hash_from_bytes(
+        msg,
+        Some(&[FieldElement::from(SCHNORR_BYTES_MSG_DOMAIN_TAG)]),
+    )
+}
+
+/// Poseidon personalization of the challenge under `domain_tag`. The personalization is
+/// padded with zeros, thus the length of the tag is included to keep tags differing only
+/// by trailing zeros (e.g. [] and [0]) apart.
+fn challenge_personalization(domain_tag: &[FieldElement]) -> Vec<FieldElement> {
+    let mut personalization = vec![
+        FieldElement::from(SCHNORR_CUSTOM_DOMAIN_CHALLENGE_DOMAIN_TAG),
+        FieldElement::from(domain_tag.len() as u64),
+    ];
+    personalization.extend_from_slice(domain_tag);
+    personalization
+}
+
+/// Sign a message made of multiple field elements. The message is hashed with Poseidon,
+/// in a domain separated way, into the single field element actually signed.
+pub fn schnorr_sign_field_elements(
+    msg: &[FieldElement],
+    sk: &SchnorrSk,
+    pk: &SchnorrPk,
+) -> Result<SchnorrSig, Error> {
+    schnorr_sign(&hash_field_elements_msg(msg)?, sk, pk)
+}
+
+/// Verify a signature produced with `schnorr_sign_field_elements`.
+pub fn schnorr_verify_field_elements(
+    msg: &[FieldElement],
+    pk: &SchnorrPk,
+    signature: &SchnorrSig,
+) -> Result<bool, Error> {
+    schnorr_verify_signature(&hash_field_elements_msg(msg)?, pk, signature)
+}
+
+/// Sign an arbitrary byte message. The message is hashed with Poseidon,
+/// in a domain separated way, into the single field element actually signed.
+pub fn schnorr_sign_bytes(msg: &[u8], sk: &SchnorrSk, pk: &SchnorrPk) -> Result<SchnorrSig, Error> {
+    schnorr_sign(&hash_bytes_msg(msg)?, sk, pk)
+}
+
+/// Verify a signature produced with `schnorr_sign_bytes`.
+pub fn schnorr_verify_bytes(
+    msg: &[u8],
+    pk: &SchnorrPk,
+    signature: &SchnorrSig,
+) -> Result<bool, Error> {
+    schnorr_verify_signature(&hash_bytes_msg(msg)?, pk, signature)
+}
+
+/// Convert an arbitrary byte domain tag into a field element domain tag,
+/// usable with `schnorr_sign_with_domain_tag` and `schnorr_verify_with_domain_tag`.
+/// The result is [SCHNORR_BYTES_DOMAIN_TAG_DOMAIN_TAG, H(domain_tag)]: the prefix keeps it
+/// apart from the bare hash of the bytes used as a domain tag.
+pub fn schnorr_domain_tag_from_bytes(domain_tag: &[u8]) -> Result<Vec<FieldElement>, Error> {
+    Ok(vec![
+        FieldElement::from(SCHNORR_BYTES_DOMAIN_TAG_DOMAIN_TAG),
+        compute_poseidon_hash_from_bytes(domain_tag, None)?,
+    ])
+}
+
+/// Sign `msg` in the context identified by `domain_tag`: the tag is used as Poseidon
+/// personalization of the challenge e = H(m || R || pk.x), so that the signature verifies
+/// neither in a context with a different tag nor as a plain signature, of any message.
+pub fn schnorr_sign_with_domain_tag(
+    msg: &FieldElement,
+    domain_tag: &[FieldElement],
+    sk: &SchnorrSk,
+    pk: &SchnorrPk,
+) -> Result<SchnorrSig, Error> {
+    let mut rng = OsRng;
+    let personalization = challenge_personalization(domain_tag);
+    let pk = pk.into_projective();
+    let e_leading_zeros = required_leading_zeros(
+        FieldElement::size_in_bits(),
+        ScalarFieldElement::size_in_bits(),
+    );
+    let s_leading_zeros = required_leading_zeros(
+        ScalarFieldElement::size_in_bits(),
+        FieldElement::size_in_bits(),
+    );
+
+    loop {
+        let k = ScalarFieldElement::rand(&mut rng);
+        if k.is_zero() {
+            continue;
+        }
+
+        // R = k * G
+        let r = SchnorrProjective::prime_subgroup_generator().mul(&k);
+        let e = schnorr_challenge_personalized(msg, &r, &pk, Some(personalization.as_slice()))?;
+
+        let e_conv = match convert_field::<_, ScalarFieldElement>(&e, e_leading_zeros) {
+            Ok(e_conv) => e_conv,
+            Err(_) => continue,
+        };
+
+        let s = k + &(e_conv * sk);
+        match convert_field::<_, FieldElement>(&s, s_leading_zeros) {
+            Ok(s_conv) => return Ok(SchnorrSig::new(e, s_conv)),
+            Err(_) => continue,
+        }
+    }
+}
+
+/// Verify a signature produced with `schnorr_sign_with_domain_tag` under `domain_tag`.
+pub fn schnorr_verify_with_domain_tag(
+    msg: &FieldElement,
+    domain_tag: &[FieldElement],
+    pk: &SchnorrPk,
+    signature: &SchnorrSig,
+) -> Result<bool, Error> {
+    let (e_conv, s_conv) = match signature_scalars(&signature.e, &signature.s) {
+        Ok(scalars) => scalars,
+        Err(_) => return Ok(false),
+    };
+
+    // R = s * G - e * pk
+    let pk = pk.into_projective();
+    let r = SchnorrProjective::prime_subgroup_generator().mul(&s_conv) - &pk.mul(&e_conv);
+
+    let e = schnorr_challenge_personalized(
+        msg,
+        &r,
+        &pk,
+        Some(challenge_personalization(domain_tag).as_slice()),
+    )?;
+
+    Ok(e == signature.e)
+}
+
+//*******************************Adaptor signatures**********************************************
+
+/// A Schnorr pre-signature, i.e. a signature "encrypted" under an adaptor point T = t * G:
+/// it can be turned into a regular `SchnorrSig` only by who knows the adaptor secret t,
+/// and, once the regular signature is published, t can be extracted from the pair.
+/// As for `SchnorrSig`, the nonce commitment R is not stored but recomputed from (e, s).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchnorrPreSig {
+    /// Challenge e = H(m || R + T || pk.x)
+    pub e: FieldElement,
+    /// Pre-signature response s' = k + e * sk
+    pub s: ScalarFieldElement,
+}
+
+pub const SCHNORR_PRE_SIG_SIZE: usize = FIELD_SIZE + SCALAR_FIELD_SIZE;
+
+impl CanonicalSerialize for SchnorrPreSig {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.e, &mut writer)?;
+        CanonicalSerialize::serialize(&self.s, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.e.serialized_size() + self.s.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize(writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        self.serialized_size()
+    }
+}
+
+impl CanonicalDeserialize for SchnorrPreSig {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let e = CanonicalDeserialize::deserialize(&mut reader)?;
+        let s = CanonicalDeserialize::deserialize(&mut reader)?;
+        Ok(Self { e, s })
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+}
+
+impl SemanticallyValid for SchnorrPreSig {
+    fn is_valid(&self) -> bool {
+        self.e.is_valid() && self.s.is_valid()
+    }
+}
+
+impl DeserializationChecks for SchnorrPreSig {}
+
+type SchnorrProjective = <SchnorrPk as AffineCurve>::Projective;
+
+/// Number of leading zeros the bit representation of an element of a field with modulus
+/// of `from_bits` bits must have to be safely converted into an element of a field with
+/// modulus of `to_bits` bits, without any modular reduction.
+fn required_leading_zeros(from_bits: usize, to_bits: usize) -> usize {
+    if from_bits >= to_bits {
+        from_bits - to_bits + 1
+    } else {
+        0
+    }
+}
+
+/// Leading zeros required to both the pre-signature response and the adaptor secret:
+/// one more than the ones required to convert a scalar into a base field element, so that
+/// their sum, i.e. the adapted signature response, is still convertible.
+fn adaptor_leading_zeros() -> usize {
+    required_leading_zeros(
+        ScalarFieldElement::size_in_bits(),
+        FieldElement::size_in_bits(),
+    ) + 1
+}
+
+fn count_leading_zeros<F: PrimeField>(f: &F) -> usize {
+    f.write_bits().into_iter().take_while(|b| !b).count()
+}
+
+/// Convert `f` into an element of `F2`, failing if `f` doesn't have `leading_zeros` leading zeros.
+fn convert_field<F1: PrimeField, F2: PrimeField>(
+    f: &F1,
+    leading_zeros: usize,
+) -> Result<F2, Error> {
+    if count_leading_zeros(f) < leading_zeros {
+        Err(format!(
+            "Unable to convert field element: less than {} leading zeros",
+            leading_zeros
+        ))?
+    }
+    convert::<F2>(f.write_bits())
+}
+
+/// Challenge of the Schnorr signature scheme: e = H(m || R || pk.x)
+fn schnorr_challenge(
+    msg: &FieldElement,
+    r: &SchnorrProjective,
+    pk: &SchnorrProjective,
+) -> Result<FieldElement, Error> {
+    schnorr_challenge_personalized(msg, r, pk, None)
+}
+
+/// Same as `schnorr_challenge`, hashing with `personalization`, if specified.
+fn schnorr_challenge_personalized(
+    msg: &FieldElement,
+    r: &SchnorrProjective,
+    pk: &SchnorrProjective,
+    personalization: Option<&[FieldElement]>,
+) -> Result<FieldElement, Error> {
+    let r_coords = r.to_field_elements()?;
+    let pk_coords = pk.to_field_elements()?;
+
+    let mut h = FieldHash::init_constant_length(4, personalization);
+    h.update(*msg);
+    r_coords.into_iter().for_each(|c| {
+        h.update(c);
+    });
+    h.update(pk_coords[0]);
+    h.finalize()
+}
+
+/// Generate an adaptor key pair (T, t), with T = t * G. The secret t is sampled with
+/// enough leading zeros to guarantee that any pre-signature can be adapted with it.
+pub fn schnorr_adaptor_generate_key() -> (SchnorrPk, SchnorrSk) {
+    let mut rng = OsRng;
+    let leading_zeros = adaptor_leading_zeros();
+
+    let t = loop {
+        let t = SchnorrSk::rand(&mut rng);
+        if !t.is_zero() && count_leading_zeros(&t) >= leading_zeros {
+            break t;
+        }
+    };
+
+    (schnorr_get_public_key(&t), t)
+}
+
+/// Pre-sign `msg` under the adaptor point `adaptor_point`.
+pub fn schnorr_adaptor_pre_sign(
+    msg: &FieldElement,
+    sk: &SchnorrSk,
+    pk: &SchnorrPk,
+    adaptor_point: &SchnorrPk,
+) -> Result<SchnorrPreSig, Error> {
+    schnorr_adaptor_pre_sign_with_rng(&mut OsRng, msg, sk, pk, adaptor_point)
+}
+
+/// Same as `schnorr_adaptor_pre_sign`, sampling the nonce from `rng`.
+pub(crate) fn schnorr_adaptor_pre_sign_with_rng<R: RngCore>(
+    rng: &mut R,
+    msg: &FieldElement,
+    sk: &SchnorrSk,
+    pk: &SchnorrPk,
+    adaptor_point: &SchnorrPk,
+) -> Result<SchnorrPreSig, Error> {
+    let pk = pk.into_projective();
+    let adaptor_point = adaptor_point.into_projective();
+    let e_leading_zeros = required_leading_zeros(
+        FieldElement::size_in_bits(),
+        ScalarFieldElement::size_in_bits(),
+    );
+
+    loop {
+        let k = ScalarFieldElement::rand(rng);
+        if k.is_zero() {
+            continue;
+        }
+
+        // R = k * G, challenge computed on R + T
+        let r = SchnorrProjective::prime_subgroup_generator().mul(&k);
+        let e = schnorr_challenge(msg, &(r + &adaptor_point), &pk)?;
+
+        let e_conv = match convert_field::<_, ScalarFieldElement>(&e, e_leading_zeros) {
+            Ok(e_conv) => e_conv,
+            Err(_) => continue,
+        };
+
+        let s = k + &(e_conv * sk);
+        if count_leading_zeros(&s) < adaptor_leading_zeros() {
+            continue;
+        }
+
+        return Ok(SchnorrPreSig { e, s });
+    }
+}
+
+/// Verify that `pre_sig` is a valid pre-signature of `msg` under `pk` and `adaptor_point`,
+/// i.e. that adapting it with the discrete log of `adaptor_point` yields a valid signature.
+pub fn schnorr_adaptor_pre_verify(
+    msg: &FieldElement,
+    pk: &SchnorrPk,
+    adaptor_point: &SchnorrPk,
+    pre_sig: &SchnorrPreSig,
+) -> Result<bool, Error> {
+    let e_leading_zeros = required_leading_zeros(
+        FieldElement::size_in_bits(),
+        ScalarFieldElement::size_in_bits(),
+    );
+
+    if count_leading_zeros(&pre_sig.s) < adaptor_leading_zeros() {
+        return Ok(false);
+    }
+
+    let e_conv = match convert_field::<_, ScalarFieldElement>(&pre_sig.e, e_leading_zeros) {
+        Ok(e_conv) => e_conv,
+        Err(_) => return Ok(false),
+    };
+
+    // R = s' * G - e * pk
+    let pk = pk.into_projective();
+    let r = SchnorrProjective::prime_subgroup_generator().mul(&pre_sig.s) - &pk.mul(&e_conv);
+
+    let e = schnorr_challenge(msg, &(r + &adaptor_point.into_projective()), &pk)?;
+
+    Ok(e == pre_sig.e)
+}
+
+/// Adapt `pre_sig` into a regular signature, verifiable with `schnorr_verify_signature`,
+/// using the adaptor secret `adaptor_secret`.
+pub fn schnorr_adaptor_adapt(
+    pre_sig: &SchnorrPreSig,
+    adaptor_secret: &SchnorrSk,
+) -> Result<SchnorrSig, Error> {
+    let s = pre_sig.s + adaptor_secret;
+    let s_conv = convert_field::<_, FieldElement>(
+        &s,
+        required_leading_zeros(
+            ScalarFieldElement::size_in_bits(),
+            FieldElement::size_in_bits(),
+        ),
+    )?;
+
+    Ok(SchnorrSig::new(pre_sig.e, s_conv))
+}
+
+/// Extract the adaptor secret from `pre_sig` and `sig`, the latter being the signature
+/// obtained by adapting the former. Fails if the extracted secret is not the discrete log
+/// of `adaptor_point`.
+pub fn schnorr_adaptor_extract_secret(
+    pre_sig: &SchnorrPreSig,
+    sig: &SchnorrSig,
+    adaptor_point: &SchnorrPk,
+) -> Result<SchnorrSk, Error> {
+    if sig.e != pre_sig.e {
+        Err("Signature and pre-signature have different challenges")?
+    }
+
+    let s_conv = convert_field::<_, ScalarFieldElement>(
+        &sig.s,
+        required_leading_zeros(
+            FieldElement::size_in_bits(),
+            ScalarFieldElement::size_in_bits(),
+        ),
+    )?;
+    let t = s_conv - &pre_sig.s;
+
+    if &schnorr_get_public_key(&t) != adaptor_point {
+        Err("Extracted secret doesn't match the adaptor point")?
+    }
+
+    Ok(t)
+}
+
+//*******************************Compact signatures**********************************************
+
+/// Alternative representation of a `SchnorrSig`, committing to the nonce commitment R
+/// instead of the challenge e. Differently from (e, s), the verification equation
+/// s * G = R + e * pk is linear in the signature components, allowing to batch verify
+/// many signatures with a single multi-scalar multiplication.
+/// Note that key recovery is not possible, not even from this representation, as the
+/// challenge e = H(m || R || pk.x) binds the public key itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchnorrCompactSig {
+    /// Nonce commitment R = k * G
+    pub r: SchnorrPk,
+    /// Response s = k + e * sk, represented as a base field element as in `SchnorrSig`
+    pub s: FieldElement,
+}
+
+pub const SCHNORR_COMPACT_SIG_SIZE: usize = GROUP_COMPRESSED_SIZE + FIELD_SIZE;
+
+impl CanonicalSerialize for SchnorrCompactSig {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.r, &mut writer)?;
+        CanonicalSerialize::serialize(&self.s, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.r.serialized_size() + self.s.serialized_size()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize_uncompressed(&self.r, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&self.s, &mut writer)
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        self.r.uncompressed_size() + self.s.uncompressed_size()
+    }
+}
+
+impl CanonicalDeserialize for SchnorrCompactSig {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let r = CanonicalDeserialize::deserialize(&mut reader)?;
+        let s = CanonicalDeserialize::deserialize(&mut reader)?;
+        Ok(Self { r, s })
+    }
+
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let r = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        let s = CanonicalDeserialize::deserialize_unchecked(&mut reader)?;
+        Ok(Self { r, s })
+    }
+
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let r = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        let s = CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+        Ok(Self { r, s })
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(
+        mut reader: R,
+    ) -> Result<Self, SerializationError> {
+        let r = CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?;
+        let s = CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?;
+        Ok(Self { r, s })
+    }
+}
+
+impl SemanticallyValid for SchnorrCompactSig {
+    fn is_valid(&self) -> bool {
+        self.r.is_valid() && self.s.is_valid()
+    }
+}
+
+impl DeserializationChecks for SchnorrCompactSig {}
+
+/// Convert the challenge `e` and the response `s` of a signature into scalars,
+/// enforcing the same size constraints of `schnorr_verify_signature`.
+fn signature_scalars(
+    e: &FieldElement,
+    s: &FieldElement,
+) -> Result<(ScalarFieldElement, ScalarFieldElement), Error> {
+    let leading_zeros = required_leading_zeros(
+        FieldElement::size_in_bits(),
+        ScalarFieldElement::size_in_bits(),
+    );
+    Ok((
+        convert_field(e, leading_zeros)?,
+        convert_field(s, leading_zeros)?,
+    ))
+}
+
+/// Convert `sig`, a signature under `pk`, into its compact representation.
+pub fn schnorr_sig_to_compact(
+    sig: &SchnorrSig,
+    pk: &SchnorrPk,
+) -> Result<SchnorrCompactSig, Error> {
+    let (e_conv, s_conv) = signature_scalars(&sig.e, &sig.s)?;
+
+    // R = s * G - e * pk
+    let r = SchnorrProjective::prime_subgroup_generator().mul(&s_conv)
+        - &pk.into_projective().mul(&e_conv);
+
+    Ok(SchnorrCompactSig {
+        r: r.into_affine(),
+        s: sig.s,
+    })
+}
+
+/// Convert `compact_sig`, a compact signature of `msg` under `pk`, into a `SchnorrSig`.
+pub fn schnorr_compact_to_sig(
+    compact_sig: &SchnorrCompactSig,
+    msg: &FieldElement,
+    pk: &SchnorrPk,
+) -> Result<SchnorrSig, Error> {
+    let e = schnorr_challenge(msg, &compact_sig.r.into_projective(), &pk.into_projective())?;
+    Ok(SchnorrSig::new(e, compact_sig.s))
+}
+
+/// Sign `msg` returning a compact signature.
+pub fn schnorr_sign_compact(
+    msg: &FieldElement,
+    sk: &SchnorrSk,
+    pk: &SchnorrPk,
+) -> Result<SchnorrCompactSig, Error> {
+    schnorr_sig_to_compact(&schnorr_sign(msg, sk, pk)?, pk)
+}
+
+/// Verify a compact signature. Equivalent to `schnorr_verify_signature` on the
+/// corresponding `SchnorrSig`.
+pub fn schnorr_verify_compact(
+    msg: &FieldElement,
+    pk: &SchnorrPk,
+    compact_sig: &SchnorrCompactSig,
+) -> Result<bool, Error> {
+    schnorr_verify_signature(msg, pk, &schnorr_compact_to_sig(compact_sig, msg, pk)?)
+}
+
+/// Verify at once many compact signatures, each one given as (msg, pk, compact_sig), by
+/// checking a random linear combination of their verification equations:
+/// (sum_i z_i * s_i) * G - sum_i z_i * R_i - sum_i (z_i * e_i) * pk_i == 0
+/// Returns Ok(true) only if all the signatures are valid (except with negligible probability).
+pub fn schnorr_batch_verify_compact(
+    items: &[(FieldElement, SchnorrPk, SchnorrCompactSig)],
+) -> Result<bool, Error> {
+    let mut rng = OsRng;
+
+    let mut bases = Vec::with_capacity(2 * items.len() + 1);
+    let mut scalars = Vec::with_capacity(2 * items.len() + 1);
+    let mut s_acc = ScalarFieldElement::zero();
+
+    for (msg, pk, compact_sig) in items.iter() {
+        let e = schnorr_challenge(msg, &compact_sig.r.into_projective(), &pk.into_projective())?;
+        let (e_conv, s_conv) = match signature_scalars(&e, &compact_sig.s) {
+            Ok(scalars) => scalars,
+            Err(_) => return Ok(false),
+        };
+
+        let z = ScalarFieldElement::rand(&mut rng);
+        s_acc += &(z * &s_conv);
+
+        bases.push(compact_sig.r);
+        scalars.push(-z);
+        bases.push(*pk);
+        scalars.push(-(z * &e_conv));
+    }
+
+    bases.push(SchnorrPk::prime_subgroup_generator());
+    scalars.push(s_acc);
+
+    Ok(multi_scalar_mul(bases.as_slice(), scalars.as_slice())?.is_zero())
+}
@@ -1,10 +1,234 @@
-use algebra::{serialize::*, SemanticallyValid};
+use super::merkle_tree::deserialize_ginger_mht;
+use crate::{
+    FieldElement, GingerMHT, GingerMHTPath, ScalarFieldElement, SchnorrPk, SchnorrSig, VRFProof,
+    CURVE_ID, FIELD_SIZE,
+};
+use algebra::{
+    serialize::*, AffineCurve, FpParameters, FromBytes, PrimeField, ProjectiveCurve,
+    SemanticallyValid,
+};
+use blake2s_simd::Params as Blake2sParams;
 use std::{
-    fs::File,
-    io::{BufReader, BufWriter, Cursor, Error as IoError, ErrorKind},
+    any::type_name,
+    fmt,
+    fs::{self, File},
+    io::{BufReader, BufWriter, Cursor, Error as IoError, ErrorKind, Seek, SeekFrom},
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
-fn _deserialize_inner<R: Read, T: CanonicalDeserialize + SemanticallyValid>(
+/// Failures of the checks performed on top of the canonical deserialization.
+/// They are wrapped into a `SerializationError::IoError`, from which
+/// `deserialization_error_code` can tell them apart.
+#[derive(Debug)]
+pub enum DeserializationCheckError {
+    SemanticChecksFailed(String),
+    TrailingBytes(String),
+    InvalidEnvelope(String),
+    InvalidTextEncoding(String),
+    NonCanonicalFieldElement(String),
+    InvalidPoint(String),
+    NotInSubgroup(String),
+}
+
+impl fmt::Display for DeserializationCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeserializationCheckError::SemanticChecksFailed(msg)
+            | DeserializationCheckError::TrailingBytes(msg)
+            | DeserializationCheckError::InvalidEnvelope(msg)
+            | DeserializationCheckError::InvalidTextEncoding(msg)
+            | DeserializationCheckError::NonCanonicalFieldElement(msg)
+            | DeserializationCheckError::InvalidPoint(msg)
+            | DeserializationCheckError::NotInSubgroup(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for DeserializationCheckError {}
+
+impl From<DeserializationCheckError> for SerializationError {
+    fn from(e: DeserializationCheckError) -> Self {
+        let kind = match &e {
+            DeserializationCheckError::TrailingBytes(_) => ErrorKind::InvalidInput,
+            _ => ErrorKind::InvalidData,
+        };
+        SerializationError::IoError(IoError::new(kind, e))
+    }
+}
+
+/// Reason of a deserialization failure, as exposed to the Java side through
+/// `DeserializationException.ErrorCode`. Values are shared with Java: never change them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeserializationErrorCode {
+    Unknown = 0,
+    /// The input ended before the object was fully read
+    NotEnoughBytes = 1,
+    /// The encoding is malformed, e.g. a compressed point not on the curve or an invalid hex string
+    MalformedData = 2,
+    /// The flags encoded together with a point are inconsistent
+    UnexpectedFlags = 3,
+    /// A field element is not smaller than the modulus
+    NonCanonicalFieldElement = 4,
+    /// The object was parsed, but it's not valid
+    SemanticChecksFailed = 5,
+    /// Bytes are left in the input after the object
+    TrailingBytes = 6,
+    /// The envelope wrapping the object is invalid, or it wraps another kind of object
+    InvalidEnvelope = 7,
+    /// Any other I/O failure, e.g. while reading from file
+    Io = 8,
+    /// A point read without compression is not on the curve
+    InvalidPoint = 9,
+    /// A point is on the curve, but outside the prime order subgroup
+    NotInSubgroup = 10,
+}
+
+/// Classify the deserialization failure `e`.
+pub fn deserialization_error_code(e: &SerializationError) -> DeserializationErrorCode {
+    match e {
+        SerializationError::NotEnoughSpace | SerializationError::InvalidData => {
+            DeserializationErrorCode::MalformedData
+        }
+        SerializationError::UnexpectedFlags => DeserializationErrorCode::UnexpectedFlags,
+        SerializationError::IoError(io_error) => {
+            match io_error
+                .get_ref()
+                .and_then(|inner| inner.downcast_ref::<DeserializationCheckError>())
+            {
+                Some(DeserializationCheckError::SemanticChecksFailed(_)) => {
+                    DeserializationErrorCode::SemanticChecksFailed
+                }
+                Some(DeserializationCheckError::TrailingBytes(_)) => {
+                    DeserializationErrorCode::TrailingBytes
+                }
+                Some(DeserializationCheckError::InvalidEnvelope(_)) => {
+                    DeserializationErrorCode::InvalidEnvelope
+                }
+                Some(DeserializationCheckError::InvalidTextEncoding(_)) => {
+                    DeserializationErrorCode::MalformedData
+                }
+                Some(DeserializationCheckError::NonCanonicalFieldElement(_)) => {
+                    DeserializationErrorCode::NonCanonicalFieldElement
+                }
+                Some(DeserializationCheckError::InvalidPoint(_)) => {
+                    DeserializationErrorCode::InvalidPoint
+                }
+                Some(DeserializationCheckError::NotInSubgroup(_)) => {
+                    DeserializationErrorCode::NotInSubgroup
+                }
+                None if io_error.kind() == ErrorKind::UnexpectedEof => {
+                    DeserializationErrorCode::NotEnoughBytes
+                }
+                // Raised by algebra on invalid encodings, e.g. a field element inside a
+                // composite object not smaller than the modulus
+                None if io_error.kind() == ErrorKind::InvalidData => {
+                    DeserializationErrorCode::MalformedData
+                }
+                None => DeserializationErrorCode::Io,
+            }
+        }
+        #[allow(unreachable_patterns)]
+        _ => DeserializationErrorCode::Unknown,
+    }
+}
+
+/// Return a `DeserializationCheckError::NonCanonicalFieldElement` error if `bytes`, the
+/// serialization of a FieldElement, encode an integer not smaller than the modulus.
+pub fn check_field_element_canonical(bytes: &[u8]) -> Result<(), SerializationError> {
+    let repr = <FieldElement as PrimeField>::BigInt::read(bytes)?;
+    if repr >= <FieldElement as PrimeField>::Params::MODULUS {
+        return Err(DeserializationCheckError::NonCanonicalFieldElement(
+            "Attempt to deserialize a field element over the modulus".to_owned(),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Deserialization of the objects handled by this crate, on top of the canonical one.
+/// The defaults fit most of the types: they are overridden where a more specific
+/// failure reason can be reported.
+pub trait DeserializationChecks: CanonicalDeserialize + SemanticallyValid {
+    /// Deserialize an object from `reader`, without performing semantic checks on it.
+    fn deserialize_without_checks<R: Read>(
+        reader: R,
+        compressed: bool,
+    ) -> Result<Self, SerializationError> {
+        if compressed {
+            Self::deserialize_unchecked(reader)
+        } else {
+            Self::deserialize_uncompressed_unchecked(reader)
+        }
+    }
+
+    /// Reason of the failure of the semantic checks on `self`.
+    fn semantic_error(&self) -> DeserializationErrorCode {
+        DeserializationErrorCode::SemanticChecksFailed
+    }
+}
+
+impl DeserializationChecks for FieldElement {
+    // Field elements are checked to be canonical here, algebra not reporting it distinctly
+    fn deserialize_without_checks<R: Read>(
+        mut reader: R,
+        _compressed: bool,
+    ) -> Result<Self, SerializationError> {
+        let mut bytes = [0u8; FIELD_SIZE];
+        reader
+            .read_exact(&mut bytes)
+            .map_err(SerializationError::IoError)?;
+        check_field_element_canonical(&bytes)?;
+        Self::deserialize_unchecked(&bytes[..])
+    }
+}
+
+/// A point not on the curve is told apart from one outside the prime order subgroup.
+fn point_semantic_error<G: AffineCurve>(point: &G) -> DeserializationErrorCode {
+    if !point.is_on_curve() {
+        DeserializationErrorCode::InvalidPoint
+    } else if !point.is_in_correct_subgroup_assuming_on_curve() {
+        DeserializationErrorCode::NotInSubgroup
+    } else {
+        DeserializationErrorCode::SemanticChecksFailed
+    }
+}
+
+// VRFPk is the same type
+impl DeserializationChecks for SchnorrPk {
+    fn semantic_error(&self) -> DeserializationErrorCode {
+        point_semantic_error(self)
+    }
+}
+
+impl DeserializationChecks for VRFProof {
+    fn semantic_error(&self) -> DeserializationErrorCode {
+        point_semantic_error(&self.gamma.into_affine())
+    }
+}
+
+// SchnorrSk and VRFSk are the same type
+impl DeserializationChecks for ScalarFieldElement {}
+impl DeserializationChecks for SchnorrSig {}
+impl DeserializationChecks for GingerMHTPath {}
+
+/// Error to report when an object of type `type_name` fails the semantic checks with `code`.
+fn semantic_checks_error(
+    code: DeserializationErrorCode,
+    type_name: &str,
+) -> DeserializationCheckError {
+    match code {
+        DeserializationErrorCode::InvalidPoint => {
+            DeserializationCheckError::InvalidPoint(format!("{} not on curve", type_name))
+        }
+        DeserializationErrorCode::NotInSubgroup => DeserializationCheckError::NotInSubgroup(
+            format!("{} not in the prime order subgroup", type_name),
+        ),
+        _ => DeserializationCheckError::SemanticChecksFailed(format!("Invalid {}", type_name)),
+    }
+}
+
+fn _deserialize_inner<R: Read, T: DeserializationChecks>(
     reader: R,
     semantic_checks: Option<bool>,
     compressed: Option<bool>,
@@ -12,14 +236,10 @@ fn _deserialize_inner<R: Read, T: CanonicalDeserialize + SemanticallyValid>(
     let semantic_checks = semantic_checks.unwrap_or(false);
     let compressed = compressed.unwrap_or(false);
 
-    let t = if compressed {
-        T::deserialize_unchecked(reader)
-    } else {
-        T::deserialize_uncompressed_unchecked(reader)
-    }?;
+    let t = T::deserialize_without_checks(reader, compressed)?;
 
     if semantic_checks && !t.is_valid() {
-        return Err(SerializationError::InvalidData);
+        return Err(semantic_checks_error(t.semantic_error(), type_name::<T>()).into());
     }
 
     Ok(t)
@@ -30,7 +250,7 @@ fn _deserialize_inner<R: Read, T: CanonicalDeserialize + SemanticallyValid>(
 /// `compressed` can be optional, due to some types being uncompressable;
 /// `semantic_checks` can be optional, due to some types having no checks to be performed,
 /// or trivial checks already performed a priori during serialization.
-pub fn deserialize_from_buffer<T: CanonicalDeserialize + SemanticallyValid>(
+pub fn deserialize_from_buffer<T: DeserializationChecks>(
     buffer: &[u8],
     semantic_checks: Option<bool>,
     compressed: Option<bool>,
@@ -44,7 +264,7 @@ pub fn deserialize_from_buffer<T: CanonicalDeserialize + SemanticallyValid>(
 /// `semantic_checks` can be optional, due to some types having no checks to be performed,
 /// or trivial checks already performed a priori during serialization.
 /// If there are still bytes to read in `buffer` after deserializing T, this function returns an error.
-pub fn deserialize_from_buffer_strict<T: CanonicalDeserialize + SemanticallyValid>(
+pub fn deserialize_from_buffer_strict<T: DeserializationChecks>(
     buffer: &[u8],
     semantic_checks: Option<bool>,
     compressed: Option<bool>,
@@ -56,18 +276,23 @@ pub fn deserialize_from_buffer_strict<T: CanonicalDeserialize + SemanticallyVali
     // Deserialize t
     let t = _deserialize_inner(&mut buffer, semantic_checks, compressed)?;
 
-    let position = buffer.position();
+    check_no_trailing_bytes(buffer.position(), buff_len)?;
+
+    Ok(t)
+}
+
+/// Return a `DeserializationCheckError::TrailingBytes` error if only the first `position`
+/// bytes of a buffer of `buff_len` bytes have been read.
+pub fn check_no_trailing_bytes(position: u64, buff_len: u64) -> Result<(), SerializationError> {
     if position != buff_len {
-        return Err(SerializationError::IoError(IoError::new(
-            ErrorKind::InvalidInput,
-            format!(
-                "Oversized data. Read {} but buff len is {}",
-                position, buff_len
-            ),
-        )));
+        return Err(DeserializationCheckError::TrailingBytes(format!(
+            "Oversized data. Read {} but buff len is {}",
+            position, buff_len
+        ))
+        .into());
     }
 
-    Ok(t)
+    Ok(())
 }
 
 /// Serialize to buffer, choosing whether to use compressed representation or not,
@@ -90,6 +315,298 @@ pub fn serialize_to_buffer<T: CanonicalSerialize>(
     Ok(buffer)
 }
 
+/// Magic bytes opening every envelope
+pub const ENVELOPE_MAGIC: &[u8; 4] = b"ZENS";
+/// Version of the envelope format
+pub const ENVELOPE_VERSION: u8 = 1;
+/// Size of the envelope header: magic, version, curve id, object type, flags and payload length
+pub const ENVELOPE_HEADER_SIZE: usize = 4 + 1 + 1 + 1 + 1 + 4;
+/// Size of the (optional) checksum closing the envelope
+pub const ENVELOPE_CHECKSUM_SIZE: usize = 4;
+
+const ENVELOPE_FLAG_COMPRESSED: u8 = 1;
+const ENVELOPE_FLAG_CHECKSUM: u8 = 1 << 1;
+
+/// BLAKE2s personalization used to compute the checksum of an envelope
+const ENVELOPE_CHECKSUM_PERSONALIZATION: &[u8; 8] = b"ZenEnvCk";
+
+/// Type of the object wrapped in an envelope.
+/// Values are part of the envelope format: never change or reuse them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectType {
+    FieldElement = 1,
+    SchnorrPublicKey = 2,
+    SchnorrSecretKey = 3,
+    SchnorrSignature = 4,
+    SchnorrPreSignature = 5,
+    SchnorrCompactSignature = 6,
+    VRFPublicKey = 7,
+    VRFSecretKey = 8,
+    VRFProof = 9,
+    MerklePath = 10,
+    MerkleTree = 11,
+    KeyRotationCertificate = 12,
+    RandomnessBeacon = 13,
+    ForwardSecurePublicKey = 14,
+    ForwardSecureSecretKey = 15,
+    ForwardSecureSignature = 16,
+}
+
+fn envelope_error(message: String) -> SerializationError {
+    DeserializationCheckError::InvalidEnvelope(message).into()
+}
+
+fn envelope_checksum(data: &[u8]) -> [u8; ENVELOPE_CHECKSUM_SIZE] {
+    let mut checksum = [0u8; ENVELOPE_CHECKSUM_SIZE];
+    checksum.copy_from_slice(
+        Blake2sParams::new()
+            .hash_length(ENVELOPE_CHECKSUM_SIZE)
+            .personal(ENVELOPE_CHECKSUM_PERSONALIZATION)
+            .hash(data)
+            .as_bytes(),
+    );
+    checksum
+}
+
+/// Serialize `to_write`, as `serialize_to_buffer` does, and wrap the result in a
+/// self-describing envelope:
+/// magic (4 bytes) || version (1 byte) || curve id (1 byte) || `object_type` (1 byte) ||
+/// flags (1 byte) || payload length (4 bytes, little endian) || payload || checksum (4 bytes),
+/// where the checksum, a truncated BLAKE2s of all the previous bytes, is present only if
+/// `with_checksum` is set.
+pub fn serialize_enveloped<T: CanonicalSerialize>(
+    to_write: &T,
+    object_type: ObjectType,
+    compressed: Option<bool>,
+    with_checksum: bool,
+) -> Result<Vec<u8>, SerializationError> {
+    let payload = serialize_to_buffer(to_write, compressed)?;
+    if payload.len() > u32::max_value() as usize {
+        return Err(SerializationError::NotEnoughSpace);
+    }
+
+    let mut flags = 0u8;
+    if compressed.unwrap_or(false) {
+        flags |= ENVELOPE_FLAG_COMPRESSED;
+    }
+    if with_checksum {
+        flags |= ENVELOPE_FLAG_CHECKSUM;
+    }
+
+    let mut buffer =
+        Vec::with_capacity(ENVELOPE_HEADER_SIZE + payload.len() + ENVELOPE_CHECKSUM_SIZE);
+    buffer.extend_from_slice(ENVELOPE_MAGIC);
+    buffer.push(ENVELOPE_VERSION);
+    buffer.push(CURVE_ID);
+    buffer.push(object_type as u8);
+    buffer.push(flags);
+    buffer.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(payload.as_slice());
+
+    if with_checksum {
+        let checksum = envelope_checksum(buffer.as_slice());
+        buffer.extend_from_slice(&checksum);
+    }
+
+    Ok(buffer)
+}
+
+/// Check the envelope in `buffer`, expected to wrap an object of type `object_type`
+/// serialized for the curves of this build, and return its payload together with
+/// the compressed flag.
+pub fn open_envelope(
+    buffer: &[u8],
+    object_type: ObjectType,
+) -> Result<(&[u8], bool), SerializationError> {
+    if buffer.len() < ENVELOPE_HEADER_SIZE {
+        return Err(envelope_error(format!(
+            "Envelope too short. Found {} bytes, header size is {}",
+            buffer.len(),
+            ENVELOPE_HEADER_SIZE
+        )));
+    }
+
+    if buffer[..4] != ENVELOPE_MAGIC[..] {
+        return Err(envelope_error("Invalid envelope magic".to_owned()));
+    }
+
+    if buffer[4] != ENVELOPE_VERSION {
+        return Err(envelope_error(format!(
+            "Unsupported envelope version {}. Expected {}",
+            buffer[4], ENVELOPE_VERSION
+        )));
+    }
+
+    if buffer[5] != CURVE_ID {
+        return Err(envelope_error(format!(
+            "Object serialized for curve {}. Expected curve {}",
+            buffer[5], CURVE_ID
+        )));
+    }
+
+    if buffer[6] != object_type as u8 {
+        return Err(envelope_error(format!(
+            "Unexpected object type {}. Expected {:?} ({})",
+            buffer[6], object_type, object_type as u8
+        )));
+    }
+
+    let flags = buffer[7];
+    if flags & !(ENVELOPE_FLAG_COMPRESSED | ENVELOPE_FLAG_CHECKSUM) != 0 {
+        return Err(envelope_error(format!("Unknown envelope flags {}", flags)));
+    }
+
+    let mut payload_len = [0u8; 4];
+    payload_len.copy_from_slice(&buffer[8..ENVELOPE_HEADER_SIZE]);
+    let payload_len = u32::from_le_bytes(payload_len) as usize;
+
+    let checksum_len = if flags & ENVELOPE_FLAG_CHECKSUM != 0 {
+        ENVELOPE_CHECKSUM_SIZE
+    } else {
+        0
+    };
+
+    let expected_len = ENVELOPE_HEADER_SIZE + payload_len + checksum_len;
+    if buffer.len() != expected_len {
+        return Err(envelope_error(format!(
+            "Invalid envelope length. Found {} bytes, expected {}",
+            buffer.len(),
+            expected_len
+        )));
+    }
+
+    let payload_end = ENVELOPE_HEADER_SIZE + payload_len;
+    if checksum_len != 0 && envelope_checksum(&buffer[..payload_end]) != buffer[payload_end..] {
+        return Err(envelope_error("Invalid envelope checksum".to_owned()));
+    }
+
+    Ok((
+        &buffer[ENVELOPE_HEADER_SIZE..payload_end],
+        flags & ENVELOPE_FLAG_COMPRESSED != 0,
+    ))
+}
+
+/// Deserialize an object of type `object_type` from the envelope in `buffer`, produced by
+/// `serialize_enveloped`, and perform checks on it, depending on the value of `semantic_checks` flag.
+/// Contrary to `deserialize_from_buffer`, the representation (compressed or not) is read from
+/// the envelope, and the whole payload must be consumed.
+pub fn deserialize_enveloped<T: DeserializationChecks>(
+    buffer: &[u8],
+    object_type: ObjectType,
+    semantic_checks: Option<bool>,
+) -> Result<T, SerializationError> {
+    let (payload, compressed) = open_envelope(buffer, object_type)?;
+    deserialize_from_buffer_strict(payload, semantic_checks, Some(compressed))
+}
+
+/// Encodings of GingerMHT and GingerMHTPath persisted by the successive versions of this
+/// library, read back by `migrate_ginger_mht` and `migrate_ginger_mht_path`.
+/// Whenever ginger-lib changes the canonical serialization of trees or paths, the encoding
+/// being replaced gets its own variant, together with a decoder and a stored fixture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MerkleEncodingVersion {
+    /// Bare canonical serialization, as written before envelopes were introduced
+    Raw = 0,
+    /// Canonical serialization wrapped in an envelope of version 1
+    EnvelopeV1 = 1,
+}
+
+impl MerkleEncodingVersion {
+    pub const ALL: [MerkleEncodingVersion; 2] = [
+        MerkleEncodingVersion::Raw,
+        MerkleEncodingVersion::EnvelopeV1,
+    ];
+
+    /// Version of the encoding in `buffer`. Nothing identifies a raw encoding: any buffer
+    /// not opening with the envelope magic is assumed to be one.
+    pub fn detect(buffer: &[u8]) -> Self {
+        if buffer.starts_with(ENVELOPE_MAGIC) {
+            MerkleEncodingVersion::EnvelopeV1
+        } else {
+            MerkleEncodingVersion::Raw
+        }
+    }
+}
+
+/// Return a `SerializationError::InvalidData` error if `t`, read from `payload`, doesn't
+/// serialize back to it: the bytes are then not in the encoding they were read as.
+fn check_round_trip<T: CanonicalSerialize>(
+    t: &T,
+    payload: &[u8],
+    compressed: bool,
+) -> Result<(), SerializationError> {
+    if serialize_to_buffer(t, Some(compressed))?.as_slice() != payload {
+        return Err(SerializationError::InvalidData);
+    }
+
+    Ok(())
+}
+
+/// Encode `tree` as the library version using `version` did, e.g. to produce fixtures.
+pub fn encode_ginger_mht(
+    tree: &GingerMHT,
+    version: MerkleEncodingVersion,
+) -> Result<Vec<u8>, SerializationError> {
+    match version {
+        MerkleEncodingVersion::Raw => serialize_to_buffer(tree, None),
+        MerkleEncodingVersion::EnvelopeV1 => {
+            serialize_enveloped(tree, ObjectType::MerkleTree, None, false)
+        }
+    }
+}
+
+/// Read a GingerMHT written in any of the supported encodings and return it, upgraded to the
+/// current type, together with the version of the encoding.
+/// The tree must fill the whole payload, pass `validate_ginger_mht` with `max_height` and
+/// serialize back to the
+/// very same bytes, so that data in an unknown encoding is rejected rather than misread.
+pub fn migrate_ginger_mht(
+    buffer: &[u8],
+    max_height: usize,
+) -> Result<(GingerMHT, MerkleEncodingVersion), SerializationError> {
+    let version = MerkleEncodingVersion::detect(buffer);
+    let (payload, compressed) = match version {
+        MerkleEncodingVersion::Raw => (buffer, false),
+        MerkleEncodingVersion::EnvelopeV1 => open_envelope(buffer, ObjectType::MerkleTree)?,
+    };
+
+    let mut reader = Cursor::new(payload);
+    let tree = deserialize_ginger_mht(&mut reader, Some(max_height))?;
+    check_no_trailing_bytes(reader.position(), payload.len() as u64)?;
+    check_round_trip(&tree, payload, compressed)?;
+
+    Ok((tree, version))
+}
+
+/// Encode `path` as the library version using `version` did, e.g. to produce fixtures.
+pub fn encode_ginger_mht_path(
+    path: &GingerMHTPath,
+    version: MerkleEncodingVersion,
+) -> Result<Vec<u8>, SerializationError> {
+    match version {
+        MerkleEncodingVersion::Raw => serialize_to_buffer(path, None),
+        MerkleEncodingVersion::EnvelopeV1 => {
+            serialize_enveloped(path, ObjectType::MerklePath, None, false)
+        }
+    }
+}
+
+/// Same as `migrate_ginger_mht`, for a GingerMHTPath, which must pass the semantic checks.
+pub fn migrate_ginger_mht_path(
+    buffer: &[u8],
+) -> Result<(GingerMHTPath, MerkleEncodingVersion), SerializationError> {
+    let version = MerkleEncodingVersion::detect(buffer);
+    let (payload, compressed) = match version {
+        MerkleEncodingVersion::Raw => (buffer, false),
+        MerkleEncodingVersion::EnvelopeV1 => open_envelope(buffer, ObjectType::MerklePath)?,
+    };
+
+    let path = deserialize_from_buffer_strict(payload, Some(true), Some(compressed))?;
+    check_round_trip(&path, payload, compressed)?;
+
+    Ok((path, version))
+}
+
 pub const DEFAULT_BUF_SIZE: usize = 1 << 20;
 
 /// Deserialize from the file at `file_path` a compressed or uncompressed element,
@@ -98,7 +615,7 @@ pub const DEFAULT_BUF_SIZE: usize = 1 << 20;
 /// `compressed` can be optional, due to some types being uncompressable;
 /// `semantic_checks` can be optional, due to some types having no checks to be performed,
 /// or trivial checks already performed a priori during serialization.
-pub fn read_from_file<T: CanonicalDeserialize + SemanticallyValid>(
+pub fn read_from_file<T: DeserializationChecks>(
     file_path: &str,
     semantic_checks: Option<bool>,
     compressed: Option<bool>,
@@ -106,23 +623,97 @@ pub fn read_from_file<T: CanonicalDeserialize + SemanticallyValid>(
     let fs = File::open(file_path).map_err(SerializationError::IoError)?;
     let reader = BufReader::with_capacity(DEFAULT_BUF_SIZE, fs);
 
+    read_from_reader(reader, semantic_checks, compressed)
+}
+
+/// Same as `read_from_file`, but the file must contain exactly the serialized element:
+/// if there are still bytes to read after deserializing T, this function returns an error.
+pub fn read_from_file_strict<T: DeserializationChecks>(
+    file_path: &str,
+    semantic_checks: Option<bool>,
+    compressed: Option<bool>,
+) -> Result<T, SerializationError> {
+    let fs = File::open(file_path).map_err(SerializationError::IoError)?;
+    let mut reader = BufReader::with_capacity(DEFAULT_BUF_SIZE, fs);
+
+    let t = read_from_reader(&mut reader, semantic_checks, compressed)?;
+    check_file_fully_read(&mut reader)?;
+
+    Ok(t)
+}
+
+/// Return a `DeserializationCheckError::TrailingBytes` error if bytes are left in the file
+/// read through `reader`, as done by `check_no_trailing_bytes` for buffers.
+pub fn check_file_fully_read(reader: &mut BufReader<File>) -> Result<(), SerializationError> {
+    let position = reader
+        .seek(SeekFrom::Current(0))
+        .map_err(SerializationError::IoError)?;
+    let file_len = reader
+        .get_ref()
+        .metadata()
+        .map_err(SerializationError::IoError)?
+        .len();
+
+    check_no_trailing_bytes(position, file_len)
+}
+
+/// Same as `read_from_file`, but reading from `reader`.
+/// Bytes following the element are left unread.
+pub fn read_from_reader<R: Read, T: DeserializationChecks>(
+    reader: R,
+    semantic_checks: Option<bool>,
+    compressed: Option<bool>,
+) -> Result<T, SerializationError> {
     _deserialize_inner(reader, semantic_checks, compressed)
 }
 
+/// Used to give distinct names to the temporary files created by `write_to_file`
+static TMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
 /// Serialize to file, choosing whether to use compressed representation or not,
 /// depending on the value of `compressed` flag.
 /// `compressed` can be optional, due to some types being uncompressable.
+/// The write is atomic: data is written to a temporary file in the same directory, which is
+/// then renamed to `file_path`. Thus, in case of failure (or crash), `file_path` is either left
+/// untouched or holds the whole serialized element.
 pub fn write_to_file<T: CanonicalSerialize>(
     to_write: &T,
     file_path: &str,
     compressed: Option<bool>,
 ) -> Result<(), SerializationError> {
-    let compressed = compressed.unwrap_or(false);
+    let tmp_path = format!(
+        "{}.{}.{}.tmp",
+        file_path,
+        std::process::id(),
+        TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
 
-    let fs = File::create(file_path).map_err(SerializationError::IoError)?;
-    let mut writer = BufWriter::with_capacity(DEFAULT_BUF_SIZE, fs);
+    let res = File::create(&tmp_path)
+        .map_err(SerializationError::IoError)
+        .and_then(|fs| {
+            let mut writer = BufWriter::with_capacity(DEFAULT_BUF_SIZE, fs);
+            write_to_writer(to_write, &mut writer, compressed)?;
+            writer
+                .get_ref()
+                .sync_all()
+                .map_err(SerializationError::IoError)?;
+            fs::rename(&tmp_path, file_path).map_err(SerializationError::IoError)
+        });
 
-    if compressed {
+    if res.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+
+    res
+}
+
+/// Same as `write_to_file`, but writing to `writer`, which is flushed at the end.
+pub fn write_to_writer<T: CanonicalSerialize, W: Write>(
+    to_write: &T,
+    mut writer: W,
+    compressed: Option<bool>,
+) -> Result<(), SerializationError> {
+    if compressed.unwrap_or(false) {
         CanonicalSerialize::serialize(to_write, &mut writer)?;
     } else {
         CanonicalSerialize::serialize_uncompressed(to_write, &mut writer)?;
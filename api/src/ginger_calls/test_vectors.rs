@@ -0,0 +1,374 @@
+//! Deterministic test vectors of the current curve, checked against the corpus committed under
+//! `test_vectors/<curve>/`: a change in any output is reported as a regression.
+//! Covered: field elements, Poseidon, Merkle tree and paths, Schnorr keys and signatures
+//! (plain, compact and adaptor), VRF keys, proofs and outputs, randomness beacon, leader
+//! election, envelopes, hex/JSON encodings and the Merkle tree and path encodings read by the
+//! migration layer of `serialization`. Not covered: key rotation certificates and
+//! forward secure signatures, whose keys and signatures are generated with OsRng internally.
+
+use super::{
+    leader_election::*, merkle_tree::*, poseidon_hash::*, randomness_beacon::*,
+    schnorr_signature::*, serialization::*, text_encoding::*, vrf::*, *,
+};
+use algebra::{serialize::*, ProjectiveCurve, UniformRand};
+use primitives::{signature::FieldBasedSignatureScheme, vrf::FieldBasedVrf};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use std::{
+    convert::TryInto,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Version of the set of test vectors. To be increased whenever vectors are added, removed or
+/// computed differently: a changed output under the same version is a regression.
+pub const TEST_VECTORS_VERSION: u8 = 1;
+
+const TEST_VECTORS_MAGIC: &[u8; 4] = b"ZENV";
+
+/// Seed of the RNG from which the inputs of the test vectors are sampled
+const TEST_VECTORS_SEED: u64 = 1234567890;
+
+/// Fixed secret key of the "vrf/fixed/*" vectors, which pin the output of
+/// `vrf_prove_deterministic` independently of the RNG
+pub const FIXED_VRF_SK: u64 = 0x5eed_5eed_5eed_5eed;
+
+/// Fixed message of the "vrf/fixed/*" vectors
+pub const FIXED_VRF_MSG: u64 = 0x0bad_c0ff_ee00_0000;
+
+/// Output of an operation over deterministic inputs, identified by `name`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestVector {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+fn push_raw_vector(vectors: &mut Vec<TestVector>, name: &str, data: Vec<u8>) {
+    vectors.push(TestVector {
+        name: name.to_owned(),
+        data,
+    });
+}
+
+fn push_vector<T: CanonicalSerialize>(
+    vectors: &mut Vec<TestVector>,
+    name: &str,
+    to_write: &T,
+    compressed: Option<bool>,
+) -> Result<(), Error> {
+    vectors.push(TestVector {
+        name: name.to_owned(),
+        data: serialize_to_buffer(to_write, compressed)?,
+    });
+    Ok(())
+}
+
+/// Generate the test vectors of the current curve. All the inputs are sampled from a
+/// XorShiftRng with a fixed seed, thus the vectors change only if the outputs do.
+pub fn generate_test_vectors() -> Result<Vec<TestVector>, Error> {
+    let mut rng = XorShiftRng::seed_from_u64(TEST_VECTORS_SEED);
+    let mut vectors = Vec::new();
+
+    // Field elements
+    let fes = (0..4)
+        .map(|_| FieldElement::rand(&mut rng))
+        .collect::<Vec<_>>();
+    for (i, fe) in fes.iter().enumerate() {
+        push_vector(&mut vectors, &format!("field_element/{}", i), fe, None)?;
+    }
+
+    // Poseidon
+    push_vector(
+        &mut vectors,
+        "poseidon/variable_length",
+        &compute_poseidon_hash(fes.as_slice(), None)?,
+        None,
+    )?;
+    push_vector(
+        &mut vectors,
+        "poseidon/personalized",
+        &compute_poseidon_hash(&fes[..2], Some(&fes[2..]))?,
+        None,
+    )?;
+    push_vector(
+        &mut vectors,
+        "poseidon/bytes",
+        &compute_poseidon_hash_from_bytes(b"Horizen test vectors", None)?,
+        None,
+    )?;
+
+    // Merkle tree, not full
+    let height = 5;
+    let mut mht = new_ginger_mht(height, 1 << height)?;
+    for _ in 0..20 {
+        append_leaf_to_ginger_mht(&mut mht, &FieldElement::rand(&mut rng))?;
+    }
+    finalize_ginger_mht_in_place(&mut mht)?;
+    push_vector(
+        &mut vectors,
+        "merkle_tree/root",
+        &get_ginger_mht_root(&mht)?,
+        None,
+    )?;
+    for leaf_index in vec![0u64, 7, 19] {
+        push_vector(
+            &mut vectors,
+            &format!("merkle_tree/path/{}", leaf_index),
+            &get_ginger_mht_path(&mht, leaf_index)?,
+            None,
+        )?;
+    }
+
+    // Fixtures of the encodings of trees and paths of all the supported versions
+    for version in MerkleEncodingVersion::ALL.iter() {
+        push_raw_vector(
+            &mut vectors,
+            &format!("migration/merkle_tree/v{}", *version as u8),
+            encode_ginger_mht(&mht, *version)?,
+        );
+        push_raw_vector(
+            &mut vectors,
+            &format!("migration/merkle_path/v{}", *version as u8),
+            encode_ginger_mht_path(&get_ginger_mht_path(&mht, 7)?, *version)?,
+        );
+    }
+
+    // Schnorr
+    let (pk, sk) = SchnorrSigScheme::keygen(&mut rng);
+    let sig = SchnorrSigScheme::sign(&mut rng, &pk, &sk, fes[0])?;
+    push_vector(&mut vectors, "schnorr/secret_key", &sk, None)?;
+    push_vector(
+        &mut vectors,
+        "schnorr/public_key",
+        &pk.0.into_affine(),
+        Some(true),
+    )?;
+    push_vector(&mut vectors, "schnorr/signature", &sig, None)?;
+    let pk = pk.0.into_affine();
+    push_vector(
+        &mut vectors,
+        "schnorr/compact_signature",
+        &schnorr_sig_to_compact(&sig, &pk)?,
+        Some(true),
+    )?;
+
+    // Schnorr adaptor signatures, with an adaptor secret small enough to be always usable
+    let adaptor_secret = SchnorrSk::from(TEST_VECTORS_SEED);
+    let adaptor_point = schnorr_get_public_key(&adaptor_secret);
+    let pre_sig = schnorr_adaptor_pre_sign_with_rng(&mut rng, &fes[0], &sk, &pk, &adaptor_point)?;
+    push_vector(&mut vectors, "schnorr/pre_signature", &pre_sig, None)?;
+    push_vector(
+        &mut vectors,
+        "schnorr/adapted_signature",
+        &schnorr_adaptor_adapt(&pre_sig, &adaptor_secret)?,
+        None,
+    )?;
+
+    // VRF
+    let (vrf_pk, vrf_sk) = VRFScheme::keygen(&mut rng);
+    let vrf_pk = vrf_pk.0.into_affine();
+    let (proof, output) = vrf_prove_deterministic(&fes[1], &vrf_sk, &vrf_pk)?;
+    push_vector(&mut vectors, "vrf/secret_key", &vrf_sk, None)?;
+    push_vector(&mut vectors, "vrf/public_key", &vrf_pk, Some(true))?;
+    push_vector(&mut vectors, "vrf/proof", &proof, Some(true))?;
+    push_vector(&mut vectors, "vrf/output", &output, None)?;
+
+    let fixed_sk = VRFSk::from(FIXED_VRF_SK);
+    let fixed_pk = vrf_get_public_key(&fixed_sk);
+    let (fixed_proof, fixed_output) =
+        vrf_prove_deterministic(&FieldElement::from(FIXED_VRF_MSG), &fixed_sk, &fixed_pk)?;
+    push_vector(&mut vectors, "vrf/fixed/proof", &fixed_proof, Some(true))?;
+    push_vector(&mut vectors, "vrf/fixed/output", &fixed_output, None)?;
+
+    // Randomness beacon
+    let mut beacon = new_randomness_beacon(&fes[2]);
+    absorb_vrf_output(&mut beacon, &output)?;
+    absorb_vrf_output(&mut beacon, &fixed_output)?;
+    push_vector(
+        &mut vectors,
+        "randomness_beacon/rolling_nonce",
+        &get_rolling_nonce(&beacon),
+        None,
+    )?;
+    push_vector(
+        &mut vectors,
+        "randomness_beacon/next_epoch_nonce",
+        &end_beacon_epoch(&mut beacon)?,
+        None,
+    )?;
+    push_vector(&mut vectors, "randomness_beacon/beacon", &beacon, None)?;
+
+    // Leader election, with an active slot coefficient of 1/20
+    for (stake, total_stake) in vec![(1u64, 100u64), (25, 100), (100, 100)] {
+        let threshold = vrf_leader_threshold(stake, total_stake, 1, 20)?;
+        let mut data = threshold.to_le_bytes().to_vec();
+        data.push(vrf_is_below_threshold(&output, threshold) as u8);
+        push_raw_vector(
+            &mut vectors,
+            &format!("leader_election/{}_{}", stake, total_stake),
+            data,
+        );
+    }
+
+    // Envelopes
+    push_raw_vector(
+        &mut vectors,
+        "envelope/schnorr_public_key",
+        serialize_enveloped(&pk, ObjectType::SchnorrPublicKey, Some(true), true)?,
+    );
+    push_raw_vector(
+        &mut vectors,
+        "envelope/field_element",
+        serialize_enveloped(&fes[0], ObjectType::FieldElement, None, false)?,
+    );
+
+    // Hex and JSON encodings
+    push_raw_vector(
+        &mut vectors,
+        "hex/vrf_public_key",
+        to_hex(&vrf_pk)?.into_bytes(),
+    );
+    push_raw_vector(
+        &mut vectors,
+        "json/field_element",
+        fes[3].to_json()?.into_bytes(),
+    );
+    push_raw_vector(
+        &mut vectors,
+        "json/schnorr_public_key",
+        pk.to_json()?.into_bytes(),
+    );
+    push_raw_vector(
+        &mut vectors,
+        "json/schnorr_signature",
+        sig.to_json()?.into_bytes(),
+    );
+    push_raw_vector(
+        &mut vectors,
+        "json/vrf_proof",
+        proof.to_json()?.into_bytes(),
+    );
+    push_raw_vector(
+        &mut vectors,
+        "json/merkle_path",
+        get_ginger_mht_path(&mht, 7)?.to_json()?.into_bytes(),
+    );
+
+    Ok(vectors)
+}
+
+/// Path of the file holding the test vectors of the current curve and version
+pub fn test_vectors_file_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("test_vectors")
+        .join(CURVE_NAME)
+        .join(format!("v{}.bin", TEST_VECTORS_VERSION))
+}
+
+/// Encode `vectors` as:
+/// MAGIC || VERSION || CURVE_ID || count (u32 LE) || [name_len (u16 LE) || name || data_len (u32 LE) || data]*
+pub fn encode_test_vectors(vectors: &[TestVector]) -> Vec<u8> {
+    let mut buffer = TEST_VECTORS_MAGIC.to_vec();
+    buffer.push(TEST_VECTORS_VERSION);
+    buffer.push(CURVE_ID);
+    buffer.extend_from_slice(&(vectors.len() as u32).to_le_bytes());
+
+    for vector in vectors {
+        buffer.extend_from_slice(&(vector.name.len() as u16).to_le_bytes());
+        buffer.extend_from_slice(vector.name.as_bytes());
+        buffer.extend_from_slice(&(vector.data.len() as u32).to_le_bytes());
+        buffer.extend_from_slice(vector.data.as_slice());
+    }
+
+    buffer
+}
+
+fn take<'a>(buffer: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+    if buffer.len() < len {
+        Err("Truncated test vectors")?
+    }
+    let (taken, rest) = buffer.split_at(len);
+    *buffer = rest;
+    Ok(taken)
+}
+
+/// Decode the test vectors encoded by `encode_test_vectors`, checking that they belong to
+/// the current curve and version.
+pub fn decode_test_vectors(mut buffer: &[u8]) -> Result<Vec<TestVector>, Error> {
+    if take(&mut buffer, TEST_VECTORS_MAGIC.len())? != &TEST_VECTORS_MAGIC[..] {
+        Err("Not a test vectors file")?
+    }
+
+    let header = take(&mut buffer, 2)?;
+    if header[0] != TEST_VECTORS_VERSION || header[1] != CURVE_ID {
+        Err(format!(
+            "Test vectors of version {} and curve {}, expected version {} and curve {}",
+            header[0], header[1], TEST_VECTORS_VERSION, CURVE_ID
+        ))?
+    }
+
+    let count = u32::from_le_bytes(take(&mut buffer, 4)?.try_into()?);
+    let mut vectors = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let name_len = u16::from_le_bytes(take(&mut buffer, 2)?.try_into()?);
+        let name = String::from_utf8(take(&mut buffer, name_len as usize)?.to_vec())?;
+        let data_len = u32::from_le_bytes(take(&mut buffer, 4)?.try_into()?);
+        let data = take(&mut buffer, data_len as usize)?.to_vec();
+        vectors.push(TestVector { name, data });
+    }
+
+    if !buffer.is_empty() {
+        Err("Trailing bytes after test vectors")?
+    }
+
+    Ok(vectors)
+}
+
+pub fn write_test_vectors(vectors: &[TestVector], file_path: &Path) -> Result<(), Error> {
+    if let Some(dir) = file_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(file_path, encode_test_vectors(vectors))?;
+    Ok(())
+}
+
+pub fn read_test_vectors(file_path: &Path) -> Result<Vec<TestVector>, Error> {
+    decode_test_vectors(fs::read(file_path)?.as_slice())
+}
+
+/// Return the data of the vector named `name` in `vectors`
+pub fn get_test_vector<'a>(vectors: &'a [TestVector], name: &str) -> Result<&'a [u8], Error> {
+    vectors
+        .iter()
+        .find(|v| v.name == name)
+        .map(|v| v.data.as_slice())
+        .ok_or_else(|| format!("Test vector {} not found", name).into())
+}
+
+/// Compare `actual` against `expected`, reporting by name all the vectors that changed,
+/// disappeared or appeared.
+pub fn check_test_vectors(expected: &[TestVector], actual: &[TestVector]) -> Result<(), Error> {
+    let mut mismatches = Vec::new();
+
+    for expected_vector in expected {
+        match actual.iter().find(|v| v.name == expected_vector.name) {
+            Some(actual_vector) if actual_vector.data != expected_vector.data => {
+                mismatches.push(format!("{} changed", expected_vector.name))
+            }
+            Some(_) => {}
+            None => mismatches.push(format!("{} is missing", expected_vector.name)),
+        }
+    }
+
+    for actual_vector in actual {
+        if !expected.iter().any(|v| v.name == actual_vector.name) {
+            mismatches.push(format!("{} is unexpected", actual_vector.name));
+        }
+    }
+
+    if !mismatches.is_empty() {
+        Err(format!("Test vectors mismatch: {}", mismatches.join(", ")))?
+    }
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use super::{serialization::*, *};
+use algebra::{serialize::*, AffineCurve, ProjectiveCurve, SemanticallyValid};
+use primitives::merkle_tree::*;
+use serde::{Deserialize, Serialize};
+
+/// Encode `to_encode` as the lowercase hex string of its compressed serialization.
+pub fn to_hex<T: CanonicalSerialize>(to_encode: &T) -> Result<String, SerializationError> {
+    serialize_to_buffer(to_encode, Some(true)).map(hex::encode)
+}
+
+/// Decode a `T` from a hex string produced by `to_hex`, performing semantic checks on it.
+/// The string must encode exactly one `T`; both lowercase and uppercase digits are accepted.
+pub fn from_hex<T: DeserializationChecks>(hex_str: &str) -> Result<T, SerializationError> {
+    let bytes = hex::decode(hex_str).map_err(|e| {
+        DeserializationCheckError::InvalidTextEncoding(format!("Invalid hex string: {}", e))
+    })?;
+
+    deserialize_from_buffer_strict(bytes.as_slice(), Some(true), Some(true))
+}
+
+/// Serde adapter encoding a field as `to_hex` and decoding it as `from_hex`, to be used as
+/// `#[serde(with = "hex_encoding")]`.
+pub mod hex_encoding {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T: CanonicalSerialize, S: Serializer>(
+        to_encode: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let hex_str =
+            to_hex(to_encode).map_err(|e| serde::ser::Error::custom(format!("{:?}", e)))?;
+        serializer.serialize_str(hex_str.as_str())
+    }
+
+    pub fn deserialize<'de, T: DeserializationChecks, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let hex_str = String::deserialize(deserializer)?;
+        from_hex(hex_str.as_str()).map_err(|e| serde::de::Error::custom(format!("{:?}", e)))
+    }
+}
+
+/// Stable JSON representation: a hex string for single elements, an object with named,
+/// hex encoded, fields for composite ones.
+/// Any failure while decoding the fields of an object is reported as an invalid encoding.
+pub trait JsonEncoding: Sized {
+    fn to_json(&self) -> Result<String, SerializationError>;
+    fn from_json(json: &str) -> Result<Self, SerializationError>;
+}
+
+fn json_to_string<J: Serialize>(to_encode: &J) -> Result<String, SerializationError> {
+    serde_json::to_string(to_encode).map_err(|e| SerializationError::IoError(e.into()))
+}
+
+fn json_from_str<'a, J: Deserialize<'a>>(json: &'a str) -> Result<J, SerializationError> {
+    serde_json::from_str(json).map_err(|e| {
+        DeserializationCheckError::InvalidTextEncoding(format!("Invalid JSON: {}", e)).into()
+    })
+}
+
+fn check_decoded<T: SemanticallyValid>(decoded: T) -> Result<T, SerializationError> {
+    if !decoded.is_valid() {
+        return Err(DeserializationCheckError::SemanticChecksFailed(format!(
+            "Invalid {} decoded from JSON",
+            std::any::type_name::<T>()
+        ))
+        .into());
+    }
+    Ok(decoded)
+}
+
+impl JsonEncoding for FieldElement {
+    fn to_json(&self) -> Result<String, SerializationError> {
+        json_to_string(&to_hex(self)?)
+    }
+
+    fn from_json(json: &str) -> Result<Self, SerializationError> {
+        from_hex(json_from_str::<String>(json)?.as_str())
+    }
+}
+
+// VRFPk is the same type
+impl JsonEncoding for SchnorrPk {
+    fn to_json(&self) -> Result<String, SerializationError> {
+        json_to_string(&to_hex(self)?)
+    }
+
+    fn from_json(json: &str) -> Result<Self, SerializationError> {
+        from_hex(json_from_str::<String>(json)?.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SchnorrSigJson {
+    #[serde(with = "hex_encoding")]
+    e: FieldElement,
+    #[serde(with = "hex_encoding")]
+    s: FieldElement,
+}
+
+impl JsonEncoding for SchnorrSig {
+    fn to_json(&self) -> Result<String, SerializationError> {
+        json_to_string(&SchnorrSigJson {
+            e: self.e,
+            s: self.s,
+        })
+    }
+
+    fn from_json(json: &str) -> Result<Self, SerializationError> {
+        let sig = json_from_str::<SchnorrSigJson>(json)?;
+        check_decoded(SchnorrSig::new(sig.e, sig.s))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct VRFProofJson {
+    #[serde(with = "hex_encoding")]
+    gamma: VRFPk,
+    #[serde(with = "hex_encoding")]
+    c: FieldElement,
+    #[serde(with = "hex_encoding")]
+    s: FieldElement,
+}
+
+impl JsonEncoding for VRFProof {
+    fn to_json(&self) -> Result<String, SerializationError> {
+        json_to_string(&VRFProofJson {
+            gamma: self.gamma.into_affine(),
+            c: self.c,
+            s: self.s,
+        })
+    }
+
+    fn from_json(json: &str) -> Result<Self, SerializationError> {
+        let proof = json_from_str::<VRFProofJson>(json)?;
+        check_decoded(VRFProof {
+            gamma: proof.gamma.into_projective(),
+            c: proof.c,
+            s: proof.s,
+        })
+    }
+}
+
+/// Node of a Merkle path, from the leaf to the root
+#[derive(Serialize, Deserialize)]
+struct MerklePathNodeJson {
+    #[serde(with = "hex_encoding")]
+    sibling: FieldElement,
+    /// Whether the node at this level of the path is a right child
+    is_right: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MerklePathJson {
+    path: Vec<MerklePathNodeJson>,
+}
+
+impl JsonEncoding for GingerMHTPath {
+    fn to_json(&self) -> Result<String, SerializationError> {
+        json_to_string(&MerklePathJson {
+            path: self
+                .get_raw_path()
+                .iter()
+                .map(|(sibling, is_right)| MerklePathNodeJson {
+                    sibling: *sibling,
+                    is_right: *is_right,
+                })
+                .collect(),
+        })
+    }
+
+    fn from_json(json: &str) -> Result<Self, SerializationError> {
+        let path = json_from_str::<MerklePathJson>(json)?;
+        check_decoded(GingerMHTPath::new(
+            path.path
+                .into_iter()
+                .map(|node| (node.sibling, node.is_right))
+                .collect(),
+        ))
+    }
+}
@@ -1,10 +1,43 @@
-use super::*;
-use algebra::{AffineCurve, ProjectiveCurve, ToConstraintField};
+use super::{
+    constant_time::Zeroizing, domain_tags::*, poseidon_hash::*, serialization::serialize_to_buffer,
+    *,
+};
+use algebra::{AffineCurve, ProjectiveCurve, SemanticallyValid, ToConstraintField, UniformRand};
+use blake2s_simd::Params as Blake2sParams;
 use primitives::{
-    crh::FieldBasedHash,
+    crh::{pedersen::PedersenWindow, FieldBasedHash},
     vrf::{ecvrf::*, FieldBasedVrf},
 };
-use rand::rngs::OsRng;
+use rand::{rngs::OsRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use std::fmt;
+
+/// Possible failures of VRF operations, allowing callers to tell apart failures due to
+/// invalid data provided by a peer from the ones due to the library itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VRFError {
+    /// The public key is not a valid point of the prime order subgroup
+    InvalidPublicKey,
+    /// The gamma point of the proof is malformed, i.e. not on curve or not in the prime order subgroup
+    InvalidGamma,
+    /// The proof is well formed but it doesn't verify
+    InvalidProof,
+    /// Unexpected failure, not related to the validity of the provided data
+    Internal(String),
+}
+
+impl fmt::Display for VRFError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VRFError::InvalidPublicKey => write!(f, "Invalid VRF public key"),
+            VRFError::InvalidGamma => write!(f, "Malformed VRF proof gamma"),
+            VRFError::InvalidProof => write!(f, "Invalid VRF proof"),
+            VRFError::Internal(e) => write!(f, "VRF internal error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for VRFError {}
 
 pub fn vrf_generate_key() -> (VRFPk, VRFSk) {
     let mut rng = OsRng;
@@ -20,47 +53,454 @@ pub fn vrf_verify_public_key(pk: &VRFPk) -> bool {
     VRFScheme::keyverify(&FieldBasedEcVrfPk(pk.into_projective()))
 }
 
-pub fn vrf_prove(
+/// Compute the VRF output for `msg` from `proof` as Poseidon(msg, gamma.x, gamma.y),
+/// using `label`, if specified, as personalization.
+fn compute_vrf_output(
+    msg: &FieldElement,
+    proof: &VRFProof,
+    label: Option<&[FieldElement]>,
+) -> Result<FieldElement, VRFError> {
+    //Convert gamma from proof to field elements
+    let gamma_coords = proof
+        .gamma
+        .to_field_elements()
+        .map_err(|e| VRFError::Internal(e.to_string()))?;
+
+    let mut h = FieldHash::init_constant_length(3, label);
+    h.update(*msg);
+    gamma_coords.into_iter().for_each(|c| {
+        h.update(c);
+    });
+    h.finalize().map_err(|e| VRFError::Internal(e.to_string()))
+}
+
+/// Window configurations of the VRF group hash. The group hash is instantiated with each of
+/// them at compile time, and the one of some `VRFParams` is selected at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VRFWindowConfig {
+    /// `VRFWindow`, i.e. 2 windows of 128 generators, used by `vrf_prove` and `vrf_proof_to_hash`
+    Default,
+    /// `VRFSmallWindow`, i.e. 4 windows of 64 generators
+    Small,
+}
+
+impl VRFWindowConfig {
+    /// Return the configuration whose windows have `window_size` generators.
+    pub fn from_window_size(window_size: usize) -> Result<Self, Error> {
+        match window_size {
+            _ if window_size == VRFWindow::WINDOW_SIZE => Ok(VRFWindowConfig::Default),
+            _ if window_size == VRFSmallWindow::WINDOW_SIZE => Ok(VRFWindowConfig::Small),
+            _ => Err(format!(
+                "Unsupported window size {}. Supported sizes: {}, {}",
+                window_size,
+                VRFWindow::WINDOW_SIZE,
+                VRFSmallWindow::WINDOW_SIZE
+            ))?,
+        }
+    }
+
+    pub fn window_size(&self) -> usize {
+        match self {
+            VRFWindowConfig::Default => VRFWindow::WINDOW_SIZE,
+            VRFWindowConfig::Small => VRFSmallWindow::WINDOW_SIZE,
+        }
+    }
+
+    pub fn num_windows(&self) -> usize {
+        match self {
+            VRFWindowConfig::Default => VRFWindow::NUM_WINDOWS,
+            VRFWindowConfig::Small => VRFSmallWindow::NUM_WINDOWS,
+        }
+    }
+}
+
+/// Parameters of the group hash used by the VRF to hash the message to a curve point.
+/// The generators are derived, one for each window, from `tags` through `hash_to_curve`
+/// with `personalization`. Proofs computed with some parameters only verify with the same ones.
+/// Fields are private, so that the generators always match the tags and the window
+/// configuration: build the parameters with `vrf_generate_params`.
+#[derive(Clone, Debug)]
+pub struct VRFParams {
+    tags: Vec<Vec<u8>>,
+    personalization: [u8; 8],
+    window: VRFWindowConfig,
+    gh_params: VRFGroupHashParams,
+}
+
+impl VRFParams {
+    pub fn tags(&self) -> &[Vec<u8>] {
+        self.tags.as_slice()
+    }
+
+    pub fn personalization(&self) -> &[u8; 8] {
+        &self.personalization
+    }
+
+    pub fn window(&self) -> VRFWindowConfig {
+        self.window
+    }
+}
+
+impl Default for VRFParams {
+    /// The parameters used by `vrf_prove` and `vrf_proof_to_hash`.
+    fn default() -> Self {
+        Self {
+            tags: VRF_GROUP_HASH_GENERATORS_TAGS
+                .iter()
+                .map(|tag| tag.to_vec())
+                .collect(),
+            personalization: *VRF_GROUP_HASH_GENERATORS_PERSONALIZATION,
+            window: VRFWindowConfig::Default,
+            gh_params: VRF_GH_PARAMS.clone(),
+        }
+    }
+}
+
+/// Generate VRF parameters with windows of `window_size` generators, deriving the group hash
+/// generators of each window from one of `tags`, which must be distinct and as many as the
+/// windows of the configuration.
+pub fn vrf_generate_params(
+    tags: &[&[u8]],
+    personalization: &[u8; 8],
+    window_size: usize,
+) -> Result<VRFParams, Error> {
+    let window = VRFWindowConfig::from_window_size(window_size)?;
+
+    if tags.len() != window.num_windows() {
+        Err(format!(
+            "A tag for each window is required. Expected {} tags, found {}",
+            window.num_windows(),
+            tags.len()
+        ))?
+    }
+
+    for (i, tag) in tags.iter().enumerate() {
+        if tags[..i].contains(tag) {
+            Err("Tags must be distinct")?
+        }
+    }
+
+    let generators = tags
+        .iter()
+        .map(|tag| {
+            hash_to_curve::<FieldElement, VRFPk>(tag, personalization)
+                .map(|g| g.into_projective())
+                .ok_or_else(|| format!("Unable to hash tag {:?} to curve", tag))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(VRFParams {
+        tags: tags.iter().map(|tag| tag.to_vec()).collect(),
+        personalization: *personalization,
+        window,
+        gh_params: VRFGroupHashParams {
+            generators: compute_group_hash_table(generators, window.window_size()),
+        },
+    })
+}
+
+fn prove<R: RngCore>(
+    rng: &mut R,
+    window: VRFWindowConfig,
+    gh_params: &VRFGroupHashParams,
     msg: &FieldElement,
     sk: &VRFSk,
     pk: &VRFPk,
-) -> Result<(VRFProof, FieldElement), Error> {
-    let mut rng = OsRng;
-
+) -> Result<(VRFProof, FieldElement), VRFError> {
     //Compute proof
-    let proof = VRFScheme::prove(
-        &mut rng,
-        &VRF_GH_PARAMS,
-        &FieldBasedEcVrfPk(pk.into_projective()),
-        sk,
-        *msg,
-    )?;
-
-    //Convert gamma from proof to field elements
-    let gamma_coords = proof.gamma.to_field_elements().unwrap();
+    let pk = FieldBasedEcVrfPk(pk.into_projective());
+    let proof = match window {
+        VRFWindowConfig::Default => VRFScheme::prove(rng, gh_params, &pk, sk, *msg),
+        VRFWindowConfig::Small => SmallWindowVRFScheme::prove(rng, gh_params, &pk, sk, *msg),
+    }
+    .map_err(|e| VRFError::Internal(e.to_string()))?;
 
     //Compute VRF output
-    let output = {
-        let mut h = FieldHash::init_constant_length(3, None);
-        h.update(*msg);
-        gamma_coords.into_iter().for_each(|c| {
-            h.update(c);
-        });
-        h.finalize()
-    }?;
+    let output = compute_vrf_output(msg, &proof, None)?;
 
     Ok((proof, output))
 }
 
-pub fn vrf_proof_to_hash(
+fn proof_to_hash(
+    window: VRFWindowConfig,
+    gh_params: &VRFGroupHashParams,
+    msg: &FieldElement,
+    pk: &VRFPk,
+    proof: &VRFProof,
+) -> Result<FieldElement, VRFError> {
+    if !vrf_verify_public_key(pk) {
+        return Err(VRFError::InvalidPublicKey);
+    }
+
+    if !proof.gamma.into_affine().is_valid() {
+        return Err(VRFError::InvalidGamma);
+    }
+
+    proof_to_hash_unchecked(window, gh_params, msg, pk, proof)
+}
+
+/// Verify `proof`, assuming that `pk` and the gamma point of `proof` have already been checked.
+fn proof_to_hash_unchecked(
+    window: VRFWindowConfig,
+    gh_params: &VRFGroupHashParams,
     msg: &FieldElement,
     pk: &VRFPk,
     proof: &VRFProof,
-) -> Result<FieldElement, Error> {
-    VRFScheme::proof_to_hash(
+) -> Result<FieldElement, VRFError> {
+    let pk = FieldBasedEcVrfPk(pk.into_projective());
+    match window {
+        VRFWindowConfig::Default => VRFScheme::proof_to_hash(gh_params, &pk, *msg, proof),
+        VRFWindowConfig::Small => SmallWindowVRFScheme::proof_to_hash(gh_params, &pk, *msg, proof),
+    }
+    .map_err(|_| VRFError::InvalidProof)
+}
+
+pub fn vrf_prove(
+    msg: &FieldElement,
+    sk: &VRFSk,
+    pk: &VRFPk,
+) -> Result<(VRFProof, FieldElement), VRFError> {
+    prove(
+        &mut OsRng,
+        VRFWindowConfig::Default,
         &VRF_GH_PARAMS,
-        &FieldBasedEcVrfPk(pk.into_projective()),
-        *msg,
-        proof,
+        msg,
+        sk,
+        pk,
+    )
+}
+
+/// Same as `vrf_prove`, but using the group hash of `params`.
+pub fn vrf_prove_with_params(
+    msg: &FieldElement,
+    sk: &VRFSk,
+    pk: &VRFPk,
+    params: &VRFParams,
+) -> Result<(VRFProof, FieldElement), VRFError> {
+    prove(&mut OsRng, params.window, &params.gh_params, msg, sk, pk)
+}
+
+fn hash_field_elements_msg(msg: &[FieldElement]) -> Result<FieldElement, VRFError> {
+    compute_poseidon_hash(
+        msg,
+        Some(&[FieldElement::from(VRF_FIELD_ELEMENTS_MSG_DOMAIN_TAG)]),
     )
+    .map_err(|e| VRFError::Internal(e.to_string()))
+}
+
+fn hash_bytes_msg(msg: &[u8]) -> Result<FieldElement, VRFError> {
+    compute_poseidon_hash_from_bytes(msg, Some(&[FieldElement::from(VRF_BYTES_MSG_DOMAIN_TAG)]))
+        .map_err(|e| VRFError::Internal(e.to_string()))
+}
+
+/// Prove a message made of multiple field elements (e.g. epoch nonce and slot number).
+/// The message is hashed with Poseidon, in a domain separated way, into the single field
+/// element actually given as input to the VRF.
+pub fn vrf_prove_field_elements(
+    msg: &[FieldElement],
+    sk: &VRFSk,
+    pk: &VRFPk,
+) -> Result<(VRFProof, FieldElement), VRFError> {
+    vrf_prove(&hash_field_elements_msg(msg)?, sk, pk)
+}
+
+/// Verify a proof produced with `vrf_prove_field_elements` and return the corresponding VRF output.
+pub fn vrf_proof_to_hash_field_elements(
+    msg: &[FieldElement],
+    pk: &VRFPk,
+    proof: &VRFProof,
+) -> Result<FieldElement, VRFError> {
+    vrf_proof_to_hash(&hash_field_elements_msg(msg)?, pk, proof)
+}
+
+/// Prove an arbitrary byte message. The message is hashed with Poseidon, in a domain
+/// separated way, into the single field element actually given as input to the VRF.
+pub fn vrf_prove_bytes(
+    msg: &[u8],
+    sk: &VRFSk,
+    pk: &VRFPk,
+) -> Result<(VRFProof, FieldElement), VRFError> {
+    vrf_prove(&hash_bytes_msg(msg)?, sk, pk)
+}
+
+/// Verify a proof produced with `vrf_prove_bytes` and return the corresponding VRF output.
+pub fn vrf_proof_to_hash_bytes(
+    msg: &[u8],
+    pk: &VRFPk,
+    proof: &VRFProof,
+) -> Result<FieldElement, VRFError> {
+    vrf_proof_to_hash(&hash_bytes_msg(msg)?, pk, proof)
+}
+
+/// BLAKE2s personalization used to derive the nonce of deterministic VRF proofs.
+const VRF_DETERMINISTIC_NONCE_PERSONALIZATION: &[u8; 8] = b"ZenVrfDN";
+
+/// Same as `vrf_prove`, but with the nonce derived from `sk`, `pk` and `msg` instead of being
+/// sampled at random (as in RFC 9381), thus the same inputs always give the same proof.
+/// The nonce is sampled from a ChaCha20 RNG seeded with BLAKE2s(sk || pk || msg).
+/// The buffers holding the serialized secret key and the seed are erased before returning.
+pub fn vrf_prove_deterministic(
+    msg: &FieldElement,
+    sk: &VRFSk,
+    pk: &VRFPk,
+) -> Result<(VRFProof, FieldElement), VRFError> {
+    let mut seed_input = Zeroizing::new(Vec::with_capacity(VRF_SK_SIZE + VRF_PK_SIZE + FIELD_SIZE));
+    for bytes in vec![
+        serialize_to_buffer(sk, None),
+        serialize_to_buffer(pk, Some(true)),
+        serialize_to_buffer(msg, None),
+    ] {
+        let bytes = Zeroizing::new(bytes.map_err(|e| VRFError::Internal(e.to_string()))?);
+        seed_input.extend_from_slice(bytes.as_slice());
+    }
+
+    let mut seed = Zeroizing::new([0u8; 32]);
+    seed.copy_from_slice(
+        Blake2sParams::new()
+            .hash_length(32)
+            .personal(VRF_DETERMINISTIC_NONCE_PERSONALIZATION)
+            .hash(seed_input.as_slice())
+            .as_bytes(),
+    );
+
+    prove(
+        &mut ChaCha20Rng::from_seed(*seed),
+        VRFWindowConfig::Default,
+        &VRF_GH_PARAMS,
+        msg,
+        sk,
+        pk,
+    )
+}
+
+/// Verify `proof` for `msg` under `pk` and return the corresponding VRF output.
+/// Public key and gamma are checked beforehand, in order to report a precise error.
+pub fn vrf_proof_to_hash(
+    msg: &FieldElement,
+    pk: &VRFPk,
+    proof: &VRFProof,
+) -> Result<FieldElement, VRFError> {
+    proof_to_hash(VRFWindowConfig::Default, &VRF_GH_PARAMS, msg, pk, proof)
+}
+
+/// Same as `vrf_proof_to_hash`, but using the group hash of `params`.
+pub fn vrf_proof_to_hash_with_params(
+    msg: &FieldElement,
+    pk: &VRFPk,
+    proof: &VRFProof,
+    params: &VRFParams,
+) -> Result<FieldElement, VRFError> {
+    proof_to_hash(params.window, &params.gh_params, msg, pk, proof)
+}
+
+/// Derive from `proof` the VRF output for `msg` labeled with `label`, allowing to get multiple
+/// independent outputs (e.g. one for leader election and one for randomness) from the same proof.
+/// An empty label gives the default output, i.e. the one returned by `vrf_prove` and `vrf_proof_to_hash`.
+/// Otherwise the personalization is [VRF_OUTPUT_LABEL_DOMAIN_TAG, label.len(), label]: as it is
+/// padded with zeros, the length keeps apart labels differing only by trailing zeros.
+/// The proof is not verified: to be used by the prover, or after a successful `vrf_proof_to_hash`.
+pub fn vrf_output_with_label(
+    msg: &FieldElement,
+    proof: &VRFProof,
+    label: &[FieldElement],
+) -> Result<FieldElement, VRFError> {
+    if label.is_empty() {
+        return compute_vrf_output(msg, proof, None);
+    }
+
+    let mut personalization = vec![
+        FieldElement::from(VRF_OUTPUT_LABEL_DOMAIN_TAG),
+        FieldElement::from(label.len() as u64),
+    ];
+    personalization.extend_from_slice(label);
+    compute_vrf_output(msg, proof, Some(personalization.as_slice()))
+}
+
+/// Verify `proof` for `msg` under `pk` and return the VRF outputs labeled with each of `labels`.
+pub fn vrf_proof_to_hash_with_labels(
+    msg: &FieldElement,
+    pk: &VRFPk,
+    proof: &VRFProof,
+    labels: &[&[FieldElement]],
+) -> Result<Vec<FieldElement>, VRFError> {
+    vrf_proof_to_hash(msg, pk, proof)?;
+
+    labels
+        .iter()
+        .map(|label| vrf_output_with_label(msg, proof, label))
+        .collect()
+}
+
+/// Check at once that all the `points`, assumed on curve, are in the prime order subgroup,
+/// by checking a random linear combination of them computed with a multi-scalar multiplication:
+/// a point outside of the subgroup brings the combination outside of it, except with
+/// probability at most 1/q, for q the smallest prime factor of the cofactor.
+fn batch_check_subgroup(points: &[VRFPk]) -> Result<bool, VRFError> {
+    let mut rng = OsRng;
+    let scalars = (0..points.len())
+        .map(|_| VRFSk::rand(&mut rng))
+        .collect::<Vec<_>>();
+
+    let combination = multi_scalar_mul(points, scalars.as_slice())
+        .map_err(|e| VRFError::Internal(e.to_string()))?;
+    Ok(combination
+        .into_affine()
+        .is_in_correct_subgroup_assuming_on_curve())
+}
+
+/// Verify many VRF proofs, each one given as (msg, pk, proof), returning the corresponding
+/// VRF outputs if all of them are valid, or the indices of the invalid ones otherwise.
+/// The group checks on the public keys and on the gamma points of the proofs are batched
+/// into a single multi-scalar multiplication; if the batched check fails, each item is
+/// checked separately to find out the invalid ones. The proofs themselves are verified one
+/// by one: as the challenge of a proof is the hash of the points recomputed by the verifier,
+/// their verification equations can't be combined.
+/// Internal failures are returned as `Err`, and never reported as invalid items.
+pub fn vrf_batch_proof_to_hash(
+    items: &[(FieldElement, VRFPk, VRFProof)],
+) -> Result<Result<Vec<FieldElement>, Vec<usize>>, VRFError> {
+    // Group checks: points on curve one by one, subgroup membership all at once
+    let mut points = Vec::with_capacity(2 * items.len());
+    let mut all_on_curve = true;
+    for (_, pk, proof) in items.iter() {
+        let gamma = proof.gamma.into_affine();
+        if pk.into_projective().is_zero() || !pk.is_on_curve() || !gamma.is_on_curve() {
+            all_on_curve = false;
+            break;
+        }
+        points.push(*pk);
+        points.push(gamma);
+    }
+
+    if !(all_on_curve && batch_check_subgroup(points.as_slice())?) {
+        let failing_indices = items
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, pk, proof))| {
+                !(vrf_verify_public_key(pk) && proof.gamma.into_affine().is_valid())
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        if !failing_indices.is_empty() {
+            return Ok(Err(failing_indices));
+        }
+    }
+
+    // Proofs verification
+    let mut outputs = Vec::with_capacity(items.len());
+    let mut failing_indices = Vec::new();
+    for (i, (msg, pk, proof)) in items.iter().enumerate() {
+        match proof_to_hash_unchecked(VRFWindowConfig::Default, &VRF_GH_PARAMS, msg, pk, proof) {
+            Ok(output) => outputs.push(output),
+            Err(VRFError::Internal(e)) => return Err(VRFError::Internal(e)),
+            Err(_) => failing_indices.push(i),
+        }
+    }
+
+    if failing_indices.is_empty() {
+        Ok(Ok(outputs))
+    } else {
+        Ok(Err(failing_indices))
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use jni::objects::JThrowable;
 use std::{any::Any, error::Error};
 
 pub const JNI_NULL: jobject = std::ptr::null::<jobject>() as jobject;
@@ -29,6 +30,31 @@ pub fn _throw_inner(env: &JNIEnv, exception: &str, description: &str) {
     }
 }
 
+/// Same as `_throw_inner`, for exceptions carrying an error code, i.e. having
+/// a (String message, int errorCode) constructor.
+pub fn _throw_with_code_inner(env: &JNIEnv, exception: &str, description: &str, code: jint) {
+    if !env.exception_check().unwrap() {
+        let exception_class = env
+            .find_class(exception)
+            .unwrap_or_else(|_| panic!("Unable to find {} class", exception));
+
+        let description = env
+            .new_string(description)
+            .expect("Should be able to create exception description");
+
+        let exception_object = env
+            .new_object(
+                exception_class,
+                "(Ljava/lang/String;I)V",
+                &[JValue::Object(description.into()), JValue::Int(code)],
+            )
+            .unwrap_or_else(|_| panic!("Should be able to create {}", exception));
+
+        env.throw(JThrowable::from(exception_object))
+            .unwrap_or_else(|_| panic!("Should be able to throw {}", exception))
+    }
+}
+
 /// Throw exception and exits from the function from within this macro is called
 /// returning $default or nothing (if the function returns void)
 #[macro_export]
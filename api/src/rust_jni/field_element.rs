@@ -1,5 +1,10 @@
 use super::*;
-use crate::ginger_calls::{field_element::*, into_i8};
+use crate::ginger_calls::{
+    field_element::*,
+    into_i8,
+    serialization::{deserialize_from_buffer_strict, ObjectType},
+    text_encoding::*,
+};
 
 ffi_export!(
     fn Java_io_horizen_common_librustsidechains_FieldElement_nativeGetFieldElementSize(
@@ -24,13 +29,22 @@ ffi_export!(
         _env: JNIEnv,
         _class: JClass,
         _field_element_bytes: jbyteArray,
+        _strict: jboolean,
     ) -> jobject {
         let fe_bytes = _env
             .convert_byte_array(_field_element_bytes)
             .expect("Cannot read field element bytes.");
-        map_to_jobject_or_throw_exc::<FieldElement, _>(
+
+        // In strict mode the field element must be exactly FIELD_SIZE bytes long, without padding
+        let fe = if _strict == JNI_TRUE {
+            deserialize_from_buffer_strict::<FieldElement>(fe_bytes.as_slice(), None, None)
+        } else {
+            read_field_element_from_buffer_with_padding(fe_bytes.as_slice())
+        };
+
+        map_to_jobject_or_throw_deserialization_exc::<FieldElement>(
             _env,
-            read_field_element_from_buffer_with_padding(fe_bytes.as_slice()),
+            fe,
             "io/horizen/common/librustsidechains/FieldElement",
             "io/horizen/common/librustsidechains/DeserializationException",
             "Unable to deserialize FieldElement",
@@ -38,6 +52,66 @@ ffi_export!(
     }
 );
 
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_FieldElement_nativeToHex(
+        _env: JNIEnv,
+        _field_element: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<FieldElement>(
+            _env,
+            _field_element,
+            "fieldElementPointer",
+            to_hex::<FieldElement>,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_FieldElement_nativeFromHex(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<FieldElement>(
+            _env,
+            _encoded,
+            from_hex::<FieldElement>,
+            "io/horizen/common/librustsidechains/FieldElement",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_FieldElement_nativeToJson(
+        _env: JNIEnv,
+        _field_element: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<FieldElement>(
+            _env,
+            _field_element,
+            "fieldElementPointer",
+            <FieldElement as JsonEncoding>::to_json,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_FieldElement_nativeFromJson(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<FieldElement>(
+            _env,
+            _encoded,
+            <FieldElement as JsonEncoding>::from_json,
+            "io/horizen/common/librustsidechains/FieldElement",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
 ffi_export!(
     fn Java_io_horizen_common_librustsidechains_FieldElement_nativeCreateRandom(
         _env: JNIEnv,
@@ -139,3 +213,37 @@ ffi_export!(
         }
     }
 );
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_FieldElement_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<FieldElement>(
+            _env,
+            _obj,
+            "fieldElementPointer",
+            ObjectType::FieldElement,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_FieldElement_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<FieldElement>(
+            _env,
+            _obj_bytes,
+            ObjectType::FieldElement,
+            None,
+            "io/horizen/common/librustsidechains/FieldElement",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
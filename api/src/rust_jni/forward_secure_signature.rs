@@ -0,0 +1,564 @@
+use super::*;
+use crate::ginger_calls::{
+    forward_secure_signature::*,
+    serialization::{is_valid, ObjectType},
+};
+use std::convert::TryFrom;
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeGetPublicKeySize(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jint {
+        FORWARD_SECURE_PK_SIZE as jint
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeSerializePublicKey(
+        _env: JNIEnv,
+        _public_key: JObject,
+    ) -> jbyteArray {
+        serialize_from_jobject::<ForwardSecurePk>(_env, _public_key, "publicKeyPointer", None)
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeDeserializePublicKey(
+        _env: JNIEnv,
+        _class: JClass,
+        _public_key_bytes: jbyteArray,
+        _check_public_key: jboolean,
+        _strict: jboolean,
+    ) -> jobject {
+        deserialize_to_jobject::<ForwardSecurePk>(
+            _env,
+            _public_key_bytes,
+            Some(_check_public_key),
+            None,
+            _strict,
+            "io/horizen/common/schnorrnative/ForwardSecurePublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeWriteToFile(
+        _env: JNIEnv,
+        _forward_secure_public_key: JObject,
+        _file_path: JString,
+    ) {
+        write_to_file_from_jobject::<ForwardSecurePk>(
+            _env,
+            _forward_secure_public_key,
+            "publicKeyPointer",
+            _file_path,
+            None,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+        _checked: jboolean,
+    ) -> jobject {
+        read_from_file_to_jobject::<ForwardSecurePk>(
+            _env,
+            _file_path,
+            Some(_checked),
+            None,
+            "io/horizen/common/schnorrnative/ForwardSecurePublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeFreePublicKey(
+        _env: JNIEnv,
+        _public_key: JObject,
+    ) {
+        drop_rust_struct_from_jobject::<ForwardSecurePk>(_env, _public_key, "publicKeyPointer")
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeGetHeight(
+        _env: JNIEnv,
+        _public_key: JObject,
+    ) -> jint {
+        let pk = parse_rust_struct_from_jobject::<ForwardSecurePk>(
+            &_env,
+            _public_key,
+            "publicKeyPointer",
+        );
+
+        pk.height as jint
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeVerifySignature(
+        _env: JNIEnv,
+        _public_key: JObject,
+        _signature: JObject,
+        _message: JObject,
+        _period: jlong,
+    ) -> jboolean {
+        let pk = parse_rust_struct_from_jobject::<ForwardSecurePk>(
+            &_env,
+            _public_key,
+            "publicKeyPointer",
+        );
+        let signature = parse_rust_struct_from_jobject::<ForwardSecureSig>(
+            &_env,
+            _signature,
+            "signaturePointer",
+        );
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        map_to_jboolean_or_throw_exc(
+            _env,
+            forward_secure_verify(message, pk, signature, _period as u64),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to verify forward secure signature",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeSerializeSecretKey(
+        _env: JNIEnv,
+        _secret_key: JObject,
+    ) -> jbyteArray {
+        serialize_from_jobject::<ForwardSecureSk>(_env, _secret_key, "secretKeyPointer", None)
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeDeserializeSecretKey(
+        _env: JNIEnv,
+        _class: JClass,
+        _secret_key_bytes: jbyteArray,
+        _strict: jboolean,
+    ) -> jobject {
+        deserialize_to_jobject::<ForwardSecureSk>(
+            _env,
+            _secret_key_bytes,
+            Some(JNI_TRUE),
+            None,
+            _strict,
+            "io/horizen/common/schnorrnative/ForwardSecureSecretKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeWriteToFile(
+        _env: JNIEnv,
+        _forward_secure_secret_key: JObject,
+        _file_path: JString,
+    ) {
+        write_to_file_from_jobject::<ForwardSecureSk>(
+            _env,
+            _forward_secure_secret_key,
+            "secretKeyPointer",
+            _file_path,
+            None,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+    ) -> jobject {
+        read_from_file_to_jobject::<ForwardSecureSk>(
+            _env,
+            _file_path,
+            None,
+            None,
+            "io/horizen/common/schnorrnative/ForwardSecureSecretKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeFreeSecretKey(
+        _env: JNIEnv,
+        _secret_key: JObject,
+    ) {
+        drop_rust_struct_from_jobject::<ForwardSecureSk>(_env, _secret_key, "secretKeyPointer")
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeGetPublicKey(
+        _env: JNIEnv,
+        _secret_key: JObject,
+    ) -> jobject {
+        let sk = parse_rust_struct_from_jobject::<ForwardSecureSk>(
+            &_env,
+            _secret_key,
+            "secretKeyPointer",
+        );
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            forward_secure_get_public_key(sk),
+            "io/horizen/common/schnorrnative/ForwardSecurePublicKey",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to get forward secure public key",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeGetPeriod(
+        _env: JNIEnv,
+        _secret_key: JObject,
+    ) -> jlong {
+        let sk = parse_rust_struct_from_jobject::<ForwardSecureSk>(
+            &_env,
+            _secret_key,
+            "secretKeyPointer",
+        );
+
+        sk.period as jlong
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeGetHeight(
+        _env: JNIEnv,
+        _secret_key: JObject,
+    ) -> jint {
+        let sk = parse_rust_struct_from_jobject::<ForwardSecureSk>(
+            &_env,
+            _secret_key,
+            "secretKeyPointer",
+        );
+
+        sk.height as jint
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeUpdateTo(
+        _env: JNIEnv,
+        _secret_key: JObject,
+        _period: jlong,
+    ) {
+        let sk = parse_mut_rust_struct_from_jobject::<ForwardSecureSk>(
+            &_env,
+            _secret_key,
+            "secretKeyPointer",
+        );
+
+        ok_or_throw_exc!(
+            _env,
+            forward_secure_update_to(sk, _period as u64),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to update forward secure secret key"
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeSignMessage(
+        _env: JNIEnv,
+        _secret_key: JObject,
+        _message: JObject,
+    ) -> jobject {
+        let sk = parse_rust_struct_from_jobject::<ForwardSecureSk>(
+            &_env,
+            _secret_key,
+            "secretKeyPointer",
+        );
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            forward_secure_sign(message, sk),
+            "io/horizen/common/schnorrnative/ForwardSecureSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to sign message",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSignature_nativeSerializeSignature(
+        _env: JNIEnv,
+        _signature: JObject,
+    ) -> jbyteArray {
+        serialize_from_jobject::<ForwardSecureSig>(
+            _env,
+            _signature,
+            "signaturePointer",
+            Some(JNI_TRUE),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSignature_nativeDeserializeSignature(
+        _env: JNIEnv,
+        _class: JClass,
+        _signature_bytes: jbyteArray,
+        _check_signature: jboolean,
+        _strict: jboolean,
+    ) -> jobject {
+        deserialize_to_jobject::<ForwardSecureSig>(
+            _env,
+            _signature_bytes,
+            Some(_check_signature),
+            Some(JNI_TRUE),
+            _strict,
+            "io/horizen/common/schnorrnative/ForwardSecureSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSignature_nativeWriteToFile(
+        _env: JNIEnv,
+        _forward_secure_signature: JObject,
+        _file_path: JString,
+    ) {
+        write_to_file_from_jobject::<ForwardSecureSig>(
+            _env,
+            _forward_secure_signature,
+            "signaturePointer",
+            _file_path,
+            None,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSignature_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+        _checked: jboolean,
+    ) -> jobject {
+        read_from_file_to_jobject::<ForwardSecureSig>(
+            _env,
+            _file_path,
+            Some(_checked),
+            None,
+            "io/horizen/common/schnorrnative/ForwardSecureSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSignature_nativeIsValidSignature(
+        _env: JNIEnv,
+        _signature: JObject,
+    ) -> jboolean {
+        let signature = parse_rust_struct_from_jobject::<ForwardSecureSig>(
+            &_env,
+            _signature,
+            "signaturePointer",
+        );
+
+        if is_valid(signature) {
+            JNI_TRUE
+        } else {
+            JNI_FALSE
+        }
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSignature_nativeGetPeriod(
+        _env: JNIEnv,
+        _signature: JObject,
+    ) -> jlong {
+        let signature = parse_rust_struct_from_jobject::<ForwardSecureSig>(
+            &_env,
+            _signature,
+            "signaturePointer",
+        );
+
+        signature.period as jlong
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSignature_nativeFreeSignature(
+        _env: JNIEnv,
+        _signature: JObject,
+    ) {
+        drop_rust_struct_from_jobject::<ForwardSecureSig>(_env, _signature, "signaturePointer")
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureKeyPair_nativeGenerate(
+        _env: JNIEnv,
+        _class: JClass,
+        _height: jint,
+    ) -> jobject {
+        // Check the range before narrowing, e.g. 300 mustn't become 44
+        let height = ok_or_throw_exc!(
+            &_env,
+            u8::try_from(_height).map_err(|_| format!(
+                "Height must be in [1, {}]. Found {}",
+                FORWARD_SECURE_MAX_HEIGHT, _height
+            )),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to generate forward secure key pair",
+            JNI_NULL
+        );
+
+        let (pk, sk) = ok_or_throw_exc!(
+            &_env,
+            forward_secure_generate_key(height),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to generate forward secure key pair",
+            JNI_NULL
+        );
+
+        let secret_key_object = return_jobject(
+            &_env,
+            sk,
+            "io/horizen/common/schnorrnative/ForwardSecureSecretKey",
+        );
+        let public_key_object = return_jobject(
+            &_env,
+            pk,
+            "io/horizen/common/schnorrnative/ForwardSecurePublicKey",
+        );
+
+        let class = _env
+            .find_class("io/horizen/common/schnorrnative/ForwardSecureKeyPair")
+            .expect("Should be able to find ForwardSecureKeyPair class");
+
+        let result = _env.new_object(
+            class,
+            "(Lio/horizen/common/schnorrnative/ForwardSecureSecretKey;Lio/horizen/common/schnorrnative/ForwardSecurePublicKey;)V",
+            &[JValue::Object(secret_key_object), JValue::Object(public_key_object)]
+        ).expect("Should be able to create new (ForwardSecureSecretKey, ForwardSecurePublicKey) object");
+
+        *result
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<ForwardSecurePk>(
+            _env,
+            _obj,
+            "publicKeyPointer",
+            ObjectType::ForwardSecurePublicKey,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecurePublicKey_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<ForwardSecurePk>(
+            _env,
+            _obj_bytes,
+            ObjectType::ForwardSecurePublicKey,
+            Some(_checked),
+            "io/horizen/common/schnorrnative/ForwardSecurePublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<ForwardSecureSk>(
+            _env,
+            _obj,
+            "secretKeyPointer",
+            ObjectType::ForwardSecureSecretKey,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSecretKey_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<ForwardSecureSk>(
+            _env,
+            _obj_bytes,
+            ObjectType::ForwardSecureSecretKey,
+            Some(JNI_TRUE),
+            "io/horizen/common/schnorrnative/ForwardSecureSecretKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSignature_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<ForwardSecureSig>(
+            _env,
+            _obj,
+            "signaturePointer",
+            ObjectType::ForwardSecureSignature,
+            Some(JNI_TRUE),
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_ForwardSecureSignature_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<ForwardSecureSig>(
+            _env,
+            _obj_bytes,
+            ObjectType::ForwardSecureSignature,
+            Some(_checked),
+            "io/horizen/common/schnorrnative/ForwardSecureSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
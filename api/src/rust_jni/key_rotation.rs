@@ -0,0 +1,352 @@
+use super::{schnorr_signature::parse_schnorr_key_pair, vrf::parse_vrf_key_pair, *};
+use crate::ginger_calls::{
+    key_rotation::*,
+    serialization::{is_valid, ObjectType},
+};
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeGetCertificateSize(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jint {
+        KEY_ROTATION_CERTIFICATE_SIZE as jint
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeSerializeCertificate(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) -> jbyteArray {
+        serialize_from_jobject::<KeyRotationCertificate>(
+            _env,
+            _certificate,
+            "certificatePointer",
+            Some(JNI_TRUE),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeDeserializeCertificate(
+        _env: JNIEnv,
+        _class: JClass,
+        _certificate_bytes: jbyteArray,
+        _check_certificate: jboolean,
+        _strict: jboolean,
+    ) -> jobject {
+        deserialize_to_jobject::<KeyRotationCertificate>(
+            _env,
+            _certificate_bytes,
+            Some(_check_certificate),
+            Some(JNI_TRUE),
+            _strict,
+            "io/horizen/common/librustsidechains/KeyRotationCertificate",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeIsValidCertificate(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) -> jboolean {
+        let certificate = parse_rust_struct_from_jobject::<KeyRotationCertificate>(
+            &_env,
+            _certificate,
+            "certificatePointer",
+        );
+
+        if is_valid(certificate) {
+            JNI_TRUE
+        } else {
+            JNI_FALSE
+        }
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeFreeCertificate(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) {
+        drop_rust_struct_from_jobject::<KeyRotationCertificate>(
+            _env,
+            _certificate,
+            "certificatePointer",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeCreateSchnorr(
+        _env: JNIEnv,
+        _class: JClass,
+        _old_key_pair: JObject,
+        _new_public_key: JObject,
+        _epoch: jlong,
+    ) -> jobject {
+        let (old_sk, old_pk) = parse_schnorr_key_pair(&_env, _old_key_pair);
+        let new_pk =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _new_public_key, "publicKeyPointer");
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            create_schnorr_key_rotation_certificate(old_sk, old_pk, new_pk, _epoch as u64),
+            "io/horizen/common/librustsidechains/KeyRotationCertificate",
+            "io/horizen/common/librustsidechains/KeyRotationException",
+            "Unable to create key rotation certificate",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeCreateVRF(
+        _env: JNIEnv,
+        _class: JClass,
+        _old_key_pair: JObject,
+        _new_public_key: JObject,
+        _epoch: jlong,
+    ) -> jobject {
+        let (old_sk, old_pk) = parse_vrf_key_pair(&_env, _old_key_pair);
+        let new_pk =
+            parse_rust_struct_from_jobject::<VRFPk>(&_env, _new_public_key, "publicKeyPointer");
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            create_vrf_key_rotation_certificate(old_sk, old_pk, new_pk, _epoch as u64),
+            "io/horizen/common/librustsidechains/KeyRotationCertificate",
+            "io/horizen/common/librustsidechains/KeyRotationException",
+            "Unable to create key rotation certificate",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeVerify(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) -> jboolean {
+        let certificate = parse_rust_struct_from_jobject::<KeyRotationCertificate>(
+            &_env,
+            _certificate,
+            "certificatePointer",
+        );
+
+        map_to_jboolean_or_throw_exc(
+            _env,
+            verify_key_rotation_certificate(certificate),
+            "io/horizen/common/librustsidechains/KeyRotationException",
+            "Unable to verify key rotation certificate",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeGetKeyType(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) -> jint {
+        let certificate = parse_rust_struct_from_jobject::<KeyRotationCertificate>(
+            &_env,
+            _certificate,
+            "certificatePointer",
+        );
+
+        certificate.key_type as jint
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeGetEpoch(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) -> jlong {
+        let certificate = parse_rust_struct_from_jobject::<KeyRotationCertificate>(
+            &_env,
+            _certificate,
+            "certificatePointer",
+        );
+
+        certificate.epoch as jlong
+    }
+);
+
+/// Return old or new public key of `_certificate` as an instance of `class_path`
+fn return_certificate_public_key(
+    _env: JNIEnv,
+    _certificate: JObject,
+    old: bool,
+    class_path: &str,
+) -> jobject {
+    let certificate = parse_rust_struct_from_jobject::<KeyRotationCertificate>(
+        &_env,
+        _certificate,
+        "certificatePointer",
+    );
+
+    let pk = if old {
+        certificate.old_pk
+    } else {
+        certificate.new_pk
+    };
+
+    return_jobject(&_env, pk, class_path).into_inner()
+}
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeGetOldSchnorrPublicKey(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) -> jobject {
+        return_certificate_public_key(
+            _env,
+            _certificate,
+            true,
+            "io/horizen/common/schnorrnative/SchnorrPublicKey",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeGetNewSchnorrPublicKey(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) -> jobject {
+        return_certificate_public_key(
+            _env,
+            _certificate,
+            false,
+            "io/horizen/common/schnorrnative/SchnorrPublicKey",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeGetOldVRFPublicKey(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) -> jobject {
+        return_certificate_public_key(
+            _env,
+            _certificate,
+            true,
+            "io/horizen/common/vrfnative/VRFPublicKey",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeGetNewVRFPublicKey(
+        _env: JNIEnv,
+        _certificate: JObject,
+    ) -> jobject {
+        return_certificate_public_key(
+            _env,
+            _certificate,
+            false,
+            "io/horizen/common/vrfnative/VRFPublicKey",
+        )
+    }
+);
+
+/// Validate the chain of certificates `_certificates`, rotating `_initial_public_key`, and
+/// return the current public key as an instance of `class_path`
+fn return_key_rotation_chain_result(
+    _env: JNIEnv,
+    key_type: RotatedKeyType,
+    _initial_public_key: JObject,
+    _certificates: jobjectArray,
+    class_path: &str,
+) -> jobject {
+    let initial_pk =
+        parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _initial_public_key, "publicKeyPointer");
+
+    let mut certificates: Vec<&KeyRotationCertificate> = vec![];
+    parse_rust_struct_vec_from_jobject_array!(
+        _env,
+        _certificates,
+        certificates,
+        "certificates",
+        "certificatePointer"
+    );
+    let certificates = certificates.into_iter().cloned().collect::<Vec<_>>();
+
+    map_to_jobject_or_throw_exc(
+        _env,
+        verify_key_rotation_chain(key_type, initial_pk, certificates.as_slice()),
+        class_path,
+        "io/horizen/common/librustsidechains/KeyRotationException",
+        "Invalid key rotation chain",
+    )
+}
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeVerifySchnorrChain(
+        _env: JNIEnv,
+        _class: JClass,
+        _initial_public_key: JObject,
+        _certificates: jobjectArray,
+    ) -> jobject {
+        return_key_rotation_chain_result(
+            _env,
+            RotatedKeyType::Schnorr,
+            _initial_public_key,
+            _certificates,
+            "io/horizen/common/schnorrnative/SchnorrPublicKey",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeVerifyVRFChain(
+        _env: JNIEnv,
+        _class: JClass,
+        _initial_public_key: JObject,
+        _certificates: jobjectArray,
+    ) -> jobject {
+        return_key_rotation_chain_result(
+            _env,
+            RotatedKeyType::VRF,
+            _initial_public_key,
+            _certificates,
+            "io/horizen/common/vrfnative/VRFPublicKey",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<KeyRotationCertificate>(
+            _env,
+            _obj,
+            "certificatePointer",
+            ObjectType::KeyRotationCertificate,
+            Some(JNI_TRUE),
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_librustsidechains_KeyRotationCertificate_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<KeyRotationCertificate>(
+            _env,
+            _obj_bytes,
+            ObjectType::KeyRotationCertificate,
+            Some(_checked),
+            "io/horizen/common/librustsidechains/KeyRotationCertificate",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
@@ -0,0 +1,66 @@
+use super::*;
+use crate::ginger_calls::leader_election::*;
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFLeaderElection_nativeGetThresholdFracBits(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jint {
+        THRESHOLD_FRAC_BITS as jint
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFLeaderElection_nativeGetThreshold(
+        _env: JNIEnv,
+        _class: JClass,
+        _stake: jlong,
+        _total_stake: jlong,
+        _active_slot_coeff_num: jlong,
+        _active_slot_coeff_den: jlong,
+    ) -> jlong {
+        ok_or_throw_exc!(
+            &_env,
+            vrf_leader_threshold(
+                _stake as u64,
+                _total_stake as u64,
+                _active_slot_coeff_num as u64,
+                _active_slot_coeff_den as u64,
+            ),
+            "io/horizen/common/vrfnative/VRFException",
+            "Unable to compute leader election threshold",
+            -1
+        ) as jlong
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFLeaderElection_nativeIsSlotLeader(
+        _env: JNIEnv,
+        _class: JClass,
+        _vrf_output: JObject,
+        _stake: jlong,
+        _total_stake: jlong,
+        _active_slot_coeff_num: jlong,
+        _active_slot_coeff_den: jlong,
+    ) -> jboolean {
+        let vrf_output = parse_rust_struct_from_jobject::<FieldElement>(
+            &_env,
+            _vrf_output,
+            "fieldElementPointer",
+        );
+
+        map_to_jboolean_or_throw_exc(
+            _env,
+            vrf_is_slot_leader(
+                vrf_output,
+                _stake as u64,
+                _total_stake as u64,
+                _active_slot_coeff_num as u64,
+                _active_slot_coeff_den as u64,
+            ),
+            "io/horizen/common/vrfnative/VRFException",
+            "Unable to check slot leader eligibility",
+        )
+    }
+);
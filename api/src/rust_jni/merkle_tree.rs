@@ -1,6 +1,17 @@
+use super::stream::JavaInputStream;
 use super::*;
-use crate::ginger_calls::merkle_tree::*;
-use algebra::{CanonicalDeserialize, SemanticallyValid};
+use crate::ginger_calls::{
+    merkle_tree::*,
+    serialization::{
+        check_file_fully_read, check_no_trailing_bytes, open_envelope, ObjectType, DEFAULT_BUF_SIZE,
+    },
+    text_encoding::*,
+};
+use algebra::{SemanticallyValid, SerializationError};
+use std::{
+    fs::File,
+    io::{BufReader, Cursor},
+};
 
 ffi_export!(
     fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeVerify(
@@ -141,12 +152,143 @@ ffi_export!(
         _class: JClass,
         _path_bytes: jbyteArray,
         _checked: jboolean,
+        _strict: jboolean,
     ) -> jobject {
         deserialize_to_jobject::<GingerMHTPath>(
             _env,
             _path_bytes,
             Some(_checked),
             None,
+            _strict,
+            "io/horizen/common/merkletreenative/FieldBasedMerklePath",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeSerializeCompact(
+        _env: JNIEnv,
+        _path: JObject,
+    ) -> jbyteArray {
+        let path =
+            parse_rust_struct_from_jobject::<GingerMHTPath>(&_env, _path, "merklePathPointer");
+
+        let path_bytes =
+            encode_ginger_mht_path_compact(path).expect("unable to write GingerMHTPath to buffer");
+
+        _env.byte_array_from_slice(path_bytes.as_slice())
+            .expect("Cannot write object.")
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeDeserializeCompact(
+        _env: JNIEnv,
+        _class: JClass,
+        _path_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        let path_bytes = _env
+            .convert_byte_array(_path_bytes)
+            .expect("Cannot read path bytes.");
+
+        map_to_jobject_or_throw_deserialization_exc(
+            _env,
+            decode_ginger_mht_path_compact(path_bytes.as_slice(), _checked == JNI_TRUE),
+            "io/horizen/common/merkletreenative/FieldBasedMerklePath",
+            "io/horizen/common/librustsidechains/DeserializationException",
+            "Unable to deserialize compact MerklePath",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeWriteToFile(
+        _env: JNIEnv,
+        _field_based_merkle_path: JObject,
+        _file_path: JString,
+    ) {
+        write_to_file_from_jobject::<GingerMHTPath>(
+            _env,
+            _field_based_merkle_path,
+            "merklePathPointer",
+            _file_path,
+            None,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+    ) -> jobject {
+        read_from_file_to_jobject::<GingerMHTPath>(
+            _env,
+            _file_path,
+            None,
+            "io/horizen/common/merkletreenative/FieldBasedMerklePath",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeToHex(
+        _env: JNIEnv,
+        _field_based_merkle_path: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<GingerMHTPath>(
+            _env,
+            _field_based_merkle_path,
+            "merklePathPointer",
+            to_hex::<GingerMHTPath>,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeFromHex(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<GingerMHTPath>(
+            _env,
+            _encoded,
+            from_hex::<GingerMHTPath>,
+            "io/horizen/common/merkletreenative/FieldBasedMerklePath",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeToJson(
+        _env: JNIEnv,
+        _field_based_merkle_path: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<GingerMHTPath>(
+            _env,
+            _field_based_merkle_path,
+            "merklePathPointer",
+            <GingerMHTPath as JsonEncoding>::to_json,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeFromJson(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<GingerMHTPath>(
+            _env,
+            _encoded,
+            <GingerMHTPath as JsonEncoding>::from_json,
             "io/horizen/common/merkletreenative/FieldBasedMerklePath",
             "io/horizen/common/librustsidechains/DeserializationException",
         )
@@ -320,22 +462,42 @@ ffi_export!(
     }
 );
 
+/// Bound on the height of a tree read in checked mode, if `max_checked_height` is positive,
+/// otherwise None for unchecked reads
+fn checked_max_height(max_checked_height: jint) -> Option<usize> {
+    if max_checked_height > 0 {
+        Some(max_checked_height as usize)
+    } else {
+        None
+    }
+}
+
 ffi_export!(
     fn Java_io_horizen_common_merkletreenative_BaseMerkleTree_nativeDeserialize(
         _env: JNIEnv,
         _class: JClass,
         _tree_bytes: jbyteArray,
+        _max_checked_height: jint,
+        _strict: jboolean,
     ) -> jobject {
-        // Not really necessary to do deep checks: no one can trick a node into using different _tree_bytes
-        // possibly deserializing an unconsistent tree that may lead to internal crashes (DOS).
-        // TODO: Is this true ?
+        // Deep checks are needed only for trees coming from untrusted sources: an inconsistent
+        // tree may lead to internal crashes (DOS).
         let obj_bytes = _env
             .convert_byte_array(_tree_bytes)
             .expect("Cannot read tree bytes.");
 
-        map_to_jobject_or_throw_exc(
+        let mut reader = Cursor::new(obj_bytes.as_slice());
+        let tree = deserialize_ginger_mht(&mut reader, checked_max_height(_max_checked_height))
+            .and_then(|tree| {
+                if _strict == JNI_TRUE {
+                    check_no_trailing_bytes(reader.position(), obj_bytes.len() as u64)?;
+                }
+                Ok(tree)
+            });
+
+        map_to_jobject_or_throw_deserialization_exc(
             _env,
-            <GingerMHT as CanonicalDeserialize>::deserialize(obj_bytes.as_slice()),
+            tree,
             "io/horizen/common/merkletreenative/BaseMerkleTree",
             "io/horizen/common/librustsidechains/DeserializationException",
             "Unable to deserialize MerkleTree",
@@ -343,6 +505,90 @@ ffi_export!(
     }
 );
 
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_BaseMerkleTree_nativeSerializeToStream(
+        _env: JNIEnv,
+        _tree: JObject,
+        _stream: JObject,
+    ) {
+        serialize_to_stream_from_jobject::<GingerMHT>(
+            _env,
+            _tree,
+            "inMemoryOptimizedMerkleTreePointer",
+            None,
+            _stream,
+            "java/io/IOException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_BaseMerkleTree_nativeDeserializeFromStream(
+        _env: JNIEnv,
+        _class: JClass,
+        _stream: JObject,
+        _max_checked_height: jint,
+    ) -> jobject {
+        let tree = JavaInputStream::new(&_env, _stream)
+            .map_err(SerializationError::IoError)
+            .and_then(|stream| {
+                deserialize_ginger_mht(stream, checked_max_height(_max_checked_height))
+            });
+
+        map_to_jobject_or_throw_deserialization_exc(
+            _env,
+            tree,
+            "io/horizen/common/merkletreenative/BaseMerkleTree",
+            "io/horizen/common/librustsidechains/DeserializationException",
+            "Unable to deserialize MerkleTree from stream",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_BaseMerkleTree_nativeWriteToFile(
+        _env: JNIEnv,
+        _tree: JObject,
+        _file_path: JString,
+    ) {
+        write_to_file_from_jobject::<GingerMHT>(
+            _env,
+            _tree,
+            "inMemoryOptimizedMerkleTreePointer",
+            _file_path,
+            None,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_BaseMerkleTree_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+        _max_checked_height: jint,
+    ) -> jobject {
+        let file_path = parse_path_from_jstring(&_env, _file_path);
+        let tree = File::open(file_path.as_str())
+            .map_err(SerializationError::IoError)
+            .and_then(|fs| {
+                let mut reader = BufReader::with_capacity(DEFAULT_BUF_SIZE, fs);
+                let tree =
+                    deserialize_ginger_mht(&mut reader, checked_max_height(_max_checked_height))?;
+                check_file_fully_read(&mut reader)?;
+                Ok(tree)
+            });
+
+        map_to_jobject_or_throw_deserialization_exc(
+            _env,
+            tree,
+            "io/horizen/common/merkletreenative/BaseMerkleTree",
+            "io/horizen/common/librustsidechains/DeserializationException",
+            format!("Unable to read MerkleTree from {}", file_path).as_str(),
+        )
+    }
+);
+
 ffi_export!(
     fn Java_io_horizen_common_merkletreenative_BaseMerkleTree_nativeReset(
         _env: JNIEnv,
@@ -370,3 +616,86 @@ ffi_export!(
         )
     }
 );
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<GingerMHTPath>(
+            _env,
+            _obj,
+            "merklePathPointer",
+            ObjectType::MerklePath,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_FieldBasedMerklePath_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<GingerMHTPath>(
+            _env,
+            _obj_bytes,
+            ObjectType::MerklePath,
+            Some(_checked),
+            "io/horizen/common/merkletreenative/FieldBasedMerklePath",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_BaseMerkleTree_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _tree: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<GingerMHT>(
+            _env,
+            _tree,
+            "inMemoryOptimizedMerkleTreePointer",
+            ObjectType::MerkleTree,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_merkletreenative_BaseMerkleTree_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _tree_bytes: jbyteArray,
+        _max_checked_height: jint,
+    ) -> jobject {
+        let obj_bytes = _env
+            .convert_byte_array(_tree_bytes)
+            .expect("Cannot read tree bytes.");
+
+        // As for the other objects, the whole payload must be consumed
+        let tree =
+            open_envelope(obj_bytes.as_slice(), ObjectType::MerkleTree).and_then(|(payload, _)| {
+                let mut reader = Cursor::new(payload);
+                let tree =
+                    deserialize_ginger_mht(&mut reader, checked_max_height(_max_checked_height))?;
+                check_no_trailing_bytes(reader.position(), payload.len() as u64)?;
+                Ok(tree)
+            });
+
+        map_to_jobject_or_throw_deserialization_exc(
+            _env,
+            tree,
+            "io/horizen/common/merkletreenative/BaseMerkleTree",
+            "io/horizen/common/librustsidechains/DeserializationException",
+            "Unable to deserialize MerkleTree",
+        )
+    }
+);
@@ -1,7 +1,7 @@
 use super::*;
 use jni::{
-    objects::{JClass, JObject, JValue},
-    sys::{jboolean, jbyteArray, jint, jlong, jobject, jobjectArray, JNI_FALSE, JNI_TRUE},
+    objects::{JClass, JObject, JString, JValue},
+    sys::{jboolean, jbyteArray, jint, jlong, jobject, jobjectArray, jstring, JNI_FALSE, JNI_TRUE},
     JNIEnv,
 };
 
@@ -12,9 +12,14 @@ pub mod exception;
 use exception::*;
 
 pub mod field_element;
+pub mod forward_secure_signature;
+pub mod key_rotation;
+pub mod leader_election;
 pub mod merkle_tree;
 pub mod poseidon_hash;
+pub mod randomness_beacon;
 pub mod schnorr_signature;
+pub mod stream;
 pub mod vrf;
 
 ffi_export!(
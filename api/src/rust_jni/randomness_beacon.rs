@@ -0,0 +1,213 @@
+use super::*;
+use crate::ginger_calls::{
+    randomness_beacon::*,
+    serialization::{is_valid, ObjectType},
+};
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeGetBeaconSize(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jint {
+        RANDOMNESS_BEACON_SIZE as jint
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeSerializeBeacon(
+        _env: JNIEnv,
+        _beacon: JObject,
+    ) -> jbyteArray {
+        serialize_from_jobject::<RandomnessBeacon>(_env, _beacon, "beaconPointer", None)
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeDeserializeBeacon(
+        _env: JNIEnv,
+        _class: JClass,
+        _beacon_bytes: jbyteArray,
+        _check_beacon: jboolean,
+        _strict: jboolean,
+    ) -> jobject {
+        deserialize_to_jobject::<RandomnessBeacon>(
+            _env,
+            _beacon_bytes,
+            Some(_check_beacon),
+            None,
+            _strict,
+            "io/horizen/common/vrfnative/RandomnessBeacon",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeIsValidBeacon(
+        _env: JNIEnv,
+        _beacon: JObject,
+    ) -> jboolean {
+        let beacon =
+            parse_rust_struct_from_jobject::<RandomnessBeacon>(&_env, _beacon, "beaconPointer");
+
+        if is_valid(beacon) {
+            JNI_TRUE
+        } else {
+            JNI_FALSE
+        }
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeFreeBeacon(
+        _env: JNIEnv,
+        _beacon: JObject,
+    ) {
+        drop_rust_struct_from_jobject::<RandomnessBeacon>(_env, _beacon, "beaconPointer")
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeCreate(
+        _env: JNIEnv,
+        _class: JClass,
+        _initial_nonce: JObject,
+    ) -> jobject {
+        let initial_nonce = parse_rust_struct_from_jobject::<FieldElement>(
+            &_env,
+            _initial_nonce,
+            "fieldElementPointer",
+        );
+
+        return_jobject(
+            &_env,
+            new_randomness_beacon(initial_nonce),
+            "io/horizen/common/vrfnative/RandomnessBeacon",
+        )
+        .into_inner()
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeAbsorb(
+        _env: JNIEnv,
+        _beacon: JObject,
+        _vrf_output: JObject,
+    ) {
+        let beacon =
+            parse_mut_rust_struct_from_jobject::<RandomnessBeacon>(&_env, _beacon, "beaconPointer");
+        let vrf_output = parse_rust_struct_from_jobject::<FieldElement>(
+            &_env,
+            _vrf_output,
+            "fieldElementPointer",
+        );
+
+        ok_or_throw_exc!(
+            _env,
+            absorb_vrf_output(beacon, vrf_output),
+            "io/horizen/common/vrfnative/VRFException",
+            "Unable to absorb VRF output"
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeEndEpoch(
+        _env: JNIEnv,
+        _beacon: JObject,
+    ) -> jobject {
+        let beacon =
+            parse_mut_rust_struct_from_jobject::<RandomnessBeacon>(&_env, _beacon, "beaconPointer");
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            end_beacon_epoch(beacon),
+            "io/horizen/common/librustsidechains/FieldElement",
+            "io/horizen/common/vrfnative/VRFException",
+            "Unable to end beacon epoch",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeGetRollingNonce(
+        _env: JNIEnv,
+        _beacon: JObject,
+    ) -> jobject {
+        let beacon =
+            parse_rust_struct_from_jobject::<RandomnessBeacon>(&_env, _beacon, "beaconPointer");
+
+        return_field_element(_env, get_rolling_nonce(beacon))
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeGetEpochNonce(
+        _env: JNIEnv,
+        _beacon: JObject,
+    ) -> jobject {
+        let beacon =
+            parse_rust_struct_from_jobject::<RandomnessBeacon>(&_env, _beacon, "beaconPointer");
+
+        return_field_element(_env, beacon.epoch_nonce)
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeGetEpoch(
+        _env: JNIEnv,
+        _beacon: JObject,
+    ) -> jlong {
+        let beacon =
+            parse_rust_struct_from_jobject::<RandomnessBeacon>(&_env, _beacon, "beaconPointer");
+
+        beacon.epoch as jlong
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeGetOutputsCount(
+        _env: JNIEnv,
+        _beacon: JObject,
+    ) -> jlong {
+        let beacon =
+            parse_rust_struct_from_jobject::<RandomnessBeacon>(&_env, _beacon, "beaconPointer");
+
+        beacon.outputs_count as jlong
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<RandomnessBeacon>(
+            _env,
+            _obj,
+            "beaconPointer",
+            ObjectType::RandomnessBeacon,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_RandomnessBeacon_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<RandomnessBeacon>(
+            _env,
+            _obj_bytes,
+            ObjectType::RandomnessBeacon,
+            Some(_checked),
+            "io/horizen/common/vrfnative/RandomnessBeacon",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
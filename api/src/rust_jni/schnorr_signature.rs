@@ -1,5 +1,63 @@
 use super::*;
-use crate::ginger_calls::{schnorr_signature::*, serialization::is_valid};
+use crate::ginger_calls::{
+    schnorr_signature::*,
+    serialization::{is_valid, ObjectType},
+    text_encoding::*,
+};
+
+pub(crate) fn parse_schnorr_key_pair<'a>(
+    _env: &'a JNIEnv,
+    _schnorr_key_pair: JObject<'a>,
+) -> (&'a SchnorrSk, &'a SchnorrPk) {
+    //Read sk
+    let sk_object = _env
+        .get_field(
+            _schnorr_key_pair,
+            "secretKey",
+            "Lio/horizen/common/schnorrnative/SchnorrSecretKey;",
+        )
+        .expect("Should be able to get field secretKey")
+        .l()
+        .unwrap();
+
+    let secret_key =
+        parse_rust_struct_from_jobject::<SchnorrSk>(_env, sk_object, "secretKeyPointer");
+
+    //Read pk
+    let pk_object = _env
+        .get_field(
+            _schnorr_key_pair,
+            "publicKey",
+            "Lio/horizen/common/schnorrnative/SchnorrPublicKey;",
+        )
+        .expect("Should be able to get field publicKey")
+        .l()
+        .unwrap();
+
+    let public_key =
+        parse_rust_struct_from_jobject::<SchnorrPk>(_env, pk_object, "publicKeyPointer");
+
+    (secret_key, public_key)
+}
+
+fn return_schnorr_key_pair(_env: &JNIEnv, pk: SchnorrPk, sk: SchnorrSk) -> jobject {
+    let secret_key_object =
+        return_jobject(_env, sk, "io/horizen/common/schnorrnative/SchnorrSecretKey");
+    let public_key_object =
+        return_jobject(_env, pk, "io/horizen/common/schnorrnative/SchnorrPublicKey");
+
+    let class = _env
+        .find_class("io/horizen/common/schnorrnative/SchnorrKeyPair")
+        .expect("Should be able to find SchnorrKeyPair class");
+
+    let result = _env.new_object(
+        class,
+        "(Lio/horizen/common/schnorrnative/SchnorrSecretKey;Lio/horizen/common/schnorrnative/SchnorrPublicKey;)V",
+        &[JValue::Object(secret_key_object), JValue::Object(public_key_object)]
+    ).expect("Should be able to create new (SchnorrSecretKey, SchnorrPublicKey) object");
+
+    *result
+}
 
 ffi_export!(
     fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeGetPublicKeySize(
@@ -32,12 +90,110 @@ ffi_export!(
         _public_key_bytes: jbyteArray,
         _check_public_key: jboolean,
         _compressed: jboolean,
+        _strict: jboolean,
     ) -> jobject {
         deserialize_to_jobject::<SchnorrPk>(
             _env,
             _public_key_bytes,
             Some(_check_public_key),
             Some(_compressed),
+            _strict,
+            "io/horizen/common/schnorrnative/SchnorrPublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeWriteToFile(
+        _env: JNIEnv,
+        _schnorr_public_key: JObject,
+        _file_path: JString,
+        _compressed: jboolean,
+    ) {
+        write_to_file_from_jobject::<SchnorrPk>(
+            _env,
+            _schnorr_public_key,
+            "publicKeyPointer",
+            _file_path,
+            Some(_compressed),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+        _checked: jboolean,
+        _compressed: jboolean,
+    ) -> jobject {
+        read_from_file_to_jobject::<SchnorrPk>(
+            _env,
+            _file_path,
+            Some(_checked),
+            Some(_compressed),
+            "io/horizen/common/schnorrnative/SchnorrPublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeToHex(
+        _env: JNIEnv,
+        _schnorr_public_key: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<SchnorrPk>(
+            _env,
+            _schnorr_public_key,
+            "publicKeyPointer",
+            to_hex::<SchnorrPk>,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeFromHex(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<SchnorrPk>(
+            _env,
+            _encoded,
+            from_hex::<SchnorrPk>,
+            "io/horizen/common/schnorrnative/SchnorrPublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeToJson(
+        _env: JNIEnv,
+        _schnorr_public_key: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<SchnorrPk>(
+            _env,
+            _schnorr_public_key,
+            "publicKeyPointer",
+            <SchnorrPk as JsonEncoding>::to_json,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeFromJson(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<SchnorrPk>(
+            _env,
+            _encoded,
+            <SchnorrPk as JsonEncoding>::from_json,
             "io/horizen/common/schnorrnative/SchnorrPublicKey",
             "io/horizen/common/librustsidechains/DeserializationException",
         )
@@ -76,12 +232,47 @@ ffi_export!(
         _env: JNIEnv,
         _schnorr_secret_key_class: JClass,
         _secret_key_bytes: jbyteArray,
+        _strict: jboolean,
     ) -> jobject {
         deserialize_to_jobject::<SchnorrSk>(
             _env,
             _secret_key_bytes,
             None,
             None,
+            _strict,
+            "io/horizen/common/schnorrnative/SchnorrSecretKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSecretKey_nativeWriteToFile(
+        _env: JNIEnv,
+        _schnorr_secret_key: JObject,
+        _file_path: JString,
+    ) {
+        write_to_file_from_jobject::<SchnorrSk>(
+            _env,
+            _schnorr_secret_key,
+            "secretKeyPointer",
+            _file_path,
+            None,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSecretKey_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+    ) -> jobject {
+        read_from_file_to_jobject::<SchnorrSk>(
+            _env,
+            _file_path,
+            None,
+            None,
             "io/horizen/common/schnorrnative/SchnorrSecretKey",
             "io/horizen/common/librustsidechains/DeserializationException",
         )
@@ -97,6 +288,16 @@ ffi_export!(
     }
 );
 
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSecretKey_nativeEquals(
+        _env: JNIEnv,
+        _secret_key_1: JObject,
+        _secret_key_2: JObject,
+    ) -> jboolean {
+        ct_eq_from_jobjects::<SchnorrSk>(_env, _secret_key_1, _secret_key_2, "secretKeyPointer")
+    }
+);
+
 //Schnorr signature utility functions
 ffi_export!(
     fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeGetSignatureSize(
@@ -122,12 +323,108 @@ ffi_export!(
         _class: JClass,
         _sig_bytes: jbyteArray,
         _check_sig: jboolean,
+        _strict: jboolean,
     ) -> jobject {
         deserialize_to_jobject::<SchnorrSig>(
             _env,
             _sig_bytes,
             Some(_check_sig),
             None,
+            _strict,
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeWriteToFile(
+        _env: JNIEnv,
+        _schnorr_signature: JObject,
+        _file_path: JString,
+    ) {
+        write_to_file_from_jobject::<SchnorrSig>(
+            _env,
+            _schnorr_signature,
+            "signaturePointer",
+            _file_path,
+            None,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+        _checked: jboolean,
+    ) -> jobject {
+        read_from_file_to_jobject::<SchnorrSig>(
+            _env,
+            _file_path,
+            Some(_checked),
+            None,
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeToHex(
+        _env: JNIEnv,
+        _schnorr_signature: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<SchnorrSig>(
+            _env,
+            _schnorr_signature,
+            "signaturePointer",
+            to_hex::<SchnorrSig>,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeFromHex(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<SchnorrSig>(
+            _env,
+            _encoded,
+            from_hex::<SchnorrSig>,
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeToJson(
+        _env: JNIEnv,
+        _schnorr_signature: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<SchnorrSig>(
+            _env,
+            _schnorr_signature,
+            "signaturePointer",
+            <SchnorrSig as JsonEncoding>::to_json,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeFromJson(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<SchnorrSig>(
+            _env,
+            _encoded,
+            <SchnorrSig as JsonEncoding>::from_json,
             "io/horizen/common/schnorrnative/SchnorrSignature",
             "io/horizen/common/librustsidechains/DeserializationException",
         )
@@ -158,6 +455,16 @@ ffi_export!(
     }
 );
 
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeEquals(
+        _env: JNIEnv,
+        _sig_1: JObject,
+        _sig_2: JObject,
+    ) -> jboolean {
+        ct_eq_from_jobjects::<SchnorrSig>(_env, _sig_1, _sig_2, "signaturePointer")
+    }
+);
+
 //Schnorr signature functions
 ffi_export!(
     fn Java_io_horizen_common_schnorrnative_SchnorrKeyPair_nativeGenerate(
@@ -170,28 +477,7 @@ ffi_export!(
     ) -> jobject {
         let (pk, sk) = schnorr_generate_key();
 
-        let secret_key_object = return_jobject(
-            &_env,
-            sk,
-            "io/horizen/common/schnorrnative/SchnorrSecretKey",
-        );
-        let public_key_object = return_jobject(
-            &_env,
-            pk,
-            "io/horizen/common/schnorrnative/SchnorrPublicKey",
-        );
-
-        let class = _env
-            .find_class("io/horizen/common/schnorrnative/SchnorrKeyPair")
-            .expect("Should be able to find SchnorrKeyPair class");
-
-        let result = _env.new_object(
-        class,
-        "(Lio/horizen/common/schnorrnative/SchnorrSecretKey;Lio/horizen/common/schnorrnative/SchnorrPublicKey;)V",
-        &[JValue::Object(secret_key_object), JValue::Object(public_key_object)]
-    ).expect("Should be able to create new (SchnorrSecretKey, SchnorrPublicKey) object");
-
-        *result
+        return_schnorr_key_pair(&_env, pk, sk)
     }
 );
 
@@ -201,33 +487,7 @@ ffi_export!(
         _schnorr_key_pair: JObject,
         _message: JObject,
     ) -> jobject {
-        //Read sk
-        let sk_object = _env
-            .get_field(
-                _schnorr_key_pair,
-                "secretKey",
-                "Lio/horizen/common/schnorrnative/SchnorrSecretKey;",
-            )
-            .expect("Should be able to get field secretKey")
-            .l()
-            .unwrap();
-
-        let secret_key =
-            parse_rust_struct_from_jobject::<SchnorrSk>(&_env, sk_object, "secretKeyPointer");
-
-        //Read pk
-        let pk_object = _env
-            .get_field(
-                _schnorr_key_pair,
-                "publicKey",
-                "Lio/horizen/common/schnorrnative/SchnorrPublicKey;",
-            )
-            .expect("Should be able to get field publicKey")
-            .l()
-            .unwrap();
-
-        let public_key =
-            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, pk_object, "publicKeyPointer");
+        let (secret_key, public_key) = parse_schnorr_key_pair(&_env, _schnorr_key_pair);
 
         //Read message
         let message =
@@ -307,3 +567,882 @@ ffi_export!(
         )
     }
 );
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrKeyPair_nativeSignFieldElements(
+        _env: JNIEnv,
+        _schnorr_key_pair: JObject,
+        _message: jobjectArray,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_schnorr_key_pair(&_env, _schnorr_key_pair);
+
+        //Read message
+        let message = parse_field_elements_from_jobject_array(&_env, _message, "message");
+
+        //Sign message and return opaque pointer to sig
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_sign_field_elements(message.as_slice(), secret_key, public_key),
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to sign message",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrKeyPair_nativeSignBytes(
+        _env: JNIEnv,
+        _schnorr_key_pair: JObject,
+        _message: jbyteArray,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_schnorr_key_pair(&_env, _schnorr_key_pair);
+
+        //Read message
+        let message = _env
+            .convert_byte_array(_message)
+            .expect("Cannot read message bytes.");
+
+        //Sign message and return opaque pointer to sig
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_sign_bytes(message.as_slice(), secret_key, public_key),
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to sign message",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeVerifyFieldElementsSignature(
+        _env: JNIEnv,
+        _public_key: JObject,
+        _signature: JObject,
+        _message: jobjectArray,
+    ) -> jboolean {
+        // Read pk
+        let public_key =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _public_key, "publicKeyPointer");
+
+        //Read message
+        let message = parse_field_elements_from_jobject_array(&_env, _message, "message");
+
+        //Read sig
+        let signature =
+            parse_rust_struct_from_jobject::<SchnorrSig>(&_env, _signature, "signaturePointer");
+
+        //Verify sig
+        map_to_jboolean_or_throw_exc(
+            _env,
+            schnorr_verify_field_elements(message.as_slice(), public_key, signature),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to verify signature",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeVerifyBytesSignature(
+        _env: JNIEnv,
+        _public_key: JObject,
+        _signature: JObject,
+        _message: jbyteArray,
+    ) -> jboolean {
+        // Read pk
+        let public_key =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _public_key, "publicKeyPointer");
+
+        //Read message
+        let message = _env
+            .convert_byte_array(_message)
+            .expect("Cannot read message bytes.");
+
+        //Read sig
+        let signature =
+            parse_rust_struct_from_jobject::<SchnorrSig>(&_env, _signature, "signaturePointer");
+
+        //Verify sig
+        map_to_jboolean_or_throw_exc(
+            _env,
+            schnorr_verify_bytes(message.as_slice(), public_key, signature),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to verify signature",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrKeyPair_nativeSignMessageWithDomainTag(
+        _env: JNIEnv,
+        _schnorr_key_pair: JObject,
+        _message: JObject,
+        _domain_tag: jobjectArray,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_schnorr_key_pair(&_env, _schnorr_key_pair);
+
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Read domain tag
+        let domain_tag = parse_field_elements_from_jobject_array(&_env, _domain_tag, "domainTag");
+
+        //Sign message and return opaque pointer to sig
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_sign_with_domain_tag(message, domain_tag.as_slice(), secret_key, public_key),
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to sign message",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeVerifySignatureWithDomainTag(
+        _env: JNIEnv,
+        _public_key: JObject,
+        _signature: JObject,
+        _message: JObject,
+        _domain_tag: jobjectArray,
+    ) -> jboolean {
+        // Read pk
+        let public_key =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _public_key, "publicKeyPointer");
+
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Read domain tag
+        let domain_tag = parse_field_elements_from_jobject_array(&_env, _domain_tag, "domainTag");
+
+        //Read sig
+        let signature =
+            parse_rust_struct_from_jobject::<SchnorrSig>(&_env, _signature, "signaturePointer");
+
+        //Verify sig
+        map_to_jboolean_or_throw_exc(
+            _env,
+            schnorr_verify_with_domain_tag(message, domain_tag.as_slice(), public_key, signature),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to verify signature",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrKeyPair_nativeSignMessageWithBytesDomainTag(
+        _env: JNIEnv,
+        _schnorr_key_pair: JObject,
+        _message: JObject,
+        _domain_tag: jbyteArray,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_schnorr_key_pair(&_env, _schnorr_key_pair);
+
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Read domain tag
+        let domain_tag = _env
+            .convert_byte_array(_domain_tag)
+            .expect("Cannot read domain tag bytes.");
+        let domain_tag = ok_or_throw_exc!(
+            &_env,
+            schnorr_domain_tag_from_bytes(domain_tag.as_slice()),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to read domain tag",
+            JNI_NULL
+        );
+
+        //Sign message and return opaque pointer to sig
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_sign_with_domain_tag(message, domain_tag.as_slice(), secret_key, public_key),
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to sign message",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeVerifySignatureWithBytesDomainTag(
+        _env: JNIEnv,
+        _public_key: JObject,
+        _signature: JObject,
+        _message: JObject,
+        _domain_tag: jbyteArray,
+    ) -> jboolean {
+        // Read pk
+        let public_key =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _public_key, "publicKeyPointer");
+
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Read domain tag
+        let domain_tag = _env
+            .convert_byte_array(_domain_tag)
+            .expect("Cannot read domain tag bytes.");
+        let domain_tag = ok_or_throw_exc!(
+            &_env,
+            schnorr_domain_tag_from_bytes(domain_tag.as_slice()),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to read domain tag",
+            JNI_FALSE
+        );
+
+        //Read sig
+        let signature =
+            parse_rust_struct_from_jobject::<SchnorrSig>(&_env, _signature, "signaturePointer");
+
+        //Verify sig
+        map_to_jboolean_or_throw_exc(
+            _env,
+            schnorr_verify_with_domain_tag(message, domain_tag.as_slice(), public_key, signature),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to verify signature",
+        )
+    }
+);
+
+//Schnorr adaptor signature functions
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPreSignature_nativeGetPreSignatureSize(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jint {
+        SCHNORR_PRE_SIG_SIZE as jint
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPreSignature_nativeSerializePreSignature(
+        _env: JNIEnv,
+        _pre_sig: JObject,
+    ) -> jbyteArray {
+        serialize_from_jobject::<SchnorrPreSig>(_env, _pre_sig, "preSignaturePointer", None)
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPreSignature_nativeDeserializePreSignature(
+        _env: JNIEnv,
+        _class: JClass,
+        _pre_sig_bytes: jbyteArray,
+        _check_pre_sig: jboolean,
+        _strict: jboolean,
+    ) -> jobject {
+        deserialize_to_jobject::<SchnorrPreSig>(
+            _env,
+            _pre_sig_bytes,
+            Some(_check_pre_sig),
+            None,
+            _strict,
+            "io/horizen/common/schnorrnative/SchnorrPreSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPreSignature_nativeIsValidPreSignature(
+        _env: JNIEnv,
+        _pre_sig: JObject,
+    ) -> jboolean {
+        let pre_sig =
+            parse_rust_struct_from_jobject::<SchnorrPreSig>(&_env, _pre_sig, "preSignaturePointer");
+
+        if is_valid(pre_sig) {
+            JNI_TRUE
+        } else {
+            JNI_FALSE
+        }
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPreSignature_nativeFreePreSignature(
+        _env: JNIEnv,
+        _pre_sig: JObject,
+    ) {
+        drop_rust_struct_from_jobject::<SchnorrPreSig>(_env, _pre_sig, "preSignaturePointer")
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPreSignature_nativeAdapt(
+        _env: JNIEnv,
+        _pre_sig: JObject,
+        _adaptor_secret: JObject,
+    ) -> jobject {
+        let pre_sig =
+            parse_rust_struct_from_jobject::<SchnorrPreSig>(&_env, _pre_sig, "preSignaturePointer");
+
+        let adaptor_secret =
+            parse_rust_struct_from_jobject::<SchnorrSk>(&_env, _adaptor_secret, "secretKeyPointer");
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_adaptor_adapt(pre_sig, adaptor_secret),
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to adapt pre-signature",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPreSignature_nativeExtractSecret(
+        _env: JNIEnv,
+        _pre_sig: JObject,
+        _signature: JObject,
+        _adaptor_point: JObject,
+    ) -> jobject {
+        let pre_sig =
+            parse_rust_struct_from_jobject::<SchnorrPreSig>(&_env, _pre_sig, "preSignaturePointer");
+
+        let signature =
+            parse_rust_struct_from_jobject::<SchnorrSig>(&_env, _signature, "signaturePointer");
+
+        let adaptor_point =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _adaptor_point, "publicKeyPointer");
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_adaptor_extract_secret(pre_sig, signature, adaptor_point),
+            "io/horizen/common/schnorrnative/SchnorrSecretKey",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to extract adaptor secret",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrKeyPair_nativeGenerateAdaptor(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jobject {
+        let (pk, sk) = schnorr_adaptor_generate_key();
+
+        return_schnorr_key_pair(&_env, pk, sk)
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrKeyPair_nativePreSignMessage(
+        _env: JNIEnv,
+        _schnorr_key_pair: JObject,
+        _message: JObject,
+        _adaptor_point: JObject,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_schnorr_key_pair(&_env, _schnorr_key_pair);
+
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Read adaptor point
+        let adaptor_point =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _adaptor_point, "publicKeyPointer");
+
+        //Pre-sign message and return opaque pointer to pre-sig
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_adaptor_pre_sign(message, secret_key, public_key, adaptor_point),
+            "io/horizen/common/schnorrnative/SchnorrPreSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to pre-sign message",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeVerifyPreSignature(
+        _env: JNIEnv,
+        _public_key: JObject,
+        _pre_signature: JObject,
+        _message: JObject,
+        _adaptor_point: JObject,
+    ) -> jboolean {
+        // Read pk
+        let public_key =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _public_key, "publicKeyPointer");
+
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Read adaptor point
+        let adaptor_point =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _adaptor_point, "publicKeyPointer");
+
+        //Read pre-sig
+        let pre_signature = parse_rust_struct_from_jobject::<SchnorrPreSig>(
+            &_env,
+            _pre_signature,
+            "preSignaturePointer",
+        );
+
+        //Verify pre-sig
+        map_to_jboolean_or_throw_exc(
+            _env,
+            schnorr_adaptor_pre_verify(message, public_key, adaptor_point, pre_signature),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to verify pre-signature",
+        )
+    }
+);
+
+//Schnorr compact signature functions
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeGetCompactSignatureSize(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jint {
+        SCHNORR_COMPACT_SIG_SIZE as jint
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeSerializeCompactSignature(
+        _env: JNIEnv,
+        _compact_sig: JObject,
+        _compressed: jboolean,
+    ) -> jbyteArray {
+        serialize_from_jobject::<SchnorrCompactSig>(
+            _env,
+            _compact_sig,
+            "compactSignaturePointer",
+            Some(_compressed),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeDeserializeCompactSignature(
+        _env: JNIEnv,
+        _class: JClass,
+        _compact_sig_bytes: jbyteArray,
+        _check_compact_sig: jboolean,
+        _compressed: jboolean,
+        _strict: jboolean,
+    ) -> jobject {
+        deserialize_to_jobject::<SchnorrCompactSig>(
+            _env,
+            _compact_sig_bytes,
+            Some(_check_compact_sig),
+            Some(_compressed),
+            _strict,
+            "io/horizen/common/schnorrnative/SchnorrCompactSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeWriteToFile(
+        _env: JNIEnv,
+        _schnorr_compact_signature: JObject,
+        _file_path: JString,
+        _compressed: jboolean,
+    ) {
+        write_to_file_from_jobject::<SchnorrCompactSig>(
+            _env,
+            _schnorr_compact_signature,
+            "compactSignaturePointer",
+            _file_path,
+            Some(_compressed),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+        _checked: jboolean,
+        _compressed: jboolean,
+    ) -> jobject {
+        read_from_file_to_jobject::<SchnorrCompactSig>(
+            _env,
+            _file_path,
+            Some(_checked),
+            Some(_compressed),
+            "io/horizen/common/schnorrnative/SchnorrCompactSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeIsValidCompactSignature(
+        _env: JNIEnv,
+        _compact_sig: JObject,
+    ) -> jboolean {
+        let compact_sig = parse_rust_struct_from_jobject::<SchnorrCompactSig>(
+            &_env,
+            _compact_sig,
+            "compactSignaturePointer",
+        );
+
+        if is_valid(compact_sig) {
+            JNI_TRUE
+        } else {
+            JNI_FALSE
+        }
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeFreeCompactSignature(
+        _env: JNIEnv,
+        _compact_sig: JObject,
+    ) {
+        drop_rust_struct_from_jobject::<SchnorrCompactSig>(
+            _env,
+            _compact_sig,
+            "compactSignaturePointer",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeFromSignature(
+        _env: JNIEnv,
+        _class: JClass,
+        _signature: JObject,
+        _public_key: JObject,
+    ) -> jobject {
+        let signature =
+            parse_rust_struct_from_jobject::<SchnorrSig>(&_env, _signature, "signaturePointer");
+
+        let public_key =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _public_key, "publicKeyPointer");
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_sig_to_compact(signature, public_key),
+            "io/horizen/common/schnorrnative/SchnorrCompactSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to convert signature to compact signature",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeToSignature(
+        _env: JNIEnv,
+        _compact_sig: JObject,
+        _message: JObject,
+        _public_key: JObject,
+    ) -> jobject {
+        let compact_sig = parse_rust_struct_from_jobject::<SchnorrCompactSig>(
+            &_env,
+            _compact_sig,
+            "compactSignaturePointer",
+        );
+
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        let public_key =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _public_key, "publicKeyPointer");
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_compact_to_sig(compact_sig, message, public_key),
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to convert compact signature to signature",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeBatchVerify(
+        _env: JNIEnv,
+        _class: JClass,
+        _messages: jobjectArray,
+        _public_keys: jobjectArray,
+        _compact_signatures: jobjectArray,
+    ) -> jboolean {
+        let messages = parse_field_elements_from_jobject_array(&_env, _messages, "messages");
+
+        let mut public_keys: Vec<&SchnorrPk> = vec![];
+        parse_rust_struct_vec_from_jobject_array!(
+            _env,
+            _public_keys,
+            public_keys,
+            "publicKeys",
+            "publicKeyPointer"
+        );
+
+        let mut compact_signatures: Vec<&SchnorrCompactSig> = vec![];
+        parse_rust_struct_vec_from_jobject_array!(
+            _env,
+            _compact_signatures,
+            compact_signatures,
+            "compactSignatures",
+            "compactSignaturePointer"
+        );
+
+        if messages.len() != public_keys.len() || messages.len() != compact_signatures.len() {
+            throw!(
+                &_env,
+                "io/horizen/common/schnorrnative/SchnorrSignatureException",
+                "Messages, public keys and compact signatures must have the same length",
+                JNI_FALSE
+            )
+        }
+
+        let items = messages
+            .into_iter()
+            .zip(public_keys.into_iter().zip(compact_signatures.into_iter()))
+            .map(|(msg, (pk, sig))| (msg, *pk, sig.clone()))
+            .collect::<Vec<_>>();
+
+        map_to_jboolean_or_throw_exc(
+            _env,
+            schnorr_batch_verify_compact(items.as_slice()),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to batch verify compact signatures",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrKeyPair_nativeSignMessageCompact(
+        _env: JNIEnv,
+        _schnorr_key_pair: JObject,
+        _message: JObject,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_schnorr_key_pair(&_env, _schnorr_key_pair);
+
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Sign message and return opaque pointer to compact sig
+        map_to_jobject_or_throw_exc(
+            _env,
+            schnorr_sign_compact(message, secret_key, public_key),
+            "io/horizen/common/schnorrnative/SchnorrCompactSignature",
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to sign message",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeVerifyCompactSignature(
+        _env: JNIEnv,
+        _public_key: JObject,
+        _compact_signature: JObject,
+        _message: JObject,
+    ) -> jboolean {
+        // Read pk
+        let public_key =
+            parse_rust_struct_from_jobject::<SchnorrPk>(&_env, _public_key, "publicKeyPointer");
+
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Read compact sig
+        let compact_signature = parse_rust_struct_from_jobject::<SchnorrCompactSig>(
+            &_env,
+            _compact_signature,
+            "compactSignaturePointer",
+        );
+
+        //Verify compact sig
+        map_to_jboolean_or_throw_exc(
+            _env,
+            schnorr_verify_compact(message, public_key, compact_signature),
+            "io/horizen/common/schnorrnative/SchnorrSignatureException",
+            "Unable to verify compact signature",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _compressed: jboolean,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<SchnorrPk>(
+            _env,
+            _obj,
+            "publicKeyPointer",
+            ObjectType::SchnorrPublicKey,
+            Some(_compressed),
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPublicKey_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<SchnorrPk>(
+            _env,
+            _obj_bytes,
+            ObjectType::SchnorrPublicKey,
+            Some(_checked),
+            "io/horizen/common/schnorrnative/SchnorrPublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSecretKey_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<SchnorrSk>(
+            _env,
+            _obj,
+            "secretKeyPointer",
+            ObjectType::SchnorrSecretKey,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSecretKey_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<SchnorrSk>(
+            _env,
+            _obj_bytes,
+            ObjectType::SchnorrSecretKey,
+            None,
+            "io/horizen/common/schnorrnative/SchnorrSecretKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<SchnorrSig>(
+            _env,
+            _obj,
+            "signaturePointer",
+            ObjectType::SchnorrSignature,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrSignature_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<SchnorrSig>(
+            _env,
+            _obj_bytes,
+            ObjectType::SchnorrSignature,
+            Some(_checked),
+            "io/horizen/common/schnorrnative/SchnorrSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPreSignature_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<SchnorrPreSig>(
+            _env,
+            _obj,
+            "preSignaturePointer",
+            ObjectType::SchnorrPreSignature,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrPreSignature_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<SchnorrPreSig>(
+            _env,
+            _obj_bytes,
+            ObjectType::SchnorrPreSignature,
+            Some(_checked),
+            "io/horizen/common/schnorrnative/SchnorrPreSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _compressed: jboolean,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<SchnorrCompactSig>(
+            _env,
+            _obj,
+            "compactSignaturePointer",
+            ObjectType::SchnorrCompactSignature,
+            Some(_compressed),
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_schnorrnative_SchnorrCompactSignature_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<SchnorrCompactSig>(
+            _env,
+            _obj_bytes,
+            ObjectType::SchnorrCompactSignature,
+            Some(_checked),
+            "io/horizen/common/schnorrnative/SchnorrCompactSignature",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
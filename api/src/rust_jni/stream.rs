@@ -0,0 +1,157 @@
+use super::*;
+use jni::{errors::Error as JniError, sys::jbyte};
+use std::io::{Error as IoError, ErrorKind, Read, Write};
+
+/// Size of the Java byte[] through which data is moved between Rust and a Java stream
+pub const STREAM_CHUNK_SIZE: usize = 1 << 16;
+
+fn jni_to_io_error(e: JniError) -> IoError {
+    IoError::new(ErrorKind::Other, format!("{:?}", e))
+}
+
+/// No JNI call, apart from a few exception-related ones, is allowed while a Java exception
+/// is pending, e.g. when a BufWriter flushes on drop after the stream has thrown.
+fn check_no_pending_exception(env: &JNIEnv) -> Result<(), IoError> {
+    if env.exception_check().map_err(jni_to_io_error)? {
+        return Err(IoError::new(
+            ErrorKind::Other,
+            "A Java exception has been thrown by the stream",
+        ));
+    }
+    Ok(())
+}
+
+/// Java byte[] of STREAM_CHUNK_SIZE bytes, released when dropped.
+struct JavaChunk<'a> {
+    env: &'a JNIEnv<'a>,
+    array: jbyteArray,
+}
+
+impl<'a> JavaChunk<'a> {
+    fn new(env: &'a JNIEnv<'a>) -> Result<Self, IoError> {
+        let array = env
+            .new_byte_array(STREAM_CHUNK_SIZE as jint)
+            .map_err(jni_to_io_error)?;
+        Ok(Self { env, array })
+    }
+}
+
+impl<'a> Drop for JavaChunk<'a> {
+    fn drop(&mut self) {
+        let _ = self.env.delete_local_ref(JObject::from(self.array));
+    }
+}
+
+/// `Write` adapter over a java.io.OutputStream: data is copied, at most STREAM_CHUNK_SIZE bytes
+/// at a time, into a Java byte[] and passed to `OutputStream.write(byte[], int, int)`.
+/// If the stream throws, the Java exception is left pending, to be thrown to the caller of the
+/// native method, and an IoError is returned.
+pub struct JavaOutputStream<'a> {
+    stream: JObject<'a>,
+    chunk: JavaChunk<'a>,
+}
+
+impl<'a> JavaOutputStream<'a> {
+    pub fn new(env: &'a JNIEnv<'a>, stream: JObject<'a>) -> Result<Self, IoError> {
+        Ok(Self {
+            stream,
+            chunk: JavaChunk::new(env)?,
+        })
+    }
+}
+
+impl<'a> Write for JavaOutputStream<'a> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+        let env = self.chunk.env;
+        check_no_pending_exception(env)?;
+
+        let len = buf.len().min(STREAM_CHUNK_SIZE);
+
+        // Safe: u8 and jbyte have the same size and alignment
+        let bytes = unsafe { std::slice::from_raw_parts(buf.as_ptr() as *const jbyte, len) };
+
+        env.set_byte_array_region(self.chunk.array, 0, bytes)
+            .map_err(jni_to_io_error)?;
+        env.call_method(
+            self.stream,
+            "write",
+            "([BII)V",
+            &[
+                JValue::Object(JObject::from(self.chunk.array)),
+                JValue::Int(0),
+                JValue::Int(len as jint),
+            ],
+        )
+        .map_err(jni_to_io_error)?;
+
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> Result<(), IoError> {
+        let env = self.chunk.env;
+        check_no_pending_exception(env)?;
+
+        env.call_method(self.stream, "flush", "()V", &[])
+            .map_err(jni_to_io_error)?;
+        Ok(())
+    }
+}
+
+/// `Read` adapter over a java.io.InputStream, calling `InputStream.read(byte[], int, int)`
+/// on a Java byte[] of at most STREAM_CHUNK_SIZE bytes.
+/// No read-ahead is performed, so that the bytes following the deserialized object are
+/// left in the stream: callers wanting buffering should wrap the stream Java-side, e.g. in
+/// a BufferedInputStream.
+/// If the stream throws, the Java exception is left pending, to be thrown to the caller of the
+/// native method, and an IoError is returned.
+pub struct JavaInputStream<'a> {
+    stream: JObject<'a>,
+    chunk: JavaChunk<'a>,
+}
+
+impl<'a> JavaInputStream<'a> {
+    pub fn new(env: &'a JNIEnv<'a>, stream: JObject<'a>) -> Result<Self, IoError> {
+        Ok(Self {
+            stream,
+            chunk: JavaChunk::new(env)?,
+        })
+    }
+}
+
+impl<'a> Read for JavaInputStream<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, IoError> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let env = self.chunk.env;
+        check_no_pending_exception(env)?;
+
+        let len = buf.len().min(STREAM_CHUNK_SIZE);
+        let read = env
+            .call_method(
+                self.stream,
+                "read",
+                "([BII)I",
+                &[
+                    JValue::Object(JObject::from(self.chunk.array)),
+                    JValue::Int(0),
+                    JValue::Int(len as jint),
+                ],
+            )
+            .and_then(|read| read.i())
+            .map_err(jni_to_io_error)?;
+
+        // -1 signals the end of the stream
+        if read <= 0 {
+            return Ok(0);
+        }
+        let read = (read as usize).min(len);
+
+        // Safe: u8 and jbyte have the same size and alignment
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut jbyte, read) };
+        env.get_byte_array_region(self.chunk.array, 0, bytes)
+            .map_err(jni_to_io_error)?;
+
+        Ok(read)
+    }
+}
@@ -1,9 +1,11 @@
+use super::stream::*;
 use super::*;
 use crate::ginger_calls::{
-    field_element::read_field_element_from_buffer_with_padding, serialization::*,
+    constant_time::ct_eq, field_element::read_field_element_from_buffer_with_padding,
+    serialization::*,
 };
 use algebra::{serialize::*, SemanticallyValid};
-use std::{any::type_name, fmt::Debug};
+use std::{any::type_name, fmt::Debug, io::BufWriter};
 
 pub fn read_raw_pointer<'a, T>(env: &JNIEnv, input: *const T) -> &'a T {
     if input.is_null() {
@@ -174,6 +176,27 @@ pub fn return_jobject<'a, T: Sized>(_env: &'a JNIEnv, obj: T, class_path: &str)
         .expect("Should be able to create new jobject")
 }
 
+pub fn return_jobject_array<'a, T: Sized>(
+    _env: &'a JNIEnv,
+    objs: Vec<T>,
+    class_path: &str,
+) -> jobjectArray {
+    let obj_class = _env
+        .find_class(class_path)
+        .expect("Should be able to find class");
+
+    let array = _env
+        .new_object_array(objs.len() as i32, obj_class, JObject::null())
+        .expect("Should be able to create new jobject array");
+
+    for (i, obj) in objs.into_iter().enumerate() {
+        _env.set_object_array_element(array, i as i32, return_jobject(_env, obj, class_path))
+            .expect("Should be able to set jobject array element");
+    }
+
+    array
+}
+
 /// Map a Result<T, E> to a jobject if Ok(), otherwise throw exception and return default JNI_NULL.
 /// To be used mainly as final instruction of a Rust implementation of a JNI function returning a
 /// jobject.
@@ -197,6 +220,31 @@ pub fn map_to_jobject_or_throw_exc<T: Sized, E: Debug>(
     )
 }
 
+/// Map a Result<T, SerializationError> to a jobject if Ok(), otherwise throw an `exception_path`
+/// carrying the code returned by `deserialization_error_code` and return JNI_NULL.
+/// `exception_path` must have a (String message, int errorCode) constructor, as
+/// DeserializationException has.
+pub fn map_to_jobject_or_throw_deserialization_exc<T: Sized>(
+    env: JNIEnv,
+    res: Result<T, SerializationError>,
+    class_path: &str,
+    exception_path: &str,
+    description: &str,
+) -> jobject {
+    match res {
+        Ok(ret) => return_jobject(&env, ret, class_path).into_inner(),
+        Err(e) => {
+            _throw_with_code_inner(
+                &env,
+                exception_path,
+                format!("{:?}: {:?}", description, e).as_str(),
+                deserialization_error_code(&e) as jint,
+            );
+            JNI_NULL
+        }
+    }
+}
+
 /// Map a type Result<bool, E> to a jboolean set to JNI_TRUE if Ok(true), JNI_FALSE if Ok(false)
 /// otherwise throw exception and return default JNI_FALSE.
 /// To be used mainly as final instruction of a Rust implementation of a JNI function returning a
@@ -251,11 +299,15 @@ pub fn map_to_jbytearray_or_throw_exc<T: CanonicalSerialize + SemanticallyValid,
     )
 }
 
-pub fn deserialize_to_jobject<T: CanonicalDeserialize + SemanticallyValid>(
+/// Deserialize a `T` from `obj_bytes` and return it as a `class_path` jobject.
+/// If `strict` is JNI_TRUE, `obj_bytes` must contain exactly the serialized `T`, otherwise
+/// any trailing byte is ignored.
+pub fn deserialize_to_jobject<T: DeserializationChecks>(
     _env: JNIEnv,
     obj_bytes: jbyteArray,
     checked: Option<jboolean>, // Can be none for types with trivial checks or without themn
     compressed: Option<jboolean>, // Can be none for uncompressable types
+    strict: jboolean,
     class_path: &str,
     exception_path: &str,
 ) -> jobject {
@@ -263,13 +315,16 @@ pub fn deserialize_to_jobject<T: CanonicalDeserialize + SemanticallyValid>(
         .convert_byte_array(obj_bytes)
         .expect("Cannot read bytes.");
 
-    map_to_jobject_or_throw_exc(
+    let checked = checked.map(|jni_bool| jni_bool == JNI_TRUE);
+    let compressed = compressed.map(|jni_bool| jni_bool == JNI_TRUE);
+
+    map_to_jobject_or_throw_deserialization_exc(
         _env,
-        deserialize_from_buffer::<T>(
-            obj_bytes.as_slice(),
-            checked.map(|jni_bool| jni_bool == JNI_TRUE),
-            compressed.map(|jni_bool| jni_bool == JNI_TRUE),
-        ),
+        if strict == JNI_TRUE {
+            deserialize_from_buffer_strict::<T>(obj_bytes.as_slice(), checked, compressed)
+        } else {
+            deserialize_from_buffer::<T>(obj_bytes.as_slice(), checked, compressed)
+        },
         class_path,
         exception_path,
         format!("Unable to deserialize {:?}", class_path).as_str(),
@@ -291,6 +346,221 @@ pub fn serialize_from_jobject<T: CanonicalSerialize>(
         .expect("Cannot write object.")
 }
 
+/// Serialize the Rust struct pointed by the `ptr_name` field of `obj` directly into the
+/// java.io.OutputStream `stream`, without materializing it into a byte array.
+/// On failure, throw an `exception_path`, unless the stream itself has already thrown.
+pub fn serialize_to_stream_from_jobject<T: CanonicalSerialize>(
+    _env: JNIEnv,
+    obj: JObject,
+    ptr_name: &str,
+    compressed: Option<jboolean>, // Can be none for uncompressable types
+    stream: JObject,
+    exception_path: &str,
+) {
+    let to_write = read_raw_pointer(
+        &_env,
+        parse_long_from_jobject(&_env, obj, ptr_name) as *const T,
+    );
+
+    let res = JavaOutputStream::new(&_env, stream)
+        .map_err(SerializationError::IoError)
+        .and_then(|stream| {
+            write_to_writer(
+                to_write,
+                BufWriter::with_capacity(STREAM_CHUNK_SIZE, stream),
+                compressed.map(|jni_bool| jni_bool == JNI_TRUE),
+            )
+        });
+
+    if let Err(e) = res {
+        _throw_inner(
+            &_env,
+            exception_path,
+            format!("Unable to write {} to stream: {:?}", type_name::<T>(), e).as_str(),
+        );
+    }
+}
+
+pub fn parse_path_from_jstring(_env: &JNIEnv, path: JString) -> String {
+    _env.get_string(path)
+        .expect("Should be able to read file path")
+        .into()
+}
+
+/// Atomically write to the file at `file_path` the Rust struct pointed by the `ptr_name` field
+/// of `obj`, throwing an IOException on failure.
+pub fn write_to_file_from_jobject<T: CanonicalSerialize>(
+    _env: JNIEnv,
+    obj: JObject,
+    ptr_name: &str,
+    file_path: JString,
+    compressed: Option<jboolean>, // Can be none for uncompressable types
+) {
+    let to_write = read_raw_pointer(
+        &_env,
+        parse_long_from_jobject(&_env, obj, ptr_name) as *const T,
+    );
+    let file_path = parse_path_from_jstring(&_env, file_path);
+
+    if let Err(e) = write_to_file(
+        to_write,
+        file_path.as_str(),
+        compressed.map(|jni_bool| jni_bool == JNI_TRUE),
+    ) {
+        _throw_inner(
+            &_env,
+            "java/io/IOException",
+            format!(
+                "Unable to write {} to {}: {:?}",
+                type_name::<T>(),
+                file_path,
+                e
+            )
+            .as_str(),
+        );
+    }
+}
+
+/// Read a `T` from the file at `file_path` and return it as a `class_path` jobject.
+/// The file must contain exactly the serialized `T`.
+/// I/O failures are reported as deserialization failures, with the Io error code.
+pub fn read_from_file_to_jobject<T: DeserializationChecks>(
+    _env: JNIEnv,
+    file_path: JString,
+    checked: Option<jboolean>, // Can be none for types with trivial checks or without them
+    compressed: Option<jboolean>, // Can be none for uncompressable types
+    class_path: &str,
+    exception_path: &str,
+) -> jobject {
+    let file_path = parse_path_from_jstring(&_env, file_path);
+
+    map_to_jobject_or_throw_deserialization_exc(
+        _env,
+        read_from_file_strict::<T>(
+            file_path.as_str(),
+            checked.map(|jni_bool| jni_bool == JNI_TRUE),
+            compressed.map(|jni_bool| jni_bool == JNI_TRUE),
+        ),
+        class_path,
+        exception_path,
+        format!("Unable to read {:?} from {}", class_path, file_path).as_str(),
+    )
+}
+
+/// Encode, through `encode`, the Rust struct pointed by the `ptr_name` field of `obj` into a
+/// Java String.
+pub fn encode_to_jstring_from_jobject<T>(
+    _env: JNIEnv,
+    obj: JObject,
+    ptr_name: &str,
+    encode: fn(&T) -> Result<String, SerializationError>,
+) -> jstring {
+    let to_encode = read_raw_pointer(
+        &_env,
+        parse_long_from_jobject(&_env, obj, ptr_name) as *const T,
+    );
+
+    let encoded =
+        encode(to_encode).unwrap_or_else(|_| panic!("unable to encode {}", type_name::<T>()));
+
+    _env.new_string(encoded)
+        .expect("Cannot write string.")
+        .into_inner()
+}
+
+/// Decode, through `decode`, the Java String `encoded` and return the result as a `class_path`
+/// jobject.
+pub fn decode_from_jstring_to_jobject<T>(
+    _env: JNIEnv,
+    encoded: JString,
+    decode: fn(&str) -> Result<T, SerializationError>,
+    class_path: &str,
+    exception_path: &str,
+) -> jobject {
+    let encoded: String = _env
+        .get_string(encoded)
+        .expect("Should be able to read string")
+        .into();
+
+    map_to_jobject_or_throw_deserialization_exc(
+        _env,
+        decode(encoded.as_str()),
+        class_path,
+        exception_path,
+        format!("Unable to decode {:?}", class_path).as_str(),
+    )
+}
+
+pub fn serialize_enveloped_from_jobject<T: CanonicalSerialize>(
+    _env: JNIEnv,
+    obj: JObject,
+    ptr_name: &str,
+    object_type: ObjectType,
+    compressed: Option<jboolean>, // Can be none for uncompressable types
+    with_checksum: jboolean,
+) -> jbyteArray {
+    let obj_bytes = serialize_enveloped(
+        read_raw_pointer(
+            &_env,
+            parse_long_from_jobject(&_env, obj, ptr_name) as *const T,
+        ),
+        object_type,
+        compressed.map(|jni_bool| jni_bool == JNI_TRUE),
+        with_checksum == JNI_TRUE,
+    )
+    .unwrap_or_else(|_| panic!("unable to write {} to buffer", type_name::<T>()));
+
+    _env.byte_array_from_slice(obj_bytes.as_slice())
+        .expect("Cannot write object.")
+}
+
+pub fn deserialize_enveloped_to_jobject<T: DeserializationChecks>(
+    _env: JNIEnv,
+    obj_bytes: jbyteArray,
+    object_type: ObjectType,
+    checked: Option<jboolean>, // Can be none for types with trivial checks or without them
+    class_path: &str,
+    exception_path: &str,
+) -> jobject {
+    let obj_bytes = _env
+        .convert_byte_array(obj_bytes)
+        .expect("Cannot read bytes.");
+
+    map_to_jobject_or_throw_deserialization_exc(
+        _env,
+        deserialize_enveloped::<T>(
+            obj_bytes.as_slice(),
+            object_type,
+            checked.map(|jni_bool| jni_bool == JNI_TRUE),
+        ),
+        class_path,
+        exception_path,
+        format!("Unable to deserialize {:?}", class_path).as_str(),
+    )
+}
+
+/// Compare, in constant time, the Rust structs pointed by the `ptr_name` field of `obj_1`
+/// and `obj_2`. To be used for secret data, for which a non constant-time equality check
+/// might leak information.
+pub fn ct_eq_from_jobjects<T: CanonicalSerialize>(
+    _env: JNIEnv,
+    obj_1: JObject,
+    obj_2: JObject,
+    ptr_name: &str,
+) -> jboolean {
+    let t_1 = parse_rust_struct_from_jobject::<T>(&_env, obj_1, ptr_name);
+    let t_2 = parse_rust_struct_from_jobject::<T>(&_env, obj_2, ptr_name);
+
+    let equal = ct_eq(t_1, t_2)
+        .unwrap_or_else(|_| panic!("unable to compare {} instances", type_name::<T>()));
+
+    if equal {
+        JNI_TRUE
+    } else {
+        JNI_FALSE
+    }
+}
+
 pub fn parse_field_element_from_jbyte_array(
     _env: &JNIEnv,
     bytes: jbyteArray,
@@ -300,6 +570,18 @@ pub fn parse_field_element_from_jbyte_array(
     Ok(fe)
 }
 
+pub fn parse_field_elements_from_jobject_array(
+    _env: &JNIEnv,
+    _array: jobjectArray,
+    array_name: &str,
+) -> Vec<FieldElement> {
+    let mut fes = vec![];
+
+    parse_rust_struct_vec_from_jobject_array!(_env, _array, fes, array_name, "fieldElementPointer");
+
+    fes.into_iter().copied().collect()
+}
+
 pub fn return_field_element(_env: JNIEnv, fe: FieldElement) -> jobject {
     return_jobject(
         &_env,
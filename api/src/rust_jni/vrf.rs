@@ -1,5 +1,19 @@
 use super::*;
-use crate::ginger_calls::{serialization::is_valid, vrf::*};
+use crate::ginger_calls::{
+    serialization::{is_valid, ObjectType},
+    text_encoding::*,
+    vrf::*,
+};
+
+/// Return the path of the Java exception class corresponding to `e`
+fn vrf_exception_path(e: &VRFError) -> &'static str {
+    match e {
+        VRFError::InvalidPublicKey => "io/horizen/common/vrfnative/VRFInvalidPublicKeyException",
+        VRFError::InvalidGamma => "io/horizen/common/vrfnative/VRFInvalidGammaException",
+        VRFError::InvalidProof => "io/horizen/common/vrfnative/VRFInvalidProofException",
+        VRFError::Internal(_) => "io/horizen/common/vrfnative/VRFInternalException",
+    }
+}
 
 // VRF related functions
 ffi_export!(
@@ -33,12 +47,110 @@ ffi_export!(
         _public_key_bytes: jbyteArray,
         _check_public_key: jboolean,
         _compressed: jboolean,
+        _strict: jboolean,
     ) -> jobject {
         deserialize_to_jobject::<VRFPk>(
             _env,
             _public_key_bytes,
             Some(_check_public_key),
             Some(_compressed),
+            _strict,
+            "io/horizen/common/vrfnative/VRFPublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeWriteToFile(
+        _env: JNIEnv,
+        _vrf_public_key: JObject,
+        _file_path: JString,
+        _compressed: jboolean,
+    ) {
+        write_to_file_from_jobject::<VRFPk>(
+            _env,
+            _vrf_public_key,
+            "publicKeyPointer",
+            _file_path,
+            Some(_compressed),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+        _checked: jboolean,
+        _compressed: jboolean,
+    ) -> jobject {
+        read_from_file_to_jobject::<VRFPk>(
+            _env,
+            _file_path,
+            Some(_checked),
+            Some(_compressed),
+            "io/horizen/common/vrfnative/VRFPublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeToHex(
+        _env: JNIEnv,
+        _vrf_public_key: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<VRFPk>(
+            _env,
+            _vrf_public_key,
+            "publicKeyPointer",
+            to_hex::<VRFPk>,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeFromHex(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<VRFPk>(
+            _env,
+            _encoded,
+            from_hex::<VRFPk>,
+            "io/horizen/common/vrfnative/VRFPublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeToJson(
+        _env: JNIEnv,
+        _vrf_public_key: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<VRFPk>(
+            _env,
+            _vrf_public_key,
+            "publicKeyPointer",
+            <VRFPk as JsonEncoding>::to_json,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeFromJson(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<VRFPk>(
+            _env,
+            _encoded,
+            <VRFPk as JsonEncoding>::from_json,
             "io/horizen/common/vrfnative/VRFPublicKey",
             "io/horizen/common/librustsidechains/DeserializationException",
         )
@@ -70,6 +182,92 @@ ffi_export!(
     }
 );
 
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeBatchProofToHash(
+        _env: JNIEnv,
+        _class: JClass,
+        _public_keys: jobjectArray,
+        _proofs: jobjectArray,
+        _messages: jobjectArray,
+    ) -> jobject {
+        // Read data
+        let mut public_keys: Vec<&VRFPk> = vec![];
+        parse_rust_struct_vec_from_jobject_array!(
+            _env,
+            _public_keys,
+            public_keys,
+            "publicKeys",
+            "publicKeyPointer"
+        );
+
+        let mut proofs: Vec<&VRFProof> = vec![];
+        parse_rust_struct_vec_from_jobject_array!(_env, _proofs, proofs, "proofs", "proofPointer");
+
+        let messages = parse_field_elements_from_jobject_array(&_env, _messages, "messages");
+
+        if public_keys.len() != proofs.len() || public_keys.len() != messages.len() {
+            throw!(
+                &_env,
+                "io/horizen/common/vrfnative/VRFException",
+                "Public keys, proofs and messages must have the same length",
+                JNI_NULL
+            )
+        }
+
+        let items = messages
+            .into_iter()
+            .zip(public_keys.into_iter().zip(proofs.into_iter()))
+            .map(|(msg, (pk, proof))| (msg, *pk, proof.clone()))
+            .collect::<Vec<_>>();
+
+        //Verify vrf proofs and get either the vrf outputs or the failing indices
+        let (outputs, failing_indices) = match vrf_batch_proof_to_hash(items.as_slice()) {
+            Ok(Ok(outputs)) => (outputs, vec![]),
+            Ok(Err(failing_indices)) => (vec![], failing_indices),
+            Err(e) => throw!(
+                &_env,
+                vrf_exception_path(&e),
+                format!("Unable to verify vrf proofs: {:?}", e).as_str(),
+                JNI_NULL
+            ),
+        };
+
+        let outputs = return_jobject_array(
+            &_env,
+            outputs,
+            "io/horizen/common/librustsidechains/FieldElement",
+        );
+
+        let failing_indices = failing_indices
+            .into_iter()
+            .map(|i| i as jint)
+            .collect::<Vec<_>>();
+        let failing_indices_array = _env
+            .new_int_array(failing_indices.len() as jint)
+            .expect("Should be able to create new jintArray");
+        _env.set_int_array_region(failing_indices_array, 0, failing_indices.as_slice())
+            .expect("Should be able to fill jintArray");
+
+        //Create and return VRFBatchProofToHashResult instance
+        let class = _env
+            .find_class("io/horizen/common/vrfnative/VRFBatchProofToHashResult")
+            .expect("Should be able to find VRFBatchProofToHashResult class");
+
+        let result = _env
+            .new_object(
+                class,
+                "([Lio/horizen/common/librustsidechains/FieldElement;[I)V",
+                &[
+                    JValue::Object(JObject::from(outputs)),
+                    JValue::Object(JObject::from(failing_indices_array)),
+                ],
+            )
+            .expect("Should be able to create new VRFBatchProofToHashResult object");
+
+        *result
+    }
+);
+
 ffi_export!(
     fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeProofToHash(
         _env: JNIEnv,
@@ -85,11 +283,146 @@ ffi_export!(
         let proof = parse_rust_struct_from_jobject::<VRFProof>(&_env, _proof, "proofPointer");
 
         //Verify vrf proof and get vrf output
+        let res = vrf_proof_to_hash(message, public_key, proof);
+        let exception_path = res.as_ref().err().map_or(
+            "io/horizen/common/vrfnative/VRFException",
+            vrf_exception_path,
+        );
+
         map_to_jobject_or_throw_exc(
             _env,
-            vrf_proof_to_hash(message, public_key, proof),
+            res,
             "io/horizen/common/librustsidechains/FieldElement",
+            exception_path,
+            "Unable to get vrf output",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeProofToHashWithLabel(
+        _env: JNIEnv,
+        _vrf_public_key: JObject,
+        _proof: JObject,
+        _message: JObject,
+        _label: jobjectArray,
+    ) -> jobject {
+        // Read data
+        let public_key =
+            parse_rust_struct_from_jobject::<VRFPk>(&_env, _vrf_public_key, "publicKeyPointer");
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+        let proof = parse_rust_struct_from_jobject::<VRFProof>(&_env, _proof, "proofPointer");
+        let label = parse_field_elements_from_jobject_array(&_env, _label, "label");
+
+        //Verify vrf proof and get labeled vrf output
+        let res = vrf_proof_to_hash_with_labels(message, public_key, proof, &[label.as_slice()])
+            .map(|mut outputs| outputs.remove(0));
+        let exception_path = res.as_ref().err().map_or(
             "io/horizen/common/vrfnative/VRFException",
+            vrf_exception_path,
+        );
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            res,
+            "io/horizen/common/librustsidechains/FieldElement",
+            exception_path,
+            "Unable to get vrf output",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeProofToHashWithParams(
+        _env: JNIEnv,
+        _vrf_public_key: JObject,
+        _proof: JObject,
+        _message: JObject,
+        _params: JObject,
+    ) -> jobject {
+        // Read data
+        let public_key =
+            parse_rust_struct_from_jobject::<VRFPk>(&_env, _vrf_public_key, "publicKeyPointer");
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+        let proof = parse_rust_struct_from_jobject::<VRFProof>(&_env, _proof, "proofPointer");
+        let params = parse_rust_struct_from_jobject::<VRFParams>(&_env, _params, "paramsPointer");
+
+        //Verify vrf proof and get vrf output
+        let res = vrf_proof_to_hash_with_params(message, public_key, proof, params);
+        let exception_path = res.as_ref().err().map_or(
+            "io/horizen/common/vrfnative/VRFException",
+            vrf_exception_path,
+        );
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            res,
+            "io/horizen/common/librustsidechains/FieldElement",
+            exception_path,
+            "Unable to get vrf output",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeProofToHashFieldElements(
+        _env: JNIEnv,
+        _vrf_public_key: JObject,
+        _proof: JObject,
+        _message: jobjectArray,
+    ) -> jobject {
+        // Read data
+        let public_key =
+            parse_rust_struct_from_jobject::<VRFPk>(&_env, _vrf_public_key, "publicKeyPointer");
+        let message = parse_field_elements_from_jobject_array(&_env, _message, "message");
+        let proof = parse_rust_struct_from_jobject::<VRFProof>(&_env, _proof, "proofPointer");
+
+        //Verify vrf proof and get vrf output
+        let res = vrf_proof_to_hash_field_elements(message.as_slice(), public_key, proof);
+        let exception_path = res.as_ref().err().map_or(
+            "io/horizen/common/vrfnative/VRFException",
+            vrf_exception_path,
+        );
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            res,
+            "io/horizen/common/librustsidechains/FieldElement",
+            exception_path,
+            "Unable to get vrf output",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeProofToHashBytes(
+        _env: JNIEnv,
+        _vrf_public_key: JObject,
+        _proof: JObject,
+        _message: jbyteArray,
+    ) -> jobject {
+        // Read data
+        let public_key =
+            parse_rust_struct_from_jobject::<VRFPk>(&_env, _vrf_public_key, "publicKeyPointer");
+        let message = _env
+            .convert_byte_array(_message)
+            .expect("Cannot read message bytes.");
+        let proof = parse_rust_struct_from_jobject::<VRFProof>(&_env, _proof, "proofPointer");
+
+        //Verify vrf proof and get vrf output
+        let res = vrf_proof_to_hash_bytes(message.as_slice(), public_key, proof);
+        let exception_path = res.as_ref().err().map_or(
+            "io/horizen/common/vrfnative/VRFException",
+            vrf_exception_path,
+        );
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            res,
+            "io/horizen/common/librustsidechains/FieldElement",
+            exception_path,
             "Unable to get vrf output",
         )
     }
@@ -119,12 +452,47 @@ ffi_export!(
         _env: JNIEnv,
         _vrf_secret_key_class: JClass,
         _secret_key_bytes: jbyteArray,
+        _strict: jboolean,
     ) -> jobject {
         deserialize_to_jobject::<VRFSk>(
             _env,
             _secret_key_bytes,
             None,
             None,
+            _strict,
+            "io/horizen/common/vrfnative/VRFSecretKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFSecretKey_nativeWriteToFile(
+        _env: JNIEnv,
+        _vrf_secret_key: JObject,
+        _file_path: JString,
+    ) {
+        write_to_file_from_jobject::<VRFSk>(
+            _env,
+            _vrf_secret_key,
+            "secretKeyPointer",
+            _file_path,
+            None,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFSecretKey_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+    ) -> jobject {
+        read_from_file_to_jobject::<VRFSk>(
+            _env,
+            _file_path,
+            None,
+            None,
             "io/horizen/common/vrfnative/VRFSecretKey",
             "io/horizen/common/librustsidechains/DeserializationException",
         )
@@ -140,6 +508,16 @@ ffi_export!(
     }
 );
 
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFSecretKey_nativeEquals(
+        _env: JNIEnv,
+        _secret_key_1: JObject,
+        _secret_key_2: JObject,
+    ) -> jboolean {
+        ct_eq_from_jobjects::<VRFSk>(_env, _secret_key_1, _secret_key_2, "secretKeyPointer")
+    }
+);
+
 ffi_export!(
     fn Java_io_horizen_common_vrfnative_VRFSecretKey_nativeGetPublicKey(
         _env: JNIEnv,
@@ -178,12 +556,110 @@ ffi_export!(
         _proof_bytes: jbyteArray,
         _check_proof: jboolean,
         _compressed: jboolean,
+        _strict: jboolean,
     ) -> jobject {
         deserialize_to_jobject::<VRFProof>(
             _env,
             _proof_bytes,
             Some(_check_proof),
             Some(_compressed),
+            _strict,
+            "io/horizen/common/vrfnative/VRFProof",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFProof_nativeWriteToFile(
+        _env: JNIEnv,
+        _vrf_proof: JObject,
+        _file_path: JString,
+        _compressed: jboolean,
+    ) {
+        write_to_file_from_jobject::<VRFProof>(
+            _env,
+            _vrf_proof,
+            "proofPointer",
+            _file_path,
+            Some(_compressed),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFProof_nativeReadFromFile(
+        _env: JNIEnv,
+        _class: JClass,
+        _file_path: JString,
+        _checked: jboolean,
+        _compressed: jboolean,
+    ) -> jobject {
+        read_from_file_to_jobject::<VRFProof>(
+            _env,
+            _file_path,
+            Some(_checked),
+            Some(_compressed),
+            "io/horizen/common/vrfnative/VRFProof",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFProof_nativeToHex(
+        _env: JNIEnv,
+        _vrf_proof: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<VRFProof>(
+            _env,
+            _vrf_proof,
+            "proofPointer",
+            to_hex::<VRFProof>,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFProof_nativeFromHex(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<VRFProof>(
+            _env,
+            _encoded,
+            from_hex::<VRFProof>,
+            "io/horizen/common/vrfnative/VRFProof",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFProof_nativeToJson(
+        _env: JNIEnv,
+        _vrf_proof: JObject,
+    ) -> jstring {
+        encode_to_jstring_from_jobject::<VRFProof>(
+            _env,
+            _vrf_proof,
+            "proofPointer",
+            <VRFProof as JsonEncoding>::to_json,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFProof_nativeFromJson(
+        _env: JNIEnv,
+        _class: JClass,
+        _encoded: JString,
+    ) -> jobject {
+        decode_from_jstring_to_jobject::<VRFProof>(
+            _env,
+            _encoded,
+            <VRFProof as JsonEncoding>::from_json,
             "io/horizen/common/vrfnative/VRFProof",
             "io/horizen/common/librustsidechains/DeserializationException",
         )
@@ -205,6 +681,36 @@ ffi_export!(
     }
 );
 
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFProof_nativeOutputWithLabel(
+        _env: JNIEnv,
+        _vrf_proof: JObject,
+        _message: JObject,
+        _label: jobjectArray,
+    ) -> jobject {
+        // Read data
+        let proof = parse_rust_struct_from_jobject::<VRFProof>(&_env, _vrf_proof, "proofPointer");
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+        let label = parse_field_elements_from_jobject_array(&_env, _label, "label");
+
+        //Get labeled vrf output
+        let res = vrf_output_with_label(message, proof, label.as_slice());
+        let exception_path = res.as_ref().err().map_or(
+            "io/horizen/common/vrfnative/VRFException",
+            vrf_exception_path,
+        );
+
+        map_to_jobject_or_throw_exc(
+            _env,
+            res,
+            "io/horizen/common/librustsidechains/FieldElement",
+            exception_path,
+            "Unable to get labeled vrf output",
+        )
+    }
+);
+
 ffi_export!(
     fn Java_io_horizen_common_vrfnative_VRFProof_nativeFreeProof(_env: JNIEnv, _proof: JObject) {
         drop_rust_struct_from_jobject::<VRFProof>(_env, _proof, "proofPointer")
@@ -242,67 +748,351 @@ ffi_export!(
     }
 );
 
+/// Read secret and public key from a VRFKeyPair jobject
+pub(crate) fn parse_vrf_key_pair<'a>(
+    _env: &'a JNIEnv,
+    _vrf_key_pair: JObject<'a>,
+) -> (&'a VRFSk, &'a VRFPk) {
+    //Read sk
+    let sk_object = _env
+        .get_field(
+            _vrf_key_pair,
+            "secretKey",
+            "Lio/horizen/common/vrfnative/VRFSecretKey;",
+        )
+        .expect("Should be able to get field vrfKey")
+        .l()
+        .unwrap();
+
+    let secret_key = parse_rust_struct_from_jobject::<VRFSk>(_env, sk_object, "secretKeyPointer");
+
+    //Read pk
+    let pk_object = _env
+        .get_field(
+            _vrf_key_pair,
+            "publicKey",
+            "Lio/horizen/common/vrfnative/VRFPublicKey;",
+        )
+        .expect("Should be able to get field publicKey")
+        .l()
+        .unwrap();
+
+    let public_key = parse_rust_struct_from_jobject::<VRFPk>(_env, pk_object, "publicKeyPointer");
+
+    (secret_key, public_key)
+}
+
+/// Return the result of a VRF proving as a VRFProveResult jobject, or throw the exception
+/// corresponding to the error
+fn return_vrf_prove_result(
+    _env: &JNIEnv,
+    res: Result<(VRFProof, FieldElement), VRFError>,
+) -> jobject {
+    let exception_path = res.as_ref().err().map_or(
+        "io/horizen/common/vrfnative/VRFException",
+        vrf_exception_path,
+    );
+
+    let (proof, vrf_out) = ok_or_throw_exc!(
+        _env,
+        res,
+        exception_path,
+        "Unable to compute VRF proof",
+        JNI_NULL
+    );
+
+    //Create and return VRFProveResult instance
+    let class = _env
+        .find_class("io/horizen/common/vrfnative/VRFProveResult")
+        .expect("Should be able to find VRFProveResult class");
+
+    let result = _env.new_object(
+        class,
+        "(Lio/horizen/common/vrfnative/VRFProof;Lio/horizen/common/librustsidechains/FieldElement;)V",
+        &[
+            JValue::Object(return_jobject(_env, proof, "io/horizen/common/vrfnative/VRFProof")),
+            JValue::Object(return_jobject(_env, vrf_out, "io/horizen/common/librustsidechains/FieldElement"))
+        ]
+    ).expect("Should be able to create new VRFProveResult:(VRFProof, FieldElement) object");
+
+    *result
+}
+
 ffi_export!(
     fn Java_io_horizen_common_vrfnative_VRFKeyPair_nativeProve(
         _env: JNIEnv,
         _vrf_key_pair: JObject,
         _message: JObject,
     ) -> jobject {
-        //Read sk
-        let sk_object = _env
-            .get_field(
-                _vrf_key_pair,
-                "secretKey",
-                "Lio/horizen/common/vrfnative/VRFSecretKey;",
-            )
-            .expect("Should be able to get field vrfKey")
-            .l()
-            .unwrap();
-
-        let secret_key =
-            parse_rust_struct_from_jobject::<VRFSk>(&_env, sk_object, "secretKeyPointer");
-
-        //Read pk
-        let pk_object = _env
-            .get_field(
-                _vrf_key_pair,
-                "publicKey",
-                "Lio/horizen/common/vrfnative/VRFPublicKey;",
-            )
-            .expect("Should be able to get field publicKey")
-            .l()
-            .unwrap();
+        let (secret_key, public_key) = parse_vrf_key_pair(&_env, _vrf_key_pair);
 
-        let public_key =
-            parse_rust_struct_from_jobject::<VRFPk>(&_env, pk_object, "publicKeyPointer");
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Compute vrf proof
+        return_vrf_prove_result(&_env, vrf_prove(message, secret_key, public_key))
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFKeyPair_nativeProveFieldElements(
+        _env: JNIEnv,
+        _vrf_key_pair: JObject,
+        _message: jobjectArray,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_vrf_key_pair(&_env, _vrf_key_pair);
+
+        //Read message
+        let message = parse_field_elements_from_jobject_array(&_env, _message, "message");
+
+        //Compute vrf proof
+        return_vrf_prove_result(
+            &_env,
+            vrf_prove_field_elements(message.as_slice(), secret_key, public_key),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFKeyPair_nativeProveBytes(
+        _env: JNIEnv,
+        _vrf_key_pair: JObject,
+        _message: jbyteArray,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_vrf_key_pair(&_env, _vrf_key_pair);
+
+        //Read message
+        let message = _env
+            .convert_byte_array(_message)
+            .expect("Cannot read message bytes.");
+
+        //Compute vrf proof
+        return_vrf_prove_result(
+            &_env,
+            vrf_prove_bytes(message.as_slice(), secret_key, public_key),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFKeyPair_nativeProveDeterministic(
+        _env: JNIEnv,
+        _vrf_key_pair: JObject,
+        _message: JObject,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_vrf_key_pair(&_env, _vrf_key_pair);
+
+        //Read message
+        let message =
+            parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
+
+        //Compute deterministic vrf proof
+        return_vrf_prove_result(
+            &_env,
+            vrf_prove_deterministic(message, secret_key, public_key),
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFKeyPair_nativeProveWithParams(
+        _env: JNIEnv,
+        _vrf_key_pair: JObject,
+        _message: JObject,
+        _params: JObject,
+    ) -> jobject {
+        let (secret_key, public_key) = parse_vrf_key_pair(&_env, _vrf_key_pair);
 
         //Read message
         let message =
             parse_rust_struct_from_jobject::<FieldElement>(&_env, _message, "fieldElementPointer");
 
+        //Read params
+        let params = parse_rust_struct_from_jobject::<VRFParams>(&_env, _params, "paramsPointer");
+
         //Compute vrf proof
-        let (proof, vrf_out) = ok_or_throw_exc!(
+        return_vrf_prove_result(
+            &_env,
+            vrf_prove_with_params(message, secret_key, public_key, params),
+        )
+    }
+);
+
+// VRF params functions
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFParams_nativeGetDefault(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jobject {
+        return_jobject(
             &_env,
-            vrf_prove(message, secret_key, public_key),
+            VRFParams::default(),
+            "io/horizen/common/vrfnative/VRFParams",
+        )
+        .into_inner()
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFParams_nativeGenerate(
+        _env: JNIEnv,
+        _class: JClass,
+        _tags: jobjectArray,
+        _personalization: jbyteArray,
+        _window_size: jint,
+    ) -> jobject {
+        //Read tags
+        let tags_len = _env
+            .get_array_length(_tags)
+            .expect("Should be able to get tags length");
+
+        let tags = (0..tags_len)
+            .map(|i| {
+                let tag = _env
+                    .get_object_array_element(_tags, i)
+                    .unwrap_or_else(|_| panic!("Should be able to read tag {}", i));
+                _env.convert_byte_array(tag.into_inner())
+                    .unwrap_or_else(|_| panic!("Should be able to convert tag {} to bytes", i))
+            })
+            .collect::<Vec<_>>();
+
+        //Read personalization
+        let personalization = ok_or_throw_exc!(
+            &_env,
+            parse_fixed_jbyte_array(&_env, _personalization, 8),
             "io/horizen/common/vrfnative/VRFException",
-            "Unable to compute VRF proof",
+            "Invalid personalization",
             JNI_NULL
         );
+        let mut personalization_bytes = [0u8; 8];
+        personalization_bytes.copy_from_slice(personalization.as_slice());
 
-        //Create and return VRFProveResult instance
-        let class = _env
-            .find_class("io/horizen/common/vrfnative/VRFProveResult")
-            .expect("Should be able to find VRFProveResult class");
+        map_to_jobject_or_throw_exc(
+            _env,
+            vrf_generate_params(
+                tags.iter()
+                    .map(|tag| tag.as_slice())
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+                &personalization_bytes,
+                _window_size as usize,
+            ),
+            "io/horizen/common/vrfnative/VRFParams",
+            "io/horizen/common/vrfnative/VRFException",
+            "Unable to generate VRF params",
+        )
+    }
+);
 
-        let result = _env.new_object(
-        class,
-        "(Lio/horizen/common/vrfnative/VRFProof;Lio/horizen/common/librustsidechains/FieldElement;)V",
-        &[
-            JValue::Object(return_jobject(&_env, proof, "io/horizen/common/vrfnative/VRFProof")),
-            JValue::Object(return_jobject(&_env, vrf_out, "io/horizen/common/librustsidechains/FieldElement"))
-        ]
-    ).expect("Should be able to create new VRFProveResult:(VRFProof, FieldElement) object");
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFParams_nativeFreeParams(_env: JNIEnv, _params: JObject) {
+        drop_rust_struct_from_jobject::<VRFParams>(_env, _params, "paramsPointer")
+    }
+);
 
-        *result
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _compressed: jboolean,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<VRFPk>(
+            _env,
+            _obj,
+            "publicKeyPointer",
+            ObjectType::VRFPublicKey,
+            Some(_compressed),
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFPublicKey_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<VRFPk>(
+            _env,
+            _obj_bytes,
+            ObjectType::VRFPublicKey,
+            Some(_checked),
+            "io/horizen/common/vrfnative/VRFPublicKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFSecretKey_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<VRFSk>(
+            _env,
+            _obj,
+            "secretKeyPointer",
+            ObjectType::VRFSecretKey,
+            None,
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFSecretKey_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<VRFSk>(
+            _env,
+            _obj_bytes,
+            ObjectType::VRFSecretKey,
+            None,
+            "io/horizen/common/vrfnative/VRFSecretKey",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFProof_nativeSerializeEnveloped(
+        _env: JNIEnv,
+        _obj: JObject,
+        _compressed: jboolean,
+        _with_checksum: jboolean,
+    ) -> jbyteArray {
+        serialize_enveloped_from_jobject::<VRFProof>(
+            _env,
+            _obj,
+            "proofPointer",
+            ObjectType::VRFProof,
+            Some(_compressed),
+            _with_checksum,
+        )
+    }
+);
+
+ffi_export!(
+    fn Java_io_horizen_common_vrfnative_VRFProof_nativeDeserializeEnveloped(
+        _env: JNIEnv,
+        _class: JClass,
+        _obj_bytes: jbyteArray,
+        _checked: jboolean,
+    ) -> jobject {
+        deserialize_enveloped_to_jobject::<VRFProof>(
+            _env,
+            _obj_bytes,
+            ObjectType::VRFProof,
+            Some(_checked),
+            "io/horizen/common/vrfnative/VRFProof",
+            "io/horizen/common/librustsidechains/DeserializationException",
+        )
     }
 );
@@ -24,6 +24,12 @@ use primitives::{
 
 pub type Error = Box<dyn std::error::Error>;
 
+/// Identifier of the BN-382 curves, written in the envelope of serialized objects
+pub const CURVE_ID: u8 = 2;
+
+/// Name of the BN-382 curves, used to tell apart per-curve resources
+pub const CURVE_NAME: &str = "bn_382";
+
 generate_all_algebraic_crypto_types!(
     Bn382DualAffine,
     Bn382DualProjective,
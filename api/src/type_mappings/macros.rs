@@ -85,7 +85,11 @@ macro_rules! generate_vrf_types {
     ($projective_curve: ident, $affine_curve: ident) => {
         // Group hash personalizations
         /// BLAKE2s Personalization for Group hash generators used for VRF.
-        const VRF_GROUP_HASH_GENERATORS_PERSONALIZATION: &'static [u8; 8] = b"ZenVrfPH";
+        pub const VRF_GROUP_HASH_GENERATORS_PERSONALIZATION: &'static [u8; 8] = b"ZenVrfPH";
+
+        /// Tags from which the Group hash generators used for VRF are derived, one for each window.
+        pub const VRF_GROUP_HASH_GENERATORS_TAGS: [&'static [u8]; 2] =
+            [b"Magnesium Mg 12", b"Gold Au 79"];
 
         #[derive(Clone)]
         pub struct VRFWindow {}
@@ -94,19 +98,30 @@ macro_rules! generate_vrf_types {
             const NUM_WINDOWS: usize = 2;
         }
 
+        /// Alternative window configuration of the VRF group hash, covering the same input
+        /// size with twice the windows (thus tags) of half the generators.
+        #[derive(Clone)]
+        pub struct VRFSmallWindow {}
+        impl PedersenWindow for VRFSmallWindow {
+            const WINDOW_SIZE: usize = 64;
+            const NUM_WINDOWS: usize = 4;
+        }
+
+        pub type VRFGroupHashParams = BoweHopwoodPedersenParameters<$projective_curve>;
+
         lazy_static! {
-            pub static ref VRF_GH_PARAMS: BoweHopwoodPedersenParameters<$projective_curve> =
-                get_vrf_params();
+            pub static ref VRF_GH_PARAMS: VRFGroupHashParams = get_vrf_params();
         }
 
-        fn compute_group_hash_table(
+        /// Expand each of `generators` into the `window_size` generators of its window.
+        pub fn compute_group_hash_table(
             generators: Vec<$projective_curve>,
+            window_size: usize,
         ) -> Vec<Vec<$projective_curve>> {
             let mut gen_table = Vec::new();
-            for i in 0..VRFWindow::NUM_WINDOWS {
+            for mut base in generators.into_iter() {
                 let mut generators_for_segment = Vec::new();
-                let mut base = generators[i];
-                for _ in 0..VRFWindow::WINDOW_SIZE {
+                for _ in 0..window_size {
                     generators_for_segment.push(base);
                     for _ in 0..4 {
                         base.double_in_place();
@@ -117,25 +132,23 @@ macro_rules! generate_vrf_types {
             gen_table
         }
 
-        fn get_vrf_params() -> BoweHopwoodPedersenParameters<$projective_curve> {
+        fn get_vrf_params() -> VRFGroupHashParams {
             let personalization = VRF_GROUP_HASH_GENERATORS_PERSONALIZATION;
 
-            //Gen1
-            let tag = b"Magnesium Mg 12";
-            let htc_g1_out = hash_to_curve::<FieldElement, $affine_curve>(tag, personalization)
-                .unwrap()
-                .into_projective();
-
-            //Gen2
-            let tag = b"Gold Au 79";
-            let htc_g2_out = hash_to_curve::<FieldElement, $affine_curve>(tag, personalization)
-                .unwrap()
-                .into_projective();
+            //Gen1 and Gen2
+            let htc_outs = VRF_GROUP_HASH_GENERATORS_TAGS
+                .iter()
+                .map(|tag| {
+                    hash_to_curve::<FieldElement, $affine_curve>(tag, personalization)
+                        .unwrap()
+                        .into_projective()
+                })
+                .collect::<Vec<_>>();
 
             //Check GH generators
-            let gh_generators = compute_group_hash_table([htc_g1_out, htc_g2_out].to_vec());
+            let gh_generators = compute_group_hash_table(htc_outs, VRFWindow::WINDOW_SIZE);
 
-            BoweHopwoodPedersenParameters::<$projective_curve> {
+            VRFGroupHashParams {
                 generators: gh_generators,
             }
         }
@@ -147,6 +160,9 @@ macro_rules! generate_vrf_types {
         pub type GroupHash = BoweHopwoodPedersenCRH<$projective_curve, VRFWindow>;
 
         pub type VRFScheme = FieldBasedEcVrf<FieldElement, $projective_curve, FieldHash, GroupHash>;
+        pub type SmallWindowGroupHash = BoweHopwoodPedersenCRH<$projective_curve, VRFSmallWindow>;
+        pub type SmallWindowVRFScheme =
+            FieldBasedEcVrf<FieldElement, $projective_curve, FieldHash, SmallWindowGroupHash>;
         pub type VRFProof = FieldBasedEcVrfProof<FieldElement, $projective_curve>;
         pub type VRFPk = $affine_curve;
         pub type VRFSk = ScalarFieldElement;
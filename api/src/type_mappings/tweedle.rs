@@ -25,6 +25,12 @@ use primitives::{
 
 pub type Error = Box<dyn std::error::Error>;
 
+/// Identifier of the Tweedle curves, written in the envelope of serialized objects
+pub const CURVE_ID: u8 = 1;
+
+/// Name of the Tweedle curves, used to tell apart per-curve resources
+pub const CURVE_NAME: &str = "tweedle";
+
 generate_all_algebraic_crypto_types!(
     DumAffine,
     DumProjective,
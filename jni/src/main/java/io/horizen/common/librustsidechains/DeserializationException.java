@@ -2,12 +2,67 @@ package io.horizen.common.librustsidechains;
 
 public class DeserializationException extends Exception {
 
+    /*
+     * Reason of the failure. Each value carries the code of the matching
+     * DeserializationErrorCode on the Rust side: never change them.
+     */
+    public enum ErrorCode {
+        UNKNOWN(0),
+        NOT_ENOUGH_BYTES(1),
+        MALFORMED_DATA(2),
+        UNEXPECTED_FLAGS(3),
+        NON_CANONICAL_FIELD_ELEMENT(4),
+        SEMANTIC_CHECKS_FAILED(5),
+        TRAILING_BYTES(6),
+        INVALID_ENVELOPE(7),
+        IO(8),
+        INVALID_POINT(9),
+        NOT_IN_SUBGROUP(10);
+
+        private final int code;
+
+        ErrorCode(int code) {
+            this.code = code;
+        }
+
+        public int getCode() {
+            return code;
+        }
+
+        /*
+         * Return the ErrorCode with the given code, or UNKNOWN if there is none.
+         */
+        public static ErrorCode fromCode(int code) {
+            for (ErrorCode errorCode : values()) {
+                if (errorCode.code == code)
+                    return errorCode;
+            }
+            return UNKNOWN;
+        }
+    }
+
+    private final ErrorCode errorCode;
+
     public DeserializationException(String message) {
-        super(message);
+        this(message, ErrorCode.UNKNOWN);
     }
 
     public DeserializationException(String message, Throwable cause) {
         super(message, cause);
+        this.errorCode = ErrorCode.UNKNOWN;
+    }
+
+    public DeserializationException(String message, ErrorCode errorCode) {
+        super(message);
+        this.errorCode = errorCode;
+    }
+
+    // Called from native code
+    private DeserializationException(String message, int errorCode) {
+        this(message, ErrorCode.fromCode(errorCode));
+    }
+
+    public ErrorCode getErrorCode() {
+        return errorCode;
     }
-    
 }
@@ -60,7 +60,7 @@ public class FieldElement implements AutoCloseable, Cloneable {
         return nativeSerializeFieldElement();
     }
 
-    private static native FieldElement nativeDeserializeFieldElement(byte[] fieldElementBytes) throws DeserializationException;
+    private static native FieldElement nativeDeserializeFieldElement(byte[] fieldElementBytes, boolean strict) throws DeserializationException;
 
     /**
      * Deserialize a FieldElement from "fieldElementBytes"
@@ -71,9 +71,60 @@ public class FieldElement implements AutoCloseable, Cloneable {
     public static FieldElement deserialize(byte[] fieldElementBytes) throws DeserializationException {
         if (fieldElementBytes.length > FIELD_ELEMENT_LENGTH)
             throw new DeserializationException(String.format("Field element length exceeded: limit %d , %d found",
-                    FIELD_ELEMENT_LENGTH, fieldElementBytes.length));
+                    FIELD_ELEMENT_LENGTH, fieldElementBytes.length), DeserializationException.ErrorCode.TRAILING_BYTES);
 
-        return nativeDeserializeFieldElement(fieldElementBytes);
+        return nativeDeserializeFieldElement(fieldElementBytes, false);
+    }
+
+    /**
+     * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+     * if the input holds more bytes than the ones of the serialized object.
+     */
+    public static FieldElement deserializeStrict(byte[] fieldElementBytes) throws DeserializationException {
+        return nativeDeserializeFieldElement(fieldElementBytes, true);
+    }
+
+    private native String nativeToHex();
+
+    /**
+     * Encode this object as the lowercase hex string of its compressed serialization.
+     */
+    public String toHex() {
+        if (fieldElementPointer == 0)
+            throw new IllegalStateException("Field element was freed.");
+
+        return nativeToHex();
+    }
+
+    private static native FieldElement nativeFromHex(String hex) throws DeserializationException;
+
+    /**
+     * Decode an object from a hex string produced by toHex(), performing semantic checks on it.
+     */
+    public static FieldElement fromHex(String hex) throws DeserializationException {
+        return nativeFromHex(hex);
+    }
+
+    private native String nativeToJson();
+
+    /**
+     * Encode this field element as JSON, i.e. the quoted string returned by toHex().
+     */
+    public String toJson() {
+        if (fieldElementPointer == 0)
+            throw new IllegalStateException("Field element was freed.");
+
+        return nativeToJson();
+    }
+
+    private static native FieldElement nativeFromJson(String json) throws DeserializationException;
+
+    /**
+     * Decode a field element from the JSON string produced by toJson(). Values not smaller
+     * than the modulus are rejected.
+     */
+    public static FieldElement fromJson(String json) throws DeserializationException {
+        return nativeFromJson(json);
     }
 
     private native FieldElement nativeClone();
@@ -89,6 +140,11 @@ public class FieldElement implements AutoCloseable, Cloneable {
     // Declared protected for testing purposes
     protected native void nativePrintFieldElementBytes();
 
+    /**
+     * Print the bytes of this field element to the standard output.
+     * @deprecated Use toHex() to get a printable representation of this field element.
+     */
+    @Deprecated
     public void printFieldElementBytes() {
         if (fieldElementPointer == 0)
             throw new IllegalStateException("Field element was freed.");
@@ -120,6 +176,21 @@ public class FieldElement implements AutoCloseable, Cloneable {
         return nativeEquals((FieldElement) o);
     }
 
+    private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+    public byte[] serializeEnveloped(boolean withChecksum) {
+        if (fieldElementPointer == 0)
+            throw new IllegalStateException("Field element was freed.");
+
+        return nativeSerializeEnveloped(withChecksum);
+    }
+
+    private static native FieldElement nativeDeserializeEnveloped(byte[] envelopeBytes) throws DeserializationException;
+
+    public static FieldElement deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+        return nativeDeserializeEnveloped(envelopeBytes);
+    }
+
     @Override
     public void close() {
         freeFieldElement();
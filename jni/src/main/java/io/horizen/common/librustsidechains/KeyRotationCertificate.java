@@ -0,0 +1,216 @@
+package io.horizen.common.librustsidechains;
+
+import io.horizen.common.schnorrnative.SchnorrKeyPair;
+import io.horizen.common.schnorrnative.SchnorrPublicKey;
+import io.horizen.common.vrfnative.VRFKeyPair;
+import io.horizen.common.vrfnative.VRFPublicKey;
+
+/*
+ * Certificate stating that a new Schnorr or VRF public key replaces an old one starting
+ * from a given epoch, signed with the secret key of the old public key.
+ */
+public class KeyRotationCertificate implements AutoCloseable
+{
+  public enum KeyType {
+    SCHNORR,
+    VRF
+  }
+
+  public static final int CERTIFICATE_LENGTH;
+
+  private long certificatePointer;
+
+  private static native int nativeGetCertificateSize();
+
+  static {
+    Library.load();
+    CERTIFICATE_LENGTH = nativeGetCertificateSize();
+  }
+
+  private KeyRotationCertificate(long certificatePointer) {
+    if (certificatePointer == 0)
+      throw new IllegalArgumentException("Certificate pointer must be not null.");
+    this.certificatePointer = certificatePointer;
+  }
+
+  private static native KeyRotationCertificate nativeCreateSchnorr(SchnorrKeyPair oldKeyPair, SchnorrPublicKey newPublicKey, long epoch) throws KeyRotationException;
+
+  /*
+   * Certify that newPublicKey replaces the public key of oldKeyPair, starting from epoch.
+   */
+  public static KeyRotationCertificate create(SchnorrKeyPair oldKeyPair, SchnorrPublicKey newPublicKey, long epoch) throws KeyRotationException {
+    if (epoch < 0)
+      throw new IllegalArgumentException("Epoch must be not negative");
+
+    return nativeCreateSchnorr(oldKeyPair, newPublicKey, epoch);
+  }
+
+  private static native KeyRotationCertificate nativeCreateVRF(VRFKeyPair oldKeyPair, VRFPublicKey newPublicKey, long epoch) throws KeyRotationException;
+
+  /*
+   * Certify that newPublicKey replaces the public key of oldKeyPair, starting from epoch.
+   */
+  public static KeyRotationCertificate create(VRFKeyPair oldKeyPair, VRFPublicKey newPublicKey, long epoch) throws KeyRotationException {
+    if (epoch < 0)
+      throw new IllegalArgumentException("Epoch must be not negative");
+
+    return nativeCreateVRF(oldKeyPair, newPublicKey, epoch);
+  }
+
+  private native byte[] nativeSerializeCertificate();
+
+  private static native KeyRotationCertificate nativeDeserializeCertificate(byte[] certificateBytes, boolean checkCertificate, boolean strict) throws DeserializationException;
+
+  private native void nativeFreeCertificate();
+
+  public static KeyRotationCertificate deserialize(byte[] certificateBytes, boolean checkCertificate) throws DeserializationException {
+    if (certificateBytes.length != CERTIFICATE_LENGTH)
+      throw new IllegalArgumentException(String.format("Incorrect certificate length, %d expected, %d found", CERTIFICATE_LENGTH, certificateBytes.length));
+
+    return nativeDeserializeCertificate(certificateBytes, checkCertificate, false);
+  }
+
+  public static KeyRotationCertificate deserialize(byte[] certificateBytes) throws DeserializationException {
+    return deserialize(certificateBytes, true);
+  }
+
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static KeyRotationCertificate deserializeStrict(byte[] certificateBytes, boolean checkCertificate) throws DeserializationException {
+    return nativeDeserializeCertificate(certificateBytes, checkCertificate, true);
+  }
+
+  public byte[] serializeCertificate() {
+    if (certificatePointer == 0)
+      throw new IllegalStateException("Certificate was freed.");
+
+    return nativeSerializeCertificate();
+  }
+
+  private native boolean nativeIsValidCertificate();
+
+  public boolean isValidCertificate() {
+    if (certificatePointer == 0)
+      throw new IllegalStateException("Certificate was freed.");
+
+    return nativeIsValidCertificate();
+  }
+
+  private native boolean nativeVerify() throws KeyRotationException;
+
+  /*
+   * Verify the signature of this certificate and the validity of the keys in it.
+   */
+  public boolean verify() throws KeyRotationException {
+    if (certificatePointer == 0)
+      throw new IllegalStateException("Certificate was freed.");
+
+    return nativeVerify();
+  }
+
+  private native int nativeGetKeyType();
+
+  public KeyType getKeyType() {
+    if (certificatePointer == 0)
+      throw new IllegalStateException("Certificate was freed.");
+
+    return KeyType.values()[nativeGetKeyType()];
+  }
+
+  private native long nativeGetEpoch();
+
+  public long getEpoch() {
+    if (certificatePointer == 0)
+      throw new IllegalStateException("Certificate was freed.");
+
+    return nativeGetEpoch();
+  }
+
+  private native SchnorrPublicKey nativeGetOldSchnorrPublicKey();
+
+  private native SchnorrPublicKey nativeGetNewSchnorrPublicKey();
+
+  private native VRFPublicKey nativeGetOldVRFPublicKey();
+
+  private native VRFPublicKey nativeGetNewVRFPublicKey();
+
+  private void checkKeyType(KeyType keyType) {
+    if (getKeyType() != keyType)
+      throw new IllegalStateException(String.format("Certificate rotates a %s key", getKeyType()));
+  }
+
+  public SchnorrPublicKey getOldSchnorrPublicKey() {
+    checkKeyType(KeyType.SCHNORR);
+    return nativeGetOldSchnorrPublicKey();
+  }
+
+  public SchnorrPublicKey getNewSchnorrPublicKey() {
+    checkKeyType(KeyType.SCHNORR);
+    return nativeGetNewSchnorrPublicKey();
+  }
+
+  public VRFPublicKey getOldVRFPublicKey() {
+    checkKeyType(KeyType.VRF);
+    return nativeGetOldVRFPublicKey();
+  }
+
+  public VRFPublicKey getNewVRFPublicKey() {
+    checkKeyType(KeyType.VRF);
+    return nativeGetNewVRFPublicKey();
+  }
+
+  private static native SchnorrPublicKey nativeVerifySchnorrChain(SchnorrPublicKey initialPublicKey, KeyRotationCertificate[] certificates) throws KeyRotationException;
+
+  /*
+   * Validate a chain of rotations of initialPublicKey, returning the current public key.
+   * Each certificate must be valid, rotate the key certified by the previous one and have
+   * an epoch strictly greater than the previous one.
+   */
+  public static SchnorrPublicKey verifyChain(SchnorrPublicKey initialPublicKey, KeyRotationCertificate[] certificates) throws KeyRotationException {
+    return nativeVerifySchnorrChain(initialPublicKey, certificates);
+  }
+
+  private static native VRFPublicKey nativeVerifyVRFChain(VRFPublicKey initialPublicKey, KeyRotationCertificate[] certificates) throws KeyRotationException;
+
+  /*
+   * Validate a chain of rotations of initialPublicKey, returning the current public key.
+   * Each certificate must be valid, rotate the key certified by the previous one and have
+   * an epoch strictly greater than the previous one.
+   */
+  public static VRFPublicKey verifyChain(VRFPublicKey initialPublicKey, KeyRotationCertificate[] certificates) throws KeyRotationException {
+    return nativeVerifyVRFChain(initialPublicKey, certificates);
+  }
+
+  public void freeCertificate() {
+    if (certificatePointer != 0) {
+      nativeFreeCertificate();
+      certificatePointer = 0;
+    }
+  }
+
+  private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    if (certificatePointer == 0)
+      throw new IllegalStateException("Certificate was freed.");
+
+    return nativeSerializeEnveloped(withChecksum);
+  }
+
+  private static native KeyRotationCertificate nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static KeyRotationCertificate deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static KeyRotationCertificate deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
+  @Override
+  public void close() {
+    freeCertificate();
+  }
+}
@@ -0,0 +1,13 @@
+package io.horizen.common.librustsidechains;
+
+public class KeyRotationException extends Exception {
+
+    public KeyRotationException(String message) {
+        super(message);
+    }
+
+    public KeyRotationException(String message, Throwable cause) {
+        super(message, cause);
+    }
+    
+}
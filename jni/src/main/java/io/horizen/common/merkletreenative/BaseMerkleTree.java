@@ -8,6 +8,12 @@ public class BaseMerkleTree implements MerkleTree {
     
     protected long inMemoryOptimizedMerkleTreePointer;
 
+    /**
+     * Default bound on the height of the trees deserialized in checked mode: higher trees are
+     * rejected, with SEMANTIC_CHECKS_FAILED error code, before their nodes are allocated.
+     */
+    public static final int DEFAULT_MAX_CHECKED_HEIGHT = 18;
+
     static {
         Library.load();
     }
@@ -34,23 +40,136 @@ public class BaseMerkleTree implements MerkleTree {
 
     protected native byte[] nativeSerialize();
 
+    private native void nativeSerializeToStream(OutputStream out) throws IOException;
+
+    /**
+     * Serialize this tree directly into "out", without building an intermediate byte array.
+     * @param out the stream to write the tree into
+     * @throws IOException If writing to "out" fails
+     */
+    public void serialize(OutputStream out) throws IOException {
+        if (inMemoryOptimizedMerkleTreePointer == 0)
+            throw new IllegalStateException("InMemoryOptimizedMerkleTree instance was freed.");
+        nativeSerializeToStream(out);
+    }
+
     private void writeObject(ObjectOutputStream out) throws IOException {
+        serialize(out);
+    }
+
+    // The natives check the tree only if "maxCheckedHeight" is positive, bounding its height
+    private static int maxCheckedHeight(boolean checked) {
+        return checked ? DEFAULT_MAX_CHECKED_HEIGHT : 0;
+    }
+
+    private static int requirePositive(int maxHeight) {
+        if (maxHeight <= 0)
+            throw new IllegalArgumentException("maxHeight must be positive.");
+        return maxHeight;
+    }
+
+    protected static native BaseMerkleTree nativeDeserialize(byte[] serializedTree, int maxCheckedHeight, boolean strict) throws DeserializationException;
+
+    private static native BaseMerkleTree nativeDeserializeFromStream(InputStream in, int maxCheckedHeight) throws DeserializationException, IOException;
+
+    /**
+     * Deserialize a BaseMerkleTree reading directly from "in", without building an intermediate byte array.
+     * Only the bytes of the tree are consumed; as no read-ahead is performed, wrapping "in" into a
+     * BufferedInputStream may speed up the deserialization.
+     * @param in the stream to read the tree from
+     * @return The deserialized BaseMerkleTree
+     * @throws DeserializationException If the bytes read don't represent a valid tree
+     * @throws IOException If reading from "in" fails
+     */
+    public static BaseMerkleTree deserialize(InputStream in) throws DeserializationException, IOException {
+        return nativeDeserializeFromStream(in, 0);
+    }
+
+    /**
+     * Same as deserialize(InputStream), but, if "checked" is set, the consistency of the tree is
+     * validated, throwing a DeserializationException with SEMANTIC_CHECKS_FAILED error code if its
+     * nodes or root don't match its leaves. The check is as expensive as building the tree from
+     * scratch, and it's meant for trees coming from untrusted sources. Trees higher than
+     * DEFAULT_MAX_CHECKED_HEIGHT are rejected.
+     */
+    public static BaseMerkleTree deserialize(InputStream in, boolean checked) throws DeserializationException, IOException {
+        return nativeDeserializeFromStream(in, maxCheckedHeight(checked));
+    }
+
+    /**
+     * Same as deserialize(InputStream, true), accepting trees up to "maxHeight" high.
+     */
+    public static BaseMerkleTree deserialize(InputStream in, int maxHeight) throws DeserializationException, IOException {
+        return nativeDeserializeFromStream(in, requirePositive(maxHeight));
+    }
+
+    private native void nativeWriteToFile(String filePath) throws IOException;
+
+    /**
+     * Write this tree to the file at "filePath". The write is atomic: in case of failure
+     * the file is either left untouched or holds the whole serialized tree.
+     */
+    public void writeToFile(String filePath) throws IOException {
         if (inMemoryOptimizedMerkleTreePointer == 0)
             throw new IllegalStateException("InMemoryOptimizedMerkleTree instance was freed.");
-        out.write(nativeSerialize());
+        nativeWriteToFile(filePath);
+    }
+
+    private static native BaseMerkleTree nativeReadFromFile(String filePath, int maxCheckedHeight) throws DeserializationException;
+
+    /**
+     * Read a tree previously written with writeToFile() from the file at "filePath", without
+     * validating its consistency. Bytes following the tree are rejected with TRAILING_BYTES,
+     * and I/O failures are reported with the IO error code.
+     */
+    public static BaseMerkleTree readFromFile(String filePath) throws DeserializationException {
+        return nativeReadFromFile(filePath, 0);
+    }
+
+    /**
+     * Same as readFromFile(String), validating the consistency of the tree if "checked" is set.
+     */
+    public static BaseMerkleTree readFromFile(String filePath, boolean checked) throws DeserializationException {
+        return nativeReadFromFile(filePath, maxCheckedHeight(checked));
     }
 
-    protected static native BaseMerkleTree nativeDeserialize(byte[] serializedTree) throws DeserializationException;
+    /**
+     * Same as readFromFile(String, true), accepting trees up to "maxHeight" high.
+     */
+    public static BaseMerkleTree readFromFile(String filePath, int maxHeight) throws DeserializationException {
+        return nativeReadFromFile(filePath, requirePositive(maxHeight));
+    }
 
     private void readObject(ObjectInputStream in) throws IOException, ClassNotFoundException {
-        byte[] serialized = in.readAllBytes();
         try {
-            this.inMemoryOptimizedMerkleTreePointer = nativeDeserialize(serialized).inMemoryOptimizedMerkleTreePointer;
+            this.inMemoryOptimizedMerkleTreePointer = deserialize(in).inMemoryOptimizedMerkleTreePointer;
         } catch (DeserializationException ex) {
             throw new IOException(ex.getMessage());
         }
     }
 
+    /**
+     * Deserialize a BaseMerkleTree from "serializedTree", throwing a DeserializationException with
+     * TRAILING_BYTES error code if the input holds more bytes than the ones of the serialized tree.
+     */
+    public static BaseMerkleTree deserializeStrict(byte[] serializedTree) throws DeserializationException {
+        return nativeDeserialize(serializedTree, 0, true);
+    }
+
+    /**
+     * Same as deserializeStrict(byte[]), validating the consistency of the tree if "checked" is set.
+     */
+    public static BaseMerkleTree deserializeStrict(byte[] serializedTree, boolean checked) throws DeserializationException {
+        return nativeDeserialize(serializedTree, maxCheckedHeight(checked), true);
+    }
+
+    /**
+     * Same as deserializeStrict(byte[], true), accepting trees up to "maxHeight" high.
+     */
+    public static BaseMerkleTree deserializeStrict(byte[] serializedTree, int maxHeight) throws DeserializationException {
+        return nativeDeserialize(serializedTree, requirePositive(maxHeight), true);
+    }
+
     private native void nativeAppend(FieldElement input) throws MerkleTreeException;
 
     @Override
@@ -128,6 +247,29 @@ public class BaseMerkleTree implements MerkleTree {
         nativeReset();
     }
 
+    private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+    public byte[] serializeEnveloped(boolean withChecksum) {
+        if (inMemoryOptimizedMerkleTreePointer == 0)
+            throw new IllegalStateException("InMemoryOptimizedMerkleTree instance was freed.");
+
+        return nativeSerializeEnveloped(withChecksum);
+    }
+
+    private static native BaseMerkleTree nativeDeserializeEnveloped(byte[] envelopeBytes, int maxCheckedHeight) throws DeserializationException;
+
+    public static BaseMerkleTree deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+        return nativeDeserializeEnveloped(envelopeBytes, maxCheckedHeight(checked));
+    }
+
+    public static BaseMerkleTree deserializeEnveloped(byte[] envelopeBytes, int maxHeight) throws DeserializationException {
+        return nativeDeserializeEnveloped(envelopeBytes, requirePositive(maxHeight));
+    }
+
+    public static BaseMerkleTree deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+        return deserializeEnveloped(envelopeBytes, false);
+    }
+
     private native void nativeFreeMerkleTree();
 
     @Override
@@ -3,6 +3,7 @@ package io.horizen.common.merkletreenative;
 import io.horizen.common.librustsidechains.Library;
 import io.horizen.common.librustsidechains.DeserializationException;
 import io.horizen.common.librustsidechains.FieldElement;
+import java.io.IOException;
 
 public class FieldBasedMerklePath implements AutoCloseable {
 
@@ -110,14 +111,116 @@ public class FieldBasedMerklePath implements AutoCloseable {
         return nativeSerialize();
     }
 
-    private static native FieldBasedMerklePath nativeDeserialize(byte[] merklePathBytes, boolean semanticChecks)  throws DeserializationException;
+    private static native FieldBasedMerklePath nativeDeserialize(byte[] merklePathBytes, boolean semanticChecks, boolean strict)  throws DeserializationException;
 
     public static FieldBasedMerklePath deserialize(byte[] merklePathBytes, boolean semanticChecks) throws DeserializationException {
-        return nativeDeserialize(merklePathBytes, semanticChecks);
+        return nativeDeserialize(merklePathBytes, semanticChecks, false);
     }
 
     public static FieldBasedMerklePath deserialize(byte[] merklePathBytes) throws DeserializationException {
-        return nativeDeserialize(merklePathBytes, true);
+        return nativeDeserialize(merklePathBytes, true, false);
+    }
+
+    /**
+     * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+     * if the input holds more bytes than the ones of the serialized object.
+     */
+    public static FieldBasedMerklePath deserializeStrict(byte[] merklePathBytes, boolean semanticChecks) throws DeserializationException {
+        return nativeDeserialize(merklePathBytes, semanticChecks, true);
+    }
+
+    private native byte[] nativeSerializeCompact();
+
+    /**
+     * Serialize this path in a compact form, storing the leaf index once and omitting the
+     * siblings that are roots of empty subtrees. Paths of partially filled trees take
+     * considerably less space than with serialize().
+     */
+    public byte[] serializeCompact() {
+        if (merklePathPointer == 0)
+            throw new IllegalStateException("MerklePath instance was freed.");
+
+        return nativeSerializeCompact();
+    }
+
+    private static native FieldBasedMerklePath nativeDeserializeCompact(byte[] compactPathBytes, boolean semanticChecks) throws DeserializationException;
+
+    /**
+     * Deserialize a path serialized with serializeCompact(). "compactPathBytes" must contain
+     * exactly the serialized path.
+     */
+    public static FieldBasedMerklePath deserializeCompact(byte[] compactPathBytes, boolean semanticChecks) throws DeserializationException {
+        return nativeDeserializeCompact(compactPathBytes, semanticChecks);
+    }
+
+    public static FieldBasedMerklePath deserializeCompact(byte[] compactPathBytes) throws DeserializationException {
+        return nativeDeserializeCompact(compactPathBytes, true);
+    }
+
+    private native void nativeWriteToFile(String filePath) throws IOException;
+
+    /**
+     * Write this object to the file at "filePath". The write is atomic: in case of failure
+     * the file is either left untouched or holds the whole serialized object.
+     */
+    public void writeToFile(String filePath) throws IOException {
+        if (merklePathPointer == 0)
+            throw new IllegalStateException("MerklePath instance was freed.");
+
+        nativeWriteToFile(filePath);
+    }
+
+    private static native FieldBasedMerklePath nativeReadFromFile(String filePath, boolean checked) throws DeserializationException;
+
+    /**
+     * Read a Merkle path saved by writeToFile(), checking it if "checked" is set.
+     * The whole file must be consumed by the path; I/O failures get the IO error code.
+     */
+    public static FieldBasedMerklePath readFromFile(String filePath, boolean checked) throws DeserializationException {
+        return nativeReadFromFile(filePath, checked);
+    }
+
+    private native String nativeToHex();
+
+    /**
+     * Encode this object as the lowercase hex string of its compressed serialization.
+     */
+    public String toHex() {
+        if (merklePathPointer == 0)
+            throw new IllegalStateException("MerklePath instance was freed.");
+
+        return nativeToHex();
+    }
+
+    private static native FieldBasedMerklePath nativeFromHex(String hex) throws DeserializationException;
+
+    /**
+     * Decode an object from a hex string produced by toHex(), performing semantic checks on it.
+     */
+    public static FieldBasedMerklePath fromHex(String hex) throws DeserializationException {
+        return nativeFromHex(hex);
+    }
+
+    private native String nativeToJson();
+
+    /**
+     * Encode this path as a JSON object whose "path" array lists, from the leaf to the root, the
+     * hex encoded "sibling" of each node and whether the node is a right child ("is_right").
+     */
+    public String toJson() {
+        if (merklePathPointer == 0)
+            throw new IllegalStateException("MerklePath instance was freed.");
+
+        return nativeToJson();
+    }
+
+    private static native FieldBasedMerklePath nativeFromJson(String json) throws DeserializationException;
+
+    /**
+     * Decode a path from the JSON object produced by toJson(), checking it.
+     */
+    public static FieldBasedMerklePath fromJson(String json) throws DeserializationException {
+        return nativeFromJson(json);
     }
 
     private native void nativeFreeMerklePath();
@@ -129,6 +232,25 @@ public class FieldBasedMerklePath implements AutoCloseable {
         }
     }
 
+    private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+    public byte[] serializeEnveloped(boolean withChecksum) {
+        if (merklePathPointer == 0)
+            throw new IllegalStateException("MerklePath instance was freed.");
+
+        return nativeSerializeEnveloped(withChecksum);
+    }
+
+    private static native FieldBasedMerklePath nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+    public static FieldBasedMerklePath deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+        return nativeDeserializeEnveloped(envelopeBytes, checked);
+    }
+
+    public static FieldBasedMerklePath deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+        return deserializeEnveloped(envelopeBytes, true);
+    }
+
     @Override
     public void close() {
         freeMerklePath();
@@ -0,0 +1,45 @@
+package io.horizen.common.schnorrnative;
+
+import io.horizen.common.librustsidechains.Library;
+
+public class ForwardSecureKeyPair implements AutoCloseable {
+    private ForwardSecureSecretKey secretKey;
+    private ForwardSecurePublicKey publicKey;
+
+    static {
+        Library.load();
+    }
+
+    public ForwardSecureKeyPair(ForwardSecureSecretKey secretKey, ForwardSecurePublicKey publicKey) {
+        this.secretKey = secretKey;
+        this.publicKey = publicKey;
+    }
+
+    private static native ForwardSecureKeyPair nativeGenerate(int height) throws SchnorrSignatureException;
+
+    /*
+     * Generate a forward secure key pair supporting 2^height periods, starting at period 0.
+     * The keys of all the periods are derived at generation time, thus its cost grows
+     * linearly with the number of periods.
+     */
+    public static ForwardSecureKeyPair generate(int height) throws SchnorrSignatureException {
+        if (height <= 0)
+            throw new IllegalArgumentException("Height must be positive");
+
+        return nativeGenerate(height);
+    }
+
+    public ForwardSecureSecretKey getSecretKey() {
+        return this.secretKey;
+    }
+
+    public ForwardSecurePublicKey getPublicKey() {
+        return this.publicKey;
+    }
+
+    @Override
+    public void close() {
+        this.publicKey.close();
+        this.secretKey.close();
+    }
+}
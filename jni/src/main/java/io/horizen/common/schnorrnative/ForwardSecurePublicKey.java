@@ -0,0 +1,141 @@
+package io.horizen.common.schnorrnative;
+
+import io.horizen.common.librustsidechains.*;
+import java.io.IOException;
+
+/*
+ * Public key of the forward secure signature scheme: the root of a Poseidon Merkle tree
+ * whose leaves are the Schnorr public keys of the periods.
+ */
+public class ForwardSecurePublicKey implements AutoCloseable
+{
+  public static final int PUBLIC_KEY_LENGTH;
+
+  private long publicKeyPointer;
+
+  private static native int nativeGetPublicKeySize();
+
+  static {
+    Library.load();
+    PUBLIC_KEY_LENGTH = nativeGetPublicKeySize();
+  }
+
+  private ForwardSecurePublicKey(long publicKeyPointer) {
+    if (publicKeyPointer == 0)
+      throw new IllegalArgumentException("Public key pointer must be not null.");
+    this.publicKeyPointer = publicKeyPointer;
+  }
+
+  private native byte[] nativeSerializePublicKey();
+
+  private static native ForwardSecurePublicKey nativeDeserializePublicKey(byte[] publicKeyBytes, boolean checkPublicKey, boolean strict) throws DeserializationException;
+
+  private native void nativeFreePublicKey();
+
+  public static ForwardSecurePublicKey deserialize(byte[] publicKeyBytes, boolean checkPublicKey) throws DeserializationException {
+    if (publicKeyBytes.length != PUBLIC_KEY_LENGTH)
+      throw new IllegalArgumentException(String.format("Incorrect public key length, %d expected, %d found", PUBLIC_KEY_LENGTH, publicKeyBytes.length));
+
+    return nativeDeserializePublicKey(publicKeyBytes, checkPublicKey, false);
+  }
+
+  public static ForwardSecurePublicKey deserialize(byte[] publicKeyBytes) throws DeserializationException {
+    return deserialize(publicKeyBytes, true);
+  }
+
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static ForwardSecurePublicKey deserializeStrict(byte[] publicKeyBytes, boolean checkPublicKey) throws DeserializationException {
+    return nativeDeserializePublicKey(publicKeyBytes, checkPublicKey, true);
+  }
+
+  private native void nativeWriteToFile(String filePath) throws IOException;
+
+  /**
+   * Write this object to the file at "filePath". The write is atomic: in case of failure
+   * the file is either left untouched or holds the whole serialized object.
+   */
+  public void writeToFile(String filePath) throws IOException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    nativeWriteToFile(filePath);
+  }
+
+  private static native ForwardSecurePublicKey nativeReadFromFile(String filePath, boolean checked) throws DeserializationException;
+
+  /**
+   * Read a forward secure public key saved by writeToFile(), checking it if "checked" is set.
+   * A key followed by other data is rejected; I/O failures get the IO error code.
+   */
+  public static ForwardSecurePublicKey readFromFile(String filePath, boolean checked) throws DeserializationException {
+    return nativeReadFromFile(filePath, checked);
+  }
+
+  public byte[] serializePublicKey() {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeSerializePublicKey();
+  }
+
+  private native int nativeGetHeight();
+
+  /*
+   * Return the height of the tree of period keys: the key supports 2^height periods.
+   */
+  public int getHeight() {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeGetHeight();
+  }
+
+  private native boolean nativeVerifySignature(ForwardSecureSignature signature, FieldElement message, long period) throws SchnorrSignatureException;
+
+  /*
+   * Verify signature of message, made at period.
+   */
+  public boolean verifySignature(ForwardSecureSignature signature, FieldElement message, long period) throws SchnorrSignatureException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    if (period < 0)
+      throw new IllegalArgumentException("Period must be not negative");
+
+    return nativeVerifySignature(signature, message, period);
+  }
+
+  public void freePublicKey() {
+    if (publicKeyPointer != 0) {
+      nativeFreePublicKey();
+      publicKeyPointer = 0;
+    }
+  }
+
+  private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeSerializeEnveloped(withChecksum);
+  }
+
+  private static native ForwardSecurePublicKey nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static ForwardSecurePublicKey deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static ForwardSecurePublicKey deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
+  @Override
+  public void close() {
+    freePublicKey();
+  }
+}
@@ -0,0 +1,160 @@
+package io.horizen.common.schnorrnative;
+
+import io.horizen.common.librustsidechains.*;
+import java.io.IOException;
+
+/*
+ * Secret key of the forward secure signature scheme. Updating the key to a later period
+ * erases the secrets of the previous ones: once updated, the key can't sign anymore for
+ * the past periods.
+ */
+public class ForwardSecureSecretKey implements AutoCloseable
+{
+  private long secretKeyPointer;
+
+  static {
+    Library.load();
+  }
+
+  private ForwardSecureSecretKey(long secretKeyPointer) {
+    if (secretKeyPointer == 0)
+      throw new IllegalArgumentException("Secret key pointer must be not null.");
+    this.secretKeyPointer = secretKeyPointer;
+  }
+
+  private native byte[] nativeSerializeSecretKey();
+
+  private static native ForwardSecureSecretKey nativeDeserializeSecretKey(byte[] secretKeyBytes, boolean strict) throws DeserializationException;
+
+  private native void nativeFreeSecretKey();
+
+  public static ForwardSecureSecretKey deserialize(byte[] secretKeyBytes) throws DeserializationException {
+    return nativeDeserializeSecretKey(secretKeyBytes, false);
+  }
+
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static ForwardSecureSecretKey deserializeStrict(byte[] secretKeyBytes) throws DeserializationException {
+    return nativeDeserializeSecretKey(secretKeyBytes, true);
+  }
+
+  private native void nativeWriteToFile(String filePath) throws IOException;
+
+  /**
+   * Write this object to the file at "filePath". The write is atomic: in case of failure
+   * the file is either left untouched or holds the whole serialized object.
+   */
+  public void writeToFile(String filePath) throws IOException {
+    if (secretKeyPointer == 0)
+      throw new IllegalStateException("Secret key was freed.");
+
+    nativeWriteToFile(filePath);
+  }
+
+  private static native ForwardSecureSecretKey nativeReadFromFile(String filePath) throws DeserializationException;
+
+  /**
+   * Read a forward secure secret key, with its current period and seed, saved by writeToFile().
+   * The file should be protected as any other secret key; I/O failures get the IO error code.
+   */
+  public static ForwardSecureSecretKey readFromFile(String filePath) throws DeserializationException {
+    return nativeReadFromFile(filePath);
+  }
+
+  public byte[] serializeSecretKey() {
+    if (secretKeyPointer == 0)
+      throw new IllegalStateException("Secret key was freed.");
+
+    return nativeSerializeSecretKey();
+  }
+
+  private native ForwardSecurePublicKey nativeGetPublicKey() throws SchnorrSignatureException;
+
+  public ForwardSecurePublicKey getPublicKey() throws SchnorrSignatureException {
+    if (secretKeyPointer == 0)
+      throw new IllegalStateException("Secret key was freed.");
+
+    return nativeGetPublicKey();
+  }
+
+  private native long nativeGetPeriod();
+
+  public long getPeriod() {
+    if (secretKeyPointer == 0)
+      throw new IllegalStateException("Secret key was freed.");
+
+    return nativeGetPeriod();
+  }
+
+  private native int nativeGetHeight();
+
+  public int getHeight() {
+    if (secretKeyPointer == 0)
+      throw new IllegalStateException("Secret key was freed.");
+
+    return nativeGetHeight();
+  }
+
+  private native void nativeUpdateTo(long period) throws SchnorrSignatureException;
+
+  /*
+   * Evolve the key to period, erasing the secrets of all the periods before it.
+   */
+  public void updateTo(long period) throws SchnorrSignatureException {
+    if (secretKeyPointer == 0)
+      throw new IllegalStateException("Secret key was freed.");
+
+    if (period < 0)
+      throw new IllegalArgumentException("Period must be not negative");
+
+    nativeUpdateTo(period);
+  }
+
+  /*
+   * Evolve the key to the next period, erasing the secret of the current one.
+   */
+  public void update() throws SchnorrSignatureException {
+    updateTo(getPeriod() + 1);
+  }
+
+  private native ForwardSecureSignature nativeSignMessage(FieldElement message) throws SchnorrSignatureException;
+
+  /*
+   * Sign message at the current period of the key.
+   */
+  public ForwardSecureSignature signMessage(FieldElement message) throws SchnorrSignatureException {
+    if (secretKeyPointer == 0)
+      throw new IllegalStateException("Secret key was freed.");
+
+    return nativeSignMessage(message);
+  }
+
+  public void freeSecretKey() {
+    if (secretKeyPointer != 0) {
+      nativeFreeSecretKey();
+      secretKeyPointer = 0;
+    }
+  }
+
+  private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    if (secretKeyPointer == 0)
+      throw new IllegalStateException("Secret key was freed.");
+
+    return nativeSerializeEnveloped(withChecksum);
+  }
+
+  private static native ForwardSecureSecretKey nativeDeserializeEnveloped(byte[] envelopeBytes) throws DeserializationException;
+
+  public static ForwardSecureSecretKey deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes);
+  }
+
+  @Override
+  public void close() {
+    freeSecretKey();
+  }
+}
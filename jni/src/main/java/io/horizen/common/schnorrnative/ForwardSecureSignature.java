@@ -0,0 +1,124 @@
+package io.horizen.common.schnorrnative;
+
+import io.horizen.common.librustsidechains.*;
+import java.io.IOException;
+
+/*
+ * Signature of the forward secure signature scheme: a Schnorr signature under the key
+ * of a period, together with the Merkle path of that key.
+ */
+public class ForwardSecureSignature implements AutoCloseable
+{
+  private long signaturePointer;
+
+  static {
+    Library.load();
+  }
+
+  private ForwardSecureSignature(long signaturePointer) {
+    if (signaturePointer == 0)
+      throw new IllegalArgumentException("Signature pointer must be not null.");
+    this.signaturePointer = signaturePointer;
+  }
+
+  private native byte[] nativeSerializeSignature();
+
+  private static native ForwardSecureSignature nativeDeserializeSignature(byte[] signatureBytes, boolean checkSignature, boolean strict) throws DeserializationException;
+
+  private native void nativeFreeSignature();
+
+  public static ForwardSecureSignature deserialize(byte[] signatureBytes, boolean checkSignature) throws DeserializationException {
+    return nativeDeserializeSignature(signatureBytes, checkSignature, false);
+  }
+
+  public static ForwardSecureSignature deserialize(byte[] signatureBytes) throws DeserializationException {
+    return deserialize(signatureBytes, true);
+  }
+
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static ForwardSecureSignature deserializeStrict(byte[] signatureBytes, boolean checkSignature) throws DeserializationException {
+    return nativeDeserializeSignature(signatureBytes, checkSignature, true);
+  }
+
+  private native void nativeWriteToFile(String filePath) throws IOException;
+
+  /**
+   * Write this object to the file at "filePath". The write is atomic: in case of failure
+   * the file is either left untouched or holds the whole serialized object.
+   */
+  public void writeToFile(String filePath) throws IOException {
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Signature was freed.");
+
+    nativeWriteToFile(filePath);
+  }
+
+  private static native ForwardSecureSignature nativeReadFromFile(String filePath, boolean checked) throws DeserializationException;
+
+  /**
+   * Read a forward secure signature saved by writeToFile(). If "checked" is set, its period
+   * public key, Schnorr signature and Merkle path are checked. I/O failures get the IO error code.
+   */
+  public static ForwardSecureSignature readFromFile(String filePath, boolean checked) throws DeserializationException {
+    return nativeReadFromFile(filePath, checked);
+  }
+
+  public byte[] serializeSignature() {
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Signature was freed.");
+
+    return nativeSerializeSignature();
+  }
+
+  private native boolean nativeIsValidSignature();
+
+  public boolean isValidSignature() {
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Signature was freed.");
+
+    return nativeIsValidSignature();
+  }
+
+  private native long nativeGetPeriod();
+
+  public long getPeriod() {
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Signature was freed.");
+
+    return nativeGetPeriod();
+  }
+
+  public void freeSignature() {
+    if (signaturePointer != 0) {
+      nativeFreeSignature();
+      signaturePointer = 0;
+    }
+  }
+
+  private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Signature was freed.");
+
+    return nativeSerializeEnveloped(withChecksum);
+  }
+
+  private static native ForwardSecureSignature nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static ForwardSecureSignature deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static ForwardSecureSignature deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
+  @Override
+  public void close() {
+    freeSignature();
+  }
+}
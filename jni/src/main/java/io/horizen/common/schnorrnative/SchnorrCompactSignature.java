@@ -0,0 +1,169 @@
+package io.horizen.common.schnorrnative;
+
+import io.horizen.common.librustsidechains.*;
+import java.io.IOException;
+
+/*
+ * Alternative representation of a SchnorrSignature, committing to the nonce commitment R
+ * instead of the challenge e: it allows to batch verify many signatures at once.
+ */
+public class SchnorrCompactSignature implements AutoCloseable
+{
+  public static final int COMPACT_SIGNATURE_LENGTH;
+
+  private long compactSignaturePointer;
+
+  private static native int nativeGetCompactSignatureSize();
+
+  static {
+    Library.load();
+    COMPACT_SIGNATURE_LENGTH = nativeGetCompactSignatureSize();
+  }
+
+  private SchnorrCompactSignature(long compactSignaturePointer) {
+    if (compactSignaturePointer == 0)
+      throw new IllegalArgumentException("Compact signature pointer must be not null.");
+    this.compactSignaturePointer = compactSignaturePointer;
+  }
+
+  private native byte[] nativeSerializeCompactSignature(boolean compressed);
+
+  private static native SchnorrCompactSignature nativeDeserializeCompactSignature(byte[] compactSignatureBytes, boolean checkCompactSignature, boolean compressed, boolean strict) throws DeserializationException;
+
+  private native void nativeFreeCompactSignature();
+
+  public static SchnorrCompactSignature deserialize(byte[] compactSignatureBytes, boolean checkCompactSignature, boolean compressed) throws DeserializationException {
+    return nativeDeserializeCompactSignature(compactSignatureBytes, checkCompactSignature, compressed, false);
+  }
+
+  public static SchnorrCompactSignature deserialize(byte[] compactSignatureBytes, boolean checkCompactSignature) throws DeserializationException {
+    if (compactSignatureBytes.length != COMPACT_SIGNATURE_LENGTH)
+      throw new IllegalArgumentException(String.format("Incorrect compact signature length, %d expected, %d found", COMPACT_SIGNATURE_LENGTH, compactSignatureBytes.length));
+
+    return deserialize(compactSignatureBytes, checkCompactSignature, true);
+  }
+
+  public static SchnorrCompactSignature deserialize(byte[] compactSignatureBytes) throws DeserializationException {
+    return deserialize(compactSignatureBytes, true);
+  }
+
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static SchnorrCompactSignature deserializeStrict(byte[] compactSignatureBytes, boolean checkCompactSignature, boolean compressed) throws DeserializationException {
+    return nativeDeserializeCompactSignature(compactSignatureBytes, checkCompactSignature, compressed, true);
+  }
+
+  private native void nativeWriteToFile(String filePath, boolean compressed) throws IOException;
+
+  /**
+   * Write this object to the file at "filePath". The write is atomic: in case of failure
+   * the file is either left untouched or holds the whole serialized object.
+   */
+  public void writeToFile(String filePath, boolean compressed) throws IOException {
+    if (compactSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr compact signature was freed.");
+
+    nativeWriteToFile(filePath, compressed);
+  }
+
+  private static native SchnorrCompactSignature nativeReadFromFile(String filePath, boolean checked, boolean compressed) throws DeserializationException;
+
+  /**
+   * Read a compact signature saved by writeToFile() with the same "compressed" flag,
+   * checking it if "checked" is set. Extra bytes in the file are an error (TRAILING_BYTES).
+   */
+  public static SchnorrCompactSignature readFromFile(String filePath, boolean checked, boolean compressed) throws DeserializationException {
+    return nativeReadFromFile(filePath, checked, compressed);
+  }
+
+  public byte[] serializeCompactSignature(boolean compressed) {
+    if (compactSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr compact signature was freed.");
+
+    return nativeSerializeCompactSignature(compressed);
+  }
+
+  public byte[] serializeCompactSignature() {
+    return serializeCompactSignature(true);
+  }
+
+  private native boolean nativeIsValidCompactSignature(); // jni call to Rust impl
+
+  public boolean isValidCompactSignature() {
+    if (compactSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr compact signature was freed.");
+
+    return nativeIsValidCompactSignature();
+  }
+
+  private static native SchnorrCompactSignature nativeFromSignature(SchnorrSignature signature, SchnorrPublicKey publicKey) throws SchnorrSignatureException;
+
+  /*
+   * Convert `signature`, a signature under `publicKey`, into its compact representation.
+   */
+  public static SchnorrCompactSignature fromSignature(SchnorrSignature signature, SchnorrPublicKey publicKey) throws SchnorrSignatureException {
+    return nativeFromSignature(signature, publicKey);
+  }
+
+  private native SchnorrSignature nativeToSignature(FieldElement message, SchnorrPublicKey publicKey) throws SchnorrSignatureException;
+
+  /*
+   * Convert this compact signature of `message` under `publicKey` into a SchnorrSignature.
+   */
+  public SchnorrSignature toSignature(FieldElement message, SchnorrPublicKey publicKey) throws SchnorrSignatureException {
+    if (compactSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr compact signature was freed.");
+
+    return nativeToSignature(message, publicKey);
+  }
+
+  private static native boolean nativeBatchVerify(FieldElement[] messages, SchnorrPublicKey[] publicKeys, SchnorrCompactSignature[] compactSignatures) throws SchnorrSignatureException;
+
+  /*
+   * Verify at once compactSignatures[i] of messages[i] under publicKeys[i], for each i.
+   * Returns true only if all the signatures are valid.
+   */
+  public static boolean batchVerify(FieldElement[] messages, SchnorrPublicKey[] publicKeys, SchnorrCompactSignature[] compactSignatures) throws SchnorrSignatureException {
+    if (messages.length != publicKeys.length || messages.length != compactSignatures.length)
+      throw new IllegalArgumentException("Messages, public keys and compact signatures must have the same length");
+
+    return nativeBatchVerify(messages, publicKeys, compactSignatures);
+  }
+
+  public void freeCompactSignature() {
+    if (compactSignaturePointer != 0) {
+      nativeFreeCompactSignature();
+      compactSignaturePointer = 0;
+    }
+  }
+
+  private native byte[] nativeSerializeEnveloped(boolean compressed, boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean compressed, boolean withChecksum) {
+    if (compactSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr compact signature was freed.");
+
+    return nativeSerializeEnveloped(compressed, withChecksum);
+  }
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    return serializeEnveloped(true, withChecksum);
+  }
+
+  private static native SchnorrCompactSignature nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static SchnorrCompactSignature deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static SchnorrCompactSignature deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
+  @Override
+  public void close() {
+    freeCompactSignature();
+  }
+}
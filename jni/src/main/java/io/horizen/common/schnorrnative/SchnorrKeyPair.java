@@ -27,12 +27,70 @@ public class SchnorrKeyPair implements AutoCloseable {
         return nativeGenerate();
     }
 
+    private static native SchnorrKeyPair nativeGenerateAdaptor();
+
+    /*
+     * Generate a key pair to be used as adaptor point and adaptor secret
+     * for SchnorrPreSignature.
+     */
+    public static SchnorrKeyPair generateAdaptor() {
+        return nativeGenerateAdaptor();
+    }
+
     private native SchnorrSignature nativeSignMessage(FieldElement message) throws SchnorrSignatureException;
 
     public SchnorrSignature signMessage(FieldElement message) throws SchnorrSignatureException {
         return nativeSignMessage(message);
     }
 
+    private native SchnorrSignature nativeSignFieldElements(FieldElement[] message) throws SchnorrSignatureException;
+
+    /*
+     * Sign a message made of multiple field elements, hashed internally with Poseidon
+     * into the single field element actually signed.
+     */
+    public SchnorrSignature signMessage(FieldElement[] message) throws SchnorrSignatureException {
+        return nativeSignFieldElements(message);
+    }
+
+    private native SchnorrSignature nativeSignBytes(byte[] message) throws SchnorrSignatureException;
+
+    /*
+     * Sign an arbitrary byte message, hashed internally with Poseidon
+     * into the single field element actually signed.
+     */
+    public SchnorrSignature signMessage(byte[] message) throws SchnorrSignatureException {
+        return nativeSignBytes(message);
+    }
+
+    private native SchnorrSignature nativeSignMessageWithDomainTag(FieldElement message, FieldElement[] domainTag) throws SchnorrSignatureException;
+
+    /*
+     * Sign `message` in the context identified by `domainTag`. The resulting signature
+     * verifies only if the same `domainTag` is provided.
+     */
+    public SchnorrSignature signMessage(FieldElement message, FieldElement[] domainTag) throws SchnorrSignatureException {
+        return nativeSignMessageWithDomainTag(message, domainTag);
+    }
+
+    private native SchnorrSignature nativeSignMessageWithBytesDomainTag(FieldElement message, byte[] domainTag) throws SchnorrSignatureException;
+
+    public SchnorrSignature signMessage(FieldElement message, byte[] domainTag) throws SchnorrSignatureException {
+        return nativeSignMessageWithBytesDomainTag(message, domainTag);
+    }
+
+    private native SchnorrPreSignature nativePreSignMessage(FieldElement message, SchnorrPublicKey adaptorPoint) throws SchnorrSignatureException;
+
+    public SchnorrPreSignature preSignMessage(FieldElement message, SchnorrPublicKey adaptorPoint) throws SchnorrSignatureException {
+        return nativePreSignMessage(message, adaptorPoint);
+    }
+
+    private native SchnorrCompactSignature nativeSignMessageCompact(FieldElement message) throws SchnorrSignatureException;
+
+    public SchnorrCompactSignature signMessageCompact(FieldElement message) throws SchnorrSignatureException {
+        return nativeSignMessageCompact(message);
+    }
+
     public SchnorrSecretKey getSecretKey() {
         return this.secretKey;
     }
@@ -0,0 +1,124 @@
+package io.horizen.common.schnorrnative;
+
+import io.horizen.common.librustsidechains.*;
+
+/*
+ * A Schnorr pre-signature under an adaptor point T: it can be adapted into a regular
+ * SchnorrSignature only by who knows the secret of T, and the secret of T can be
+ * extracted from the pre-signature and the adapted signature.
+ */
+public class SchnorrPreSignature implements AutoCloseable
+{
+  public static final int PRE_SIGNATURE_LENGTH;
+
+  private long preSignaturePointer;
+
+  private static native int nativeGetPreSignatureSize();
+
+  static {
+    Library.load();
+    PRE_SIGNATURE_LENGTH = nativeGetPreSignatureSize();
+  }
+
+  private SchnorrPreSignature(long preSignaturePointer) {
+    if (preSignaturePointer == 0)
+      throw new IllegalArgumentException("Pre-signature pointer must be not null.");
+    this.preSignaturePointer = preSignaturePointer;
+  }
+
+  private native byte[] nativeSerializePreSignature();
+
+  private static native SchnorrPreSignature nativeDeserializePreSignature(byte[] preSignatureBytes, boolean checkPreSignature, boolean strict) throws DeserializationException;
+
+  private native void nativeFreePreSignature();
+
+  public static SchnorrPreSignature deserialize(byte[] preSignatureBytes, boolean checkPreSignature) throws DeserializationException {
+    if (preSignatureBytes.length != PRE_SIGNATURE_LENGTH)
+      throw new IllegalArgumentException(String.format("Incorrect pre-signature length, %d expected, %d found", PRE_SIGNATURE_LENGTH, preSignatureBytes.length));
+
+    return nativeDeserializePreSignature(preSignatureBytes, checkPreSignature, false);
+  }
+
+  public static SchnorrPreSignature deserialize(byte[] preSignatureBytes) throws DeserializationException {
+    return deserialize(preSignatureBytes, true);
+  }
+
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static SchnorrPreSignature deserializeStrict(byte[] preSignatureBytes, boolean checkPreSignature) throws DeserializationException {
+    return nativeDeserializePreSignature(preSignatureBytes, checkPreSignature, true);
+  }
+
+  public byte[] serializePreSignature() {
+    if (preSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr pre-signature was freed.");
+
+    return nativeSerializePreSignature();
+  }
+
+  private native boolean nativeIsValidPreSignature(); // jni call to Rust impl
+
+  public boolean isValidPreSignature() {
+    if (preSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr pre-signature was freed.");
+
+    return nativeIsValidPreSignature();
+  }
+
+  private native SchnorrSignature nativeAdapt(SchnorrSecretKey adaptorSecret) throws SchnorrSignatureException;
+
+  /*
+   * Adapt this pre-signature into a regular SchnorrSignature using the secret of the adaptor point.
+   */
+  public SchnorrSignature adapt(SchnorrSecretKey adaptorSecret) throws SchnorrSignatureException {
+    if (preSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr pre-signature was freed.");
+
+    return nativeAdapt(adaptorSecret);
+  }
+
+  private native SchnorrSecretKey nativeExtractSecret(SchnorrSignature signature, SchnorrPublicKey adaptorPoint) throws SchnorrSignatureException;
+
+  /*
+   * Extract the secret of `adaptorPoint` from this pre-signature and the `signature` adapted from it.
+   */
+  public SchnorrSecretKey extractSecret(SchnorrSignature signature, SchnorrPublicKey adaptorPoint) throws SchnorrSignatureException {
+    if (preSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr pre-signature was freed.");
+
+    return nativeExtractSecret(signature, adaptorPoint);
+  }
+
+  public void freePreSignature() {
+    if (preSignaturePointer != 0) {
+      nativeFreePreSignature();
+      preSignaturePointer = 0;
+    }
+  }
+
+  private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    if (preSignaturePointer == 0)
+      throw new IllegalStateException("Schnorr pre-signature was freed.");
+
+    return nativeSerializeEnveloped(withChecksum);
+  }
+
+  private static native SchnorrPreSignature nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static SchnorrPreSignature deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static SchnorrPreSignature deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
+  @Override
+  public void close() {
+    freePreSignature();
+  }
+}
@@ -1,6 +1,7 @@
 package io.horizen.common.schnorrnative;
 
 import io.horizen.common.librustsidechains.*;
+import java.io.IOException;
 
 public class SchnorrPublicKey implements AutoCloseable
 {
@@ -22,13 +23,13 @@ public class SchnorrPublicKey implements AutoCloseable
     this.publicKeyPointer = publicKeyPointer;
   }
 
-  private static native SchnorrPublicKey nativeDeserializePublicKey(byte[] publicKeyBytes, boolean checkPublicKey, boolean compressed) throws DeserializationException;
+  private static native SchnorrPublicKey nativeDeserializePublicKey(byte[] publicKeyBytes, boolean checkPublicKey, boolean compressed, boolean strict) throws DeserializationException;
 
   public static SchnorrPublicKey deserialize(byte[] publicKeyBytes, boolean checkPublicKey, boolean compressed) throws DeserializationException {
     if (publicKeyBytes.length != PUBLIC_KEY_LENGTH)
       throw new IllegalArgumentException(String.format("Incorrect public key length, %d expected, %d found", PUBLIC_KEY_LENGTH, publicKeyBytes.length));
 
-    return nativeDeserializePublicKey(publicKeyBytes, checkPublicKey, compressed);
+    return nativeDeserializePublicKey(publicKeyBytes, checkPublicKey, compressed, false);
   }
 
   public static SchnorrPublicKey deserialize(byte[] publicKeyBytes, boolean checkPublicKey) throws DeserializationException {
@@ -39,6 +40,81 @@ public class SchnorrPublicKey implements AutoCloseable
     return deserialize(publicKeyBytes, true, true);
   }
 
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static SchnorrPublicKey deserializeStrict(byte[] publicKeyBytes, boolean checkPublicKey, boolean compressed) throws DeserializationException {
+    return nativeDeserializePublicKey(publicKeyBytes, checkPublicKey, compressed, true);
+  }
+
+  private native void nativeWriteToFile(String filePath, boolean compressed) throws IOException;
+
+  /**
+   * Write this object to the file at "filePath". The write is atomic: in case of failure
+   * the file is either left untouched or holds the whole serialized object.
+   */
+  public void writeToFile(String filePath, boolean compressed) throws IOException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    nativeWriteToFile(filePath, compressed);
+  }
+
+  private static native SchnorrPublicKey nativeReadFromFile(String filePath, boolean checked, boolean compressed) throws DeserializationException;
+
+  /**
+   * Read a public key saved by writeToFile() with the same "compressed" flag. If "checked" is set,
+   * a point not on the curve or outside the prime order subgroup is rejected. Bytes following the
+   * key are rejected with TRAILING_BYTES, a missing or unreadable file with IO.
+   */
+  public static SchnorrPublicKey readFromFile(String filePath, boolean checked, boolean compressed) throws DeserializationException {
+    return nativeReadFromFile(filePath, checked, compressed);
+  }
+
+  private native String nativeToHex();
+
+  /**
+   * Encode this object as the lowercase hex string of its compressed serialization.
+   */
+  public String toHex() {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeToHex();
+  }
+
+  private static native SchnorrPublicKey nativeFromHex(String hex) throws DeserializationException;
+
+  /**
+   * Decode an object from a hex string produced by toHex(), performing semantic checks on it.
+   */
+  public static SchnorrPublicKey fromHex(String hex) throws DeserializationException {
+    return nativeFromHex(hex);
+  }
+
+  private native String nativeToJson();
+
+  /**
+   * Encode this public key as JSON: a string holding the hex encoding of the compressed point.
+   */
+  public String toJson() {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeToJson();
+  }
+
+  private static native SchnorrPublicKey nativeFromJson(String json) throws DeserializationException;
+
+  /**
+   * Decode a public key from the output of toJson(). The point must be on the curve and in
+   * the prime order subgroup.
+   */
+  public static SchnorrPublicKey fromJson(String json) throws DeserializationException {
+    return nativeFromJson(json);
+  }
+
   private native byte[] nativeSerializePublicKey(boolean compressed);
 
 
@@ -73,6 +149,60 @@ public class SchnorrPublicKey implements AutoCloseable
     return nativeVerifySignature(signature, message);
   }
 
+  private native boolean nativeVerifyFieldElementsSignature(SchnorrSignature signature, FieldElement[] message) throws SchnorrSignatureException;
+
+  public boolean verifySignature(SchnorrSignature signature, FieldElement[] message) throws SchnorrSignatureException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeVerifyFieldElementsSignature(signature, message);
+  }
+
+  private native boolean nativeVerifyBytesSignature(SchnorrSignature signature, byte[] message) throws SchnorrSignatureException;
+
+  public boolean verifySignature(SchnorrSignature signature, byte[] message) throws SchnorrSignatureException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeVerifyBytesSignature(signature, message);
+  }
+
+  private native boolean nativeVerifySignatureWithDomainTag(SchnorrSignature signature, FieldElement message, FieldElement[] domainTag) throws SchnorrSignatureException;
+
+  public boolean verifySignature(SchnorrSignature signature, FieldElement message, FieldElement[] domainTag) throws SchnorrSignatureException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeVerifySignatureWithDomainTag(signature, message, domainTag);
+  }
+
+  private native boolean nativeVerifySignatureWithBytesDomainTag(SchnorrSignature signature, FieldElement message, byte[] domainTag) throws SchnorrSignatureException;
+
+  public boolean verifySignature(SchnorrSignature signature, FieldElement message, byte[] domainTag) throws SchnorrSignatureException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeVerifySignatureWithBytesDomainTag(signature, message, domainTag);
+  }
+
+  private native boolean nativeVerifyPreSignature(SchnorrPreSignature preSignature, FieldElement message, SchnorrPublicKey adaptorPoint) throws SchnorrSignatureException;
+
+  public boolean verifyPreSignature(SchnorrPreSignature preSignature, FieldElement message, SchnorrPublicKey adaptorPoint) throws SchnorrSignatureException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeVerifyPreSignature(preSignature, message, adaptorPoint);
+  }
+
+  private native boolean nativeVerifyCompactSignature(SchnorrCompactSignature compactSignature, FieldElement message) throws SchnorrSignatureException;
+
+  public boolean verifyCompactSignature(SchnorrCompactSignature compactSignature, FieldElement message) throws SchnorrSignatureException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeVerifyCompactSignature(compactSignature, message);
+  }
+
   public boolean verifyKey() {
     if (publicKeyPointer == 0)
       throw new IllegalStateException("Public key was freed.");
@@ -80,6 +210,29 @@ public class SchnorrPublicKey implements AutoCloseable
     return nativeVerifyKey();
   }
 
+  private native byte[] nativeSerializeEnveloped(boolean compressed, boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean compressed, boolean withChecksum) {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeSerializeEnveloped(compressed, withChecksum);
+  }
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    return serializeEnveloped(true, withChecksum);
+  }
+
+  private static native SchnorrPublicKey nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static SchnorrPublicKey deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static SchnorrPublicKey deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
   @Override
   public void close() {
     freePublicKey();
@@ -1,6 +1,8 @@
 package io.horizen.common.schnorrnative;
 
 import io.horizen.common.librustsidechains.*;
+import java.io.IOException;
+import java.util.Arrays;
 
 public class SchnorrSecretKey implements AutoCloseable
 {
@@ -20,13 +22,44 @@ public class SchnorrSecretKey implements AutoCloseable
         this.secretKeyPointer = secretKeyPointer;
     }
 
-    private static native SchnorrSecretKey nativeDeserializeSecretKey(byte[] secretKeyBytes) throws DeserializationException;
+    private static native SchnorrSecretKey nativeDeserializeSecretKey(byte[] secretKeyBytes, boolean strict) throws DeserializationException;
 
     public static SchnorrSecretKey deserialize(byte[] secretKeyBytes) throws DeserializationException {
         if (secretKeyBytes.length != SECRET_KEY_LENGTH)
             throw new IllegalArgumentException(String.format("Incorrect secret key length, %d expected, %d found", SECRET_KEY_LENGTH, secretKeyBytes.length));
 
-        return nativeDeserializeSecretKey(secretKeyBytes);
+        return nativeDeserializeSecretKey(secretKeyBytes, false);
+    }
+
+    /**
+     * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+     * if the input holds more bytes than the ones of the serialized object.
+     */
+    public static SchnorrSecretKey deserializeStrict(byte[] secretKeyBytes) throws DeserializationException {
+        return nativeDeserializeSecretKey(secretKeyBytes, true);
+    }
+
+    private native void nativeWriteToFile(String filePath) throws IOException;
+
+    /**
+     * Write this object to the file at "filePath". The write is atomic: in case of failure
+     * the file is either left untouched or holds the whole serialized object.
+     */
+    public void writeToFile(String filePath) throws IOException {
+        if (secretKeyPointer == 0)
+            throw new IllegalStateException("Secret key was freed.");
+
+        nativeWriteToFile(filePath);
+    }
+
+    private static native SchnorrSecretKey nativeReadFromFile(String filePath) throws DeserializationException;
+
+    /**
+     * Read a secret key saved by writeToFile(). The file must hold nothing but the key,
+     * and any failure in accessing it is reported with the IO error code.
+     */
+    public static SchnorrSecretKey readFromFile(String filePath) throws DeserializationException {
+        return nativeReadFromFile(filePath);
     }
 
     private native byte[] nativeSerializeSecretKey();
@@ -57,6 +90,52 @@ public class SchnorrSecretKey implements AutoCloseable
         return nativeGetPublicKey();
     }
 
+    private native boolean nativeEquals(SchnorrSecretKey other);
+
+    /*
+     * Compare this instance with `o` in constant time, to avoid leaking information
+     * about the secret data through timing side channels.
+     */
+    @Override
+    public boolean equals(Object o) {
+        if (o == this)
+            return true;
+
+        if (!(o instanceof SchnorrSecretKey))
+            return false;
+
+        if (secretKeyPointer == 0)
+            throw new IllegalStateException("Secret key was freed.");
+
+        return nativeEquals((SchnorrSecretKey) o);
+    }
+
+    /*
+     * Derived from the public key, so that equal secret keys have equal hash codes
+     * without exposing anything about the secret data.
+     */
+    @Override
+    public int hashCode() {
+        try (SchnorrPublicKey publicKey = getPublicKey()) {
+            return Arrays.hashCode(publicKey.serializePublicKey());
+        }
+    }
+
+    private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+    public byte[] serializeEnveloped(boolean withChecksum) {
+        if (secretKeyPointer == 0)
+            throw new IllegalStateException("Secret key was freed.");
+
+        return nativeSerializeEnveloped(withChecksum);
+    }
+
+    private static native SchnorrSecretKey nativeDeserializeEnveloped(byte[] envelopeBytes) throws DeserializationException;
+
+    public static SchnorrSecretKey deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+        return nativeDeserializeEnveloped(envelopeBytes);
+    }
+
     @Override
     public void close() {
         freeSecretKey();
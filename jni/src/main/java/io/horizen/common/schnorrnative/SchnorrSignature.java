@@ -1,6 +1,8 @@
 package io.horizen.common.schnorrnative;
 
 import io.horizen.common.librustsidechains.*;
+import java.io.IOException;
+import java.util.Arrays;
 
 public class SchnorrSignature implements AutoCloseable
 {
@@ -27,7 +29,7 @@ public class SchnorrSignature implements AutoCloseable
 
   private native byte[] nativeSerializeSignature();
 
-  private static native SchnorrSignature nativeDeserializeSignature(byte[] signatureBytes, boolean checkSignature) throws DeserializationException;
+  private static native SchnorrSignature nativeDeserializeSignature(byte[] signatureBytes, boolean checkSignature, boolean strict) throws DeserializationException;
 
   private native void nativefreeSignature();
 
@@ -35,13 +37,87 @@ public class SchnorrSignature implements AutoCloseable
     if (signatureBytes.length != SIGNATURE_LENGTH)
       throw new IllegalArgumentException(String.format("Incorrect signature length, %d expected, %d found", SIGNATURE_LENGTH, signatureBytes.length));
 
-    return nativeDeserializeSignature(signatureBytes, checkSignature);
+    return nativeDeserializeSignature(signatureBytes, checkSignature, false);
   }
 
   public static SchnorrSignature deserialize(byte[] signatureBytes) throws DeserializationException {
     return deserialize(signatureBytes, true);
   }
 
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static SchnorrSignature deserializeStrict(byte[] signatureBytes, boolean checkSignature) throws DeserializationException {
+    return nativeDeserializeSignature(signatureBytes, checkSignature, true);
+  }
+
+  private native void nativeWriteToFile(String filePath) throws IOException;
+
+  /**
+   * Write this object to the file at "filePath". The write is atomic: in case of failure
+   * the file is either left untouched or holds the whole serialized object.
+   */
+  public void writeToFile(String filePath) throws IOException {
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Schnorr signature was freed.");
+
+    nativeWriteToFile(filePath);
+  }
+
+  private static native SchnorrSignature nativeReadFromFile(String filePath, boolean checked) throws DeserializationException;
+
+  /**
+   * Read a signature saved by writeToFile(), checking it if "checked" is set.
+   * The file must hold exactly one signature; file system errors are reported as IO.
+   */
+  public static SchnorrSignature readFromFile(String filePath, boolean checked) throws DeserializationException {
+    return nativeReadFromFile(filePath, checked);
+  }
+
+  private native String nativeToHex();
+
+  /**
+   * Encode this object as the lowercase hex string of its compressed serialization.
+   */
+  public String toHex() {
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Schnorr signature was freed.");
+
+    return nativeToHex();
+  }
+
+  private static native SchnorrSignature nativeFromHex(String hex) throws DeserializationException;
+
+  /**
+   * Decode an object from a hex string produced by toHex(), performing semantic checks on it.
+   */
+  public static SchnorrSignature fromHex(String hex) throws DeserializationException {
+    return nativeFromHex(hex);
+  }
+
+  private native String nativeToJson();
+
+  /**
+   * Encode this signature as a JSON object with the hex encoded "e" and "s" field elements.
+   */
+  public String toJson() {
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Schnorr signature was freed.");
+
+    return nativeToJson();
+  }
+
+  private static native SchnorrSignature nativeFromJson(String json) throws DeserializationException;
+
+  /**
+   * Decode a signature from a JSON object with "e" and "s" fields, as produced by toJson(),
+   * and check that both are valid field elements.
+   */
+  public static SchnorrSignature fromJson(String json) throws DeserializationException {
+    return nativeFromJson(json);
+  }
+
   public byte[] serializeSignature() {
     return nativeSerializeSignature();
   }
@@ -62,6 +138,50 @@ public class SchnorrSignature implements AutoCloseable
     }
   }
 
+  private native boolean nativeEquals(SchnorrSignature other);
+
+  /*
+   * Compare this instance with `o` in constant time, to avoid leaking information
+   * about the secret data through timing side channels.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (o == this)
+      return true;
+
+    if (!(o instanceof SchnorrSignature))
+      return false;
+
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Schnorr signature was freed.");
+
+    return nativeEquals((SchnorrSignature) o);
+  }
+
+  @Override
+  public int hashCode() {
+    return Arrays.hashCode(serializeSignature());
+  }
+
+  private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    if (signaturePointer == 0)
+      throw new IllegalStateException("Schnorr signature was freed.");
+
+    return nativeSerializeEnveloped(withChecksum);
+  }
+
+  private static native SchnorrSignature nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static SchnorrSignature deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static SchnorrSignature deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
   @Override
   public void close() {
     freeSignature();
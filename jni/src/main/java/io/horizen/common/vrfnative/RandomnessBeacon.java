@@ -0,0 +1,173 @@
+package io.horizen.common.vrfnative;
+
+import io.horizen.common.librustsidechains.*;
+
+/*
+ * Randomness beacon deriving epoch nonces from the (verified) VRF outputs of the blocks.
+ * During an epoch the VRF outputs are absorbed into a rolling accumulator; at the epoch
+ * boundary the accumulator, chained with the current nonce, gives the nonce of the next epoch.
+ */
+public class RandomnessBeacon implements AutoCloseable
+{
+  public static final int BEACON_LENGTH;
+
+  private long beaconPointer;
+
+  private static native int nativeGetBeaconSize();
+
+  static {
+    Library.load();
+    BEACON_LENGTH = nativeGetBeaconSize();
+  }
+
+  private RandomnessBeacon(long beaconPointer) {
+    if (beaconPointer == 0)
+      throw new IllegalArgumentException("Beacon pointer must be not null.");
+    this.beaconPointer = beaconPointer;
+  }
+
+  private static native RandomnessBeacon nativeCreate(FieldElement initialNonce);
+
+  /*
+   * Create a beacon at epoch 0, whose nonce is initialNonce (e.g. derived from the genesis block).
+   */
+  public static RandomnessBeacon create(FieldElement initialNonce) {
+    return nativeCreate(initialNonce);
+  }
+
+  private native byte[] nativeSerializeBeacon();
+
+  private static native RandomnessBeacon nativeDeserializeBeacon(byte[] beaconBytes, boolean checkBeacon, boolean strict) throws DeserializationException;
+
+  private native void nativeFreeBeacon();
+
+  public static RandomnessBeacon deserialize(byte[] beaconBytes, boolean checkBeacon) throws DeserializationException {
+    if (beaconBytes.length != BEACON_LENGTH)
+      throw new IllegalArgumentException(String.format("Incorrect beacon length, %d expected, %d found", BEACON_LENGTH, beaconBytes.length));
+
+    return nativeDeserializeBeacon(beaconBytes, checkBeacon, false);
+  }
+
+  public static RandomnessBeacon deserialize(byte[] beaconBytes) throws DeserializationException {
+    return deserialize(beaconBytes, true);
+  }
+
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static RandomnessBeacon deserializeStrict(byte[] beaconBytes, boolean checkBeacon) throws DeserializationException {
+    return nativeDeserializeBeacon(beaconBytes, checkBeacon, true);
+  }
+
+  public byte[] serializeBeacon() {
+    if (beaconPointer == 0)
+      throw new IllegalStateException("Beacon was freed.");
+
+    return nativeSerializeBeacon();
+  }
+
+  private native boolean nativeIsValidBeacon();
+
+  public boolean isValidBeacon() {
+    if (beaconPointer == 0)
+      throw new IllegalStateException("Beacon was freed.");
+
+    return nativeIsValidBeacon();
+  }
+
+  private native void nativeAbsorb(FieldElement vrfOutput) throws VRFException;
+
+  /*
+   * Absorb the VRF output of a block of the current epoch. The VRF output is expected
+   * to be verified beforehand, e.g. through VRFPublicKey.proofToHash().
+   */
+  public void absorb(FieldElement vrfOutput) throws VRFException {
+    if (beaconPointer == 0)
+      throw new IllegalStateException("Beacon was freed.");
+
+    nativeAbsorb(vrfOutput);
+  }
+
+  private native FieldElement nativeEndEpoch() throws VRFException;
+
+  /*
+   * Close the current epoch, returning the nonce of the next one.
+   */
+  public FieldElement endEpoch() throws VRFException {
+    if (beaconPointer == 0)
+      throw new IllegalStateException("Beacon was freed.");
+
+    return nativeEndEpoch();
+  }
+
+  private native FieldElement nativeGetRollingNonce();
+
+  /*
+   * Return the value of the accumulator after the last absorbed VRF output.
+   */
+  public FieldElement getRollingNonce() {
+    if (beaconPointer == 0)
+      throw new IllegalStateException("Beacon was freed.");
+
+    return nativeGetRollingNonce();
+  }
+
+  private native FieldElement nativeGetEpochNonce();
+
+  public FieldElement getEpochNonce() {
+    if (beaconPointer == 0)
+      throw new IllegalStateException("Beacon was freed.");
+
+    return nativeGetEpochNonce();
+  }
+
+  private native long nativeGetEpoch();
+
+  public long getEpoch() {
+    if (beaconPointer == 0)
+      throw new IllegalStateException("Beacon was freed.");
+
+    return nativeGetEpoch();
+  }
+
+  private native long nativeGetOutputsCount();
+
+  public long getOutputsCount() {
+    if (beaconPointer == 0)
+      throw new IllegalStateException("Beacon was freed.");
+
+    return nativeGetOutputsCount();
+  }
+
+  public void freeBeacon() {
+    if (beaconPointer != 0) {
+      nativeFreeBeacon();
+      beaconPointer = 0;
+    }
+  }
+
+  private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    if (beaconPointer == 0)
+      throw new IllegalStateException("Beacon was freed.");
+
+    return nativeSerializeEnveloped(withChecksum);
+  }
+
+  private static native RandomnessBeacon nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static RandomnessBeacon deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static RandomnessBeacon deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
+  @Override
+  public void close() {
+    freeBeacon();
+  }
+}
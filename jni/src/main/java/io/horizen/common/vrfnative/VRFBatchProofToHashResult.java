@@ -0,0 +1,46 @@
+package io.horizen.common.vrfnative;
+
+import io.horizen.common.librustsidechains.FieldElement;
+import io.horizen.common.librustsidechains.Library;
+
+public class VRFBatchProofToHashResult implements AutoCloseable {
+    private FieldElement[] vrfOutputs;
+    private int[] failingIndices;
+
+    static {
+        Library.load();
+    }
+
+    public VRFBatchProofToHashResult(FieldElement[] vrfOutputs, int[] failingIndices) {
+        this.vrfOutputs = vrfOutputs;
+        this.failingIndices = failingIndices;
+    }
+
+    /*
+     * Returns true if all the VRF proofs have been successfully verified.
+     */
+    public boolean isValid() {
+        return this.failingIndices.length == 0;
+    }
+
+    /*
+     * Returns the VRF outputs, in the same order of the verified proofs,
+     * or an empty array if at least one of the proofs is invalid.
+     */
+    public FieldElement[] getVRFOutputs() {
+        return this.vrfOutputs;
+    }
+
+    /*
+     * Returns the indices of the invalid proofs, if any.
+     */
+    public int[] getFailingIndices() {
+        return this.failingIndices;
+    }
+
+    @Override
+    public void close() {
+        for (FieldElement vrfOutput: this.vrfOutputs)
+            vrfOutput.close();
+    }
+}
@@ -0,0 +1,16 @@
+package io.horizen.common.vrfnative;
+
+/**
+ * Thrown when a VRF operation fails for reasons not related to the validity of the input data.
+ */
+public class VRFInternalException extends VRFException {
+
+    public VRFInternalException(String message) {
+        super(message);
+    }
+
+    public VRFInternalException(String message, Throwable cause) {
+        super(message, cause);
+    }
+
+}
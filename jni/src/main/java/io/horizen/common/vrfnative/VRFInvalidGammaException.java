@@ -0,0 +1,16 @@
+package io.horizen.common.vrfnative;
+
+/**
+ * Thrown when the gamma point of a VRF proof is malformed.
+ */
+public class VRFInvalidGammaException extends VRFException {
+
+    public VRFInvalidGammaException(String message) {
+        super(message);
+    }
+
+    public VRFInvalidGammaException(String message, Throwable cause) {
+        super(message, cause);
+    }
+
+}
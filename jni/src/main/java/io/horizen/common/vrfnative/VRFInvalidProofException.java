@@ -0,0 +1,16 @@
+package io.horizen.common.vrfnative;
+
+/**
+ * Thrown when a well formed VRF proof doesn't verify.
+ */
+public class VRFInvalidProofException extends VRFException {
+
+    public VRFInvalidProofException(String message) {
+        super(message);
+    }
+
+    public VRFInvalidProofException(String message, Throwable cause) {
+        super(message, cause);
+    }
+
+}
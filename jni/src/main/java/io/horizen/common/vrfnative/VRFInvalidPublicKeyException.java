@@ -0,0 +1,16 @@
+package io.horizen.common.vrfnative;
+
+/**
+ * Thrown when the public key is not a valid point of the prime order subgroup.
+ */
+public class VRFInvalidPublicKeyException extends VRFException {
+
+    public VRFInvalidPublicKeyException(String message) {
+        super(message);
+    }
+
+    public VRFInvalidPublicKeyException(String message, Throwable cause) {
+        super(message, cause);
+    }
+
+}
@@ -34,6 +34,42 @@ public class VRFKeyPair implements AutoCloseable {
         return nativeProve(message);
     }
 
+    private native VRFProveResult nativeProveFieldElements(FieldElement[] message) throws VRFException;
+
+    /*
+     * Prove a message made of multiple field elements, hashed into the single field element
+     * actually given as input to the VRF.
+     */
+    public VRFProveResult prove(FieldElement[] message) throws VRFException {
+        return nativeProveFieldElements(message);
+    }
+
+    private native VRFProveResult nativeProveBytes(byte[] message) throws VRFException;
+
+    /*
+     * Prove an arbitrary byte message, hashed into the single field element actually given
+     * as input to the VRF.
+     */
+    public VRFProveResult prove(byte[] message) throws VRFException {
+        return nativeProveBytes(message);
+    }
+
+    private native VRFProveResult nativeProveDeterministic(FieldElement message) throws VRFException;
+
+    /*
+     * Same as prove(), but with the nonce derived from the key pair and the message,
+     * thus the same inputs always give the same proof.
+     */
+    public VRFProveResult proveDeterministic(FieldElement message) throws VRFException {
+        return nativeProveDeterministic(message);
+    }
+
+    private native VRFProveResult nativeProveWithParams(FieldElement message, VRFParams params) throws VRFException;
+
+    public VRFProveResult prove(FieldElement message, VRFParams params) throws VRFException {
+        return nativeProveWithParams(message, params);
+    }
+
     public VRFSecretKey getSecretKey() {
         return this.secretKey;
     }
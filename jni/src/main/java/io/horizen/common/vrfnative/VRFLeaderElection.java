@@ -0,0 +1,47 @@
+package io.horizen.common.vrfnative;
+
+import io.horizen.common.librustsidechains.*;
+
+/*
+ * Ouroboros Praos-like slot leader election: a forger owning a fraction alpha of the total stake
+ * is eligible for a slot if its (verified) VRF output for that slot is below 1 - (1 - f)^alpha,
+ * f being the active slot coefficient, expressed as activeSlotCoeffNum / activeSlotCoeffDen.
+ */
+public class VRFLeaderElection
+{
+  // Number of fractional bits of the fixed-point threshold returned by getThreshold()
+  public static final int THRESHOLD_FRAC_BITS;
+
+  private static native int nativeGetThresholdFracBits();
+
+  static {
+    Library.load();
+    THRESHOLD_FRAC_BITS = nativeGetThresholdFracBits();
+  }
+
+  private VRFLeaderElection() {}
+
+  private static void checkParams(long stake, long totalStake, long activeSlotCoeffNum, long activeSlotCoeffDen) {
+    if (stake < 0 || totalStake <= 0 || stake > totalStake)
+      throw new IllegalArgumentException(String.format("Invalid stake: %d, total stake: %d", stake, totalStake));
+
+    if (activeSlotCoeffNum <= 0 || activeSlotCoeffNum >= activeSlotCoeffDen)
+      throw new IllegalArgumentException(String.format("Active slot coefficient must be in (0, 1). Found %d/%d", activeSlotCoeffNum, activeSlotCoeffDen));
+  }
+
+  private static native long nativeGetThreshold(long stake, long totalStake, long activeSlotCoeffNum, long activeSlotCoeffDen) throws VRFException;
+
+  public static long getThreshold(long stake, long totalStake, long activeSlotCoeffNum, long activeSlotCoeffDen) throws VRFException {
+    checkParams(stake, totalStake, activeSlotCoeffNum, activeSlotCoeffDen);
+
+    return nativeGetThreshold(stake, totalStake, activeSlotCoeffNum, activeSlotCoeffDen);
+  }
+
+  private static native boolean nativeIsSlotLeader(FieldElement vrfOutput, long stake, long totalStake, long activeSlotCoeffNum, long activeSlotCoeffDen) throws VRFException;
+
+  public static boolean isSlotLeader(FieldElement vrfOutput, long stake, long totalStake, long activeSlotCoeffNum, long activeSlotCoeffDen) throws VRFException {
+    checkParams(stake, totalStake, activeSlotCoeffNum, activeSlotCoeffDen);
+
+    return nativeIsSlotLeader(vrfOutput, stake, totalStake, activeSlotCoeffNum, activeSlotCoeffDen);
+  }
+}
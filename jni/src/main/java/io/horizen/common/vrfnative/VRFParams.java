@@ -0,0 +1,84 @@
+package io.horizen.common.vrfnative;
+
+import io.horizen.common.librustsidechains.Library;
+
+/*
+ * Parameters of the group hash used by the VRF: a generator is derived from each of the tags,
+ * with the given personalization. Proofs computed with some parameters only verify with the same ones.
+ * The group hash supports two window configurations: DEFAULT_WINDOW_SIZE (DEFAULT_NUM_WINDOWS windows)
+ * and SMALL_WINDOW_SIZE (SMALL_NUM_WINDOWS windows).
+ */
+public class VRFParams implements AutoCloseable
+{
+  public static final int PERSONALIZATION_LENGTH = 8;
+  public static final int DEFAULT_WINDOW_SIZE = 128;
+  public static final int DEFAULT_NUM_WINDOWS = 2;
+  public static final int SMALL_WINDOW_SIZE = 64;
+  public static final int SMALL_NUM_WINDOWS = 4;
+
+  private long paramsPointer;
+
+  static {
+    Library.load();
+  }
+
+  private VRFParams(long paramsPointer) {
+    if (paramsPointer == 0)
+      throw new IllegalArgumentException("Params pointer must be not null.");
+    this.paramsPointer = paramsPointer;
+  }
+
+  private static native VRFParams nativeGetDefault();
+
+  /*
+   * Return the parameters used by VRFKeyPair.prove(message) and VRFPublicKey.proofToHash(proof, message).
+   */
+  public static VRFParams getDefault() {
+    return nativeGetDefault();
+  }
+
+  private static native VRFParams nativeGenerate(byte[][] tags, byte[] personalization, int windowSize) throws VRFException;
+
+  /*
+   * Generate parameters with windows of windowSize generators (DEFAULT_WINDOW_SIZE or SMALL_WINDOW_SIZE),
+   * deriving the group hash generators of each window from one of tags (distinct tags, one for each window).
+   */
+  public static VRFParams generate(byte[][] tags, byte[] personalization, int windowSize) throws VRFException {
+    if (personalization.length != PERSONALIZATION_LENGTH)
+      throw new IllegalArgumentException(String.format("Incorrect personalization length, %d expected, %d found", PERSONALIZATION_LENGTH, personalization.length));
+
+    int numWindows;
+    if (windowSize == DEFAULT_WINDOW_SIZE)
+      numWindows = DEFAULT_NUM_WINDOWS;
+    else if (windowSize == SMALL_WINDOW_SIZE)
+      numWindows = SMALL_NUM_WINDOWS;
+    else
+      throw new IllegalArgumentException(String.format("Unsupported window size %d", windowSize));
+
+    if (tags.length != numWindows)
+      throw new IllegalArgumentException(String.format("Incorrect number of tags, %d expected, %d found", numWindows, tags.length));
+
+    return nativeGenerate(tags, personalization, windowSize);
+  }
+
+  /*
+   * Generate parameters with the default window configuration, i.e. DEFAULT_NUM_WINDOWS tags are expected.
+   */
+  public static VRFParams generate(byte[][] tags, byte[] personalization) throws VRFException {
+    return generate(tags, personalization, DEFAULT_WINDOW_SIZE);
+  }
+
+  private native void nativeFreeParams();
+
+  public void freeParams() {
+    if (paramsPointer != 0) {
+      nativeFreeParams();
+      paramsPointer = 0;
+    }
+  }
+
+  @Override
+  public void close() {
+    freeParams();
+  }
+}
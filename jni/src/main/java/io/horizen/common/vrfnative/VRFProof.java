@@ -1,7 +1,9 @@
 package io.horizen.common.vrfnative;
 
 import io.horizen.common.librustsidechains.DeserializationException;
+import io.horizen.common.librustsidechains.FieldElement;
 import io.horizen.common.librustsidechains.Library;
+import java.io.IOException;
 
 public class VRFProof implements AutoCloseable
 {
@@ -24,7 +26,7 @@ public class VRFProof implements AutoCloseable
 
   private native byte[] nativeSerializeProof(boolean compressed);
 
-  private static native VRFProof nativeDeserializeProof(byte[] proofBytes, boolean checkVRFProof, boolean compressed) throws DeserializationException;
+  private static native VRFProof nativeDeserializeProof(byte[] proofBytes, boolean checkVRFProof, boolean compressed, boolean strict) throws DeserializationException;
 
   private native void nativeFreeProof();
 
@@ -32,7 +34,7 @@ public class VRFProof implements AutoCloseable
     if (proofBytes.length != PROOF_LENGTH)
       throw new IllegalArgumentException(String.format("Incorrect proof length, %d expected, %d found", PROOF_LENGTH, proofBytes.length));
 
-    return nativeDeserializeProof(proofBytes, checkVRFProof, compressed);
+    return nativeDeserializeProof(proofBytes, checkVRFProof, compressed, false);
   }
 
   public static VRFProof deserialize(byte[] proofBytes, boolean checkVRFProof) throws DeserializationException {
@@ -43,6 +45,82 @@ public class VRFProof implements AutoCloseable
     return deserialize(proofBytes, true, true);
   }
 
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static VRFProof deserializeStrict(byte[] proofBytes, boolean checkVRFProof, boolean compressed) throws DeserializationException {
+    return nativeDeserializeProof(proofBytes, checkVRFProof, compressed, true);
+  }
+
+  private native void nativeWriteToFile(String filePath, boolean compressed) throws IOException;
+
+  /**
+   * Write this object to the file at "filePath". The write is atomic: in case of failure
+   * the file is either left untouched or holds the whole serialized object.
+   */
+  public void writeToFile(String filePath, boolean compressed) throws IOException {
+    if (proofPointer == 0)
+      throw new IllegalStateException("Proof was freed.");
+
+    nativeWriteToFile(filePath, compressed);
+  }
+
+  private static native VRFProof nativeReadFromFile(String filePath, boolean checked, boolean compressed) throws DeserializationException;
+
+  /**
+   * Read a VRF proof saved by writeToFile() with the same "compressed" flag. If "checked" is set,
+   * gamma must be a valid point of the prime order subgroup. The file must hold only the proof;
+   * I/O failures get the IO error code.
+   */
+  public static VRFProof readFromFile(String filePath, boolean checked, boolean compressed) throws DeserializationException {
+    return nativeReadFromFile(filePath, checked, compressed);
+  }
+
+  private native String nativeToHex();
+
+  /**
+   * Encode this object as the lowercase hex string of its compressed serialization.
+   */
+  public String toHex() {
+    if (proofPointer == 0)
+      throw new IllegalStateException("Proof was freed.");
+
+    return nativeToHex();
+  }
+
+  private static native VRFProof nativeFromHex(String hex) throws DeserializationException;
+
+  /**
+   * Decode an object from a hex string produced by toHex(), performing semantic checks on it.
+   */
+  public static VRFProof fromHex(String hex) throws DeserializationException {
+    return nativeFromHex(hex);
+  }
+
+  private native String nativeToJson();
+
+  /**
+   * Encode this proof as a JSON object with the hex encoded point "gamma" and field elements
+   * "c" and "s".
+   */
+  public String toJson() {
+    if (proofPointer == 0)
+      throw new IllegalStateException("Proof was freed.");
+
+    return nativeToJson();
+  }
+
+  private static native VRFProof nativeFromJson(String json) throws DeserializationException;
+
+  /**
+   * Decode a proof from the JSON object produced by toJson(). "gamma" must be a point of the
+   * prime order subgroup.
+   */
+  public static VRFProof fromJson(String json) throws DeserializationException {
+    return nativeFromJson(json);
+  }
+
 
   public byte[] serializeProof(boolean compressed) {
     if (proofPointer == 0)
@@ -64,6 +142,20 @@ public class VRFProof implements AutoCloseable
     return nativeIsValidVRFProof();
   }
 
+  private native FieldElement nativeOutputWithLabel(FieldElement message, FieldElement[] label) throws VRFException;
+
+  /*
+   * Derive from this proof the VRF output for message labeled with label. An empty label gives
+   * the default VRF output. The proof is not verified: to be used by the prover, or after a
+   * successful VRFPublicKey.proofToHash().
+   */
+  public FieldElement outputWithLabel(FieldElement message, FieldElement[] label) throws VRFException {
+    if (proofPointer == 0)
+      throw new IllegalStateException("Proof was freed.");
+
+    return nativeOutputWithLabel(message, label);
+  }
+
   public void freeProof() {
     if (proofPointer != 0) {
       nativeFreeProof();
@@ -71,6 +163,29 @@ public class VRFProof implements AutoCloseable
     }
   }
 
+  private native byte[] nativeSerializeEnveloped(boolean compressed, boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean compressed, boolean withChecksum) {
+    if (proofPointer == 0)
+      throw new IllegalStateException("Proof was freed.");
+
+    return nativeSerializeEnveloped(compressed, withChecksum);
+  }
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    return serializeEnveloped(true, withChecksum);
+  }
+
+  private static native VRFProof nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static VRFProof deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static VRFProof deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
   @Override
   public void close() {
     freeProof();
@@ -1,6 +1,7 @@
 package io.horizen.common.vrfnative;
 
 import io.horizen.common.librustsidechains.*;
+import java.io.IOException;
 
 public class VRFPublicKey implements AutoCloseable
 {
@@ -22,13 +23,13 @@ public class VRFPublicKey implements AutoCloseable
     this.publicKeyPointer = publicKeyPointer;
   }
 
-  private static native VRFPublicKey nativeDeserializePublicKey(byte[] publicKeyBytes, boolean checkPublicKey, boolean compressed) throws DeserializationException;
+  private static native VRFPublicKey nativeDeserializePublicKey(byte[] publicKeyBytes, boolean checkPublicKey, boolean compressed, boolean strict) throws DeserializationException;
 
   public static VRFPublicKey deserialize(byte[] publicKeyBytes, boolean checkPublicKey, boolean compressed) throws DeserializationException {
     if (publicKeyBytes.length != PUBLIC_KEY_LENGTH)
       throw new IllegalArgumentException(String.format("Incorrect public key length, %d expected, %d found", PUBLIC_KEY_LENGTH, publicKeyBytes.length));
 
-    return nativeDeserializePublicKey(publicKeyBytes, checkPublicKey, compressed);
+    return nativeDeserializePublicKey(publicKeyBytes, checkPublicKey, compressed, false);
   }
 
   public static VRFPublicKey deserialize(byte[] publicKeyBytes, boolean checkPublicKey) throws DeserializationException {
@@ -39,6 +40,82 @@ public class VRFPublicKey implements AutoCloseable
     return deserialize(publicKeyBytes, true, true);
   }
 
+  /**
+   * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+   * if the input holds more bytes than the ones of the serialized object.
+   */
+  public static VRFPublicKey deserializeStrict(byte[] publicKeyBytes, boolean checkPublicKey, boolean compressed) throws DeserializationException {
+    return nativeDeserializePublicKey(publicKeyBytes, checkPublicKey, compressed, true);
+  }
+
+  private native void nativeWriteToFile(String filePath, boolean compressed) throws IOException;
+
+  /**
+   * Write this object to the file at "filePath". The write is atomic: in case of failure
+   * the file is either left untouched or holds the whole serialized object.
+   */
+  public void writeToFile(String filePath, boolean compressed) throws IOException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    nativeWriteToFile(filePath, compressed);
+  }
+
+  private static native VRFPublicKey nativeReadFromFile(String filePath, boolean checked, boolean compressed) throws DeserializationException;
+
+  /**
+   * Read a VRF public key saved by writeToFile() with the same "compressed" flag. If "checked" is
+   * set, a point not on the curve fails with INVALID_POINT, one outside the prime order subgroup
+   * with NOT_IN_SUBGROUP. Bytes left after the key make the read fail; a missing file is
+   * reported with the IO error code.
+   */
+  public static VRFPublicKey readFromFile(String filePath, boolean checked, boolean compressed) throws DeserializationException {
+    return nativeReadFromFile(filePath, checked, compressed);
+  }
+
+  private native String nativeToHex();
+
+  /**
+   * Encode this object as the lowercase hex string of its compressed serialization.
+   */
+  public String toHex() {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeToHex();
+  }
+
+  private static native VRFPublicKey nativeFromHex(String hex) throws DeserializationException;
+
+  /**
+   * Decode an object from a hex string produced by toHex(), performing semantic checks on it.
+   */
+  public static VRFPublicKey fromHex(String hex) throws DeserializationException {
+    return nativeFromHex(hex);
+  }
+
+  private native String nativeToJson();
+
+  /**
+   * Encode this VRF public key as a JSON string, whose value is the same as toHex().
+   */
+  public String toJson() {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeToJson();
+  }
+
+  private static native VRFPublicKey nativeFromJson(String json) throws DeserializationException;
+
+  /**
+   * Decode a VRF public key encoded by toJson(), rejecting points not on the curve or outside
+   * the prime order subgroup.
+   */
+  public static VRFPublicKey fromJson(String json) throws DeserializationException {
+    return nativeFromJson(json);
+  }
+
   private native byte[] nativeSerializePublicKey(boolean compressed);
 
 
@@ -80,6 +157,85 @@ public class VRFPublicKey implements AutoCloseable
     return nativeProofToHash(proof, message);
   }
 
+  private native FieldElement nativeProofToHashFieldElements(VRFProof proof, FieldElement[] message) throws VRFException;
+
+  public FieldElement proofToHash(VRFProof proof, FieldElement[] message) throws VRFException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeProofToHashFieldElements(proof, message);
+  }
+
+  private native FieldElement nativeProofToHashBytes(VRFProof proof, byte[] message) throws VRFException;
+
+  public FieldElement proofToHash(VRFProof proof, byte[] message) throws VRFException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeProofToHashBytes(proof, message);
+  }
+
+  private native FieldElement nativeProofToHashWithParams(VRFProof proof, FieldElement message, VRFParams params) throws VRFException;
+
+  public FieldElement proofToHash(VRFProof proof, FieldElement message, VRFParams params) throws VRFException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeProofToHashWithParams(proof, message, params);
+  }
+
+  private native FieldElement nativeProofToHashWithLabel(VRFProof proof, FieldElement message, FieldElement[] label) throws VRFException;
+
+  /*
+   * Verify proof for message and return the VRF output labeled with label.
+   * An empty label gives the same output of proofToHash().
+   */
+  public FieldElement proofToHashWithLabel(VRFProof proof, FieldElement message, FieldElement[] label) throws VRFException {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeProofToHashWithLabel(proof, message, label);
+  }
+
+  private static native VRFBatchProofToHashResult nativeBatchProofToHash(VRFPublicKey[] publicKeys, VRFProof[] proofs, FieldElement[] messages) throws VRFException;
+
+  /*
+   * Verify proofs[i] for messages[i] under publicKeys[i], for each i, returning
+   * either all the VRF outputs or the indices of the invalid proofs.
+   * The group checks on public keys and proofs are batched, while the proofs are verified
+   * one by one, in a single native call. Failures not due to the provided data are thrown
+   * as VRFInternalException.
+   */
+  public static VRFBatchProofToHashResult batchProofToHash(VRFPublicKey[] publicKeys, VRFProof[] proofs, FieldElement[] messages) throws VRFException {
+    if (publicKeys.length != proofs.length || publicKeys.length != messages.length)
+      throw new IllegalArgumentException("Public keys, proofs and messages must have the same length");
+
+    return nativeBatchProofToHash(publicKeys, proofs, messages);
+  }
+
+  private native byte[] nativeSerializeEnveloped(boolean compressed, boolean withChecksum);
+
+  public byte[] serializeEnveloped(boolean compressed, boolean withChecksum) {
+    if (publicKeyPointer == 0)
+      throw new IllegalStateException("Public key was freed.");
+
+    return nativeSerializeEnveloped(compressed, withChecksum);
+  }
+
+  public byte[] serializeEnveloped(boolean withChecksum) {
+    return serializeEnveloped(true, withChecksum);
+  }
+
+  private static native VRFPublicKey nativeDeserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException;
+
+  public static VRFPublicKey deserializeEnveloped(byte[] envelopeBytes, boolean checked) throws DeserializationException {
+    return nativeDeserializeEnveloped(envelopeBytes, checked);
+  }
+
+  public static VRFPublicKey deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+    return deserializeEnveloped(envelopeBytes, true);
+  }
+
   @Override
   public void close() {
     freePublicKey();
@@ -1,6 +1,8 @@
 package io.horizen.common.vrfnative;
 
 import io.horizen.common.librustsidechains.*;
+import java.io.IOException;
+import java.util.Arrays;
 
 public class VRFSecretKey implements AutoCloseable
 {
@@ -21,13 +23,44 @@ public class VRFSecretKey implements AutoCloseable
         this.secretKeyPointer = secretKeyPointer;
     }
 
-    private static native VRFSecretKey nativeDeserializeSecretKey(byte[] secretKeyBytes) throws DeserializationException;
+    private static native VRFSecretKey nativeDeserializeSecretKey(byte[] secretKeyBytes, boolean strict) throws DeserializationException;
 
     public static VRFSecretKey deserialize(byte[] secretKeyBytes) throws DeserializationException {
         if (secretKeyBytes.length != SECRET_KEY_LENGTH)
             throw new IllegalArgumentException(String.format("Incorrect secret key length, %d expected, %d found", SECRET_KEY_LENGTH, secretKeyBytes.length));
 
-        return nativeDeserializeSecretKey(secretKeyBytes);
+        return nativeDeserializeSecretKey(secretKeyBytes, false);
+    }
+
+    /**
+     * Same as deserialize(), but throw a DeserializationException with TRAILING_BYTES error code
+     * if the input holds more bytes than the ones of the serialized object.
+     */
+    public static VRFSecretKey deserializeStrict(byte[] secretKeyBytes) throws DeserializationException {
+        return nativeDeserializeSecretKey(secretKeyBytes, true);
+    }
+
+    private native void nativeWriteToFile(String filePath) throws IOException;
+
+    /**
+     * Write this object to the file at "filePath". The write is atomic: in case of failure
+     * the file is either left untouched or holds the whole serialized object.
+     */
+    public void writeToFile(String filePath) throws IOException {
+        if (secretKeyPointer == 0)
+            throw new IllegalStateException("Secret key was freed.");
+
+        nativeWriteToFile(filePath);
+    }
+
+    private static native VRFSecretKey nativeReadFromFile(String filePath) throws DeserializationException;
+
+    /**
+     * Read a VRF secret key saved by writeToFile(). Nothing but the key may follow in the file;
+     * I/O failures are reported with the IO error code.
+     */
+    public static VRFSecretKey readFromFile(String filePath) throws DeserializationException {
+        return nativeReadFromFile(filePath);
     }
 
     private native byte[] nativeSerializeSecretKey();
@@ -58,6 +91,52 @@ public class VRFSecretKey implements AutoCloseable
         return nativeGetPublicKey();
     }
 
+    private native boolean nativeEquals(VRFSecretKey other);
+
+    /*
+     * Compare this instance with `o` in constant time, to avoid leaking information
+     * about the secret data through timing side channels.
+     */
+    @Override
+    public boolean equals(Object o) {
+        if (o == this)
+            return true;
+
+        if (!(o instanceof VRFSecretKey))
+            return false;
+
+        if (secretKeyPointer == 0)
+            throw new IllegalStateException("Secret key was freed.");
+
+        return nativeEquals((VRFSecretKey) o);
+    }
+
+    /*
+     * Derived from the public key, so that equal secret keys have equal hash codes
+     * without exposing anything about the secret data.
+     */
+    @Override
+    public int hashCode() {
+        try (VRFPublicKey publicKey = getPublicKey()) {
+            return Arrays.hashCode(publicKey.serializePublicKey());
+        }
+    }
+
+    private native byte[] nativeSerializeEnveloped(boolean withChecksum);
+
+    public byte[] serializeEnveloped(boolean withChecksum) {
+        if (secretKeyPointer == 0)
+            throw new IllegalStateException("Secret key was freed.");
+
+        return nativeSerializeEnveloped(withChecksum);
+    }
+
+    private static native VRFSecretKey nativeDeserializeEnveloped(byte[] envelopeBytes) throws DeserializationException;
+
+    public static VRFSecretKey deserializeEnveloped(byte[] envelopeBytes) throws DeserializationException {
+        return nativeDeserializeEnveloped(envelopeBytes);
+    }
+
     @Override
     public void close() {
         freeSecretKey();
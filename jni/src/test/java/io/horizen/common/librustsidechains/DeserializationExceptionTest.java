@@ -0,0 +1,25 @@
+package io.horizen.common.librustsidechains;
+
+import org.junit.Test;
+
+import static org.junit.Assert.*;
+
+public class DeserializationExceptionTest {
+
+    @Test
+    public void testErrorCodes() {
+        // Codes are the ones of DeserializationErrorCode on the Rust side
+        assertEquals(0, DeserializationException.ErrorCode.UNKNOWN.getCode());
+        assertEquals(4, DeserializationException.ErrorCode.NON_CANONICAL_FIELD_ELEMENT.getCode());
+        assertEquals(8, DeserializationException.ErrorCode.IO.getCode());
+        assertEquals(9, DeserializationException.ErrorCode.INVALID_POINT.getCode());
+        assertEquals(10, DeserializationException.ErrorCode.NOT_IN_SUBGROUP.getCode());
+
+        for (DeserializationException.ErrorCode errorCode : DeserializationException.ErrorCode.values())
+            assertEquals(errorCode, DeserializationException.ErrorCode.fromCode(errorCode.getCode()));
+
+        // Unknown codes
+        assertEquals(DeserializationException.ErrorCode.UNKNOWN, DeserializationException.ErrorCode.fromCode(-1));
+        assertEquals(DeserializationException.ErrorCode.UNKNOWN, DeserializationException.ErrorCode.fromCode(1000));
+    }
+}
@@ -92,6 +92,7 @@ public class FieldElementTest {
             assertFalse("Must be unable to deserialize a FieldElement over the modulus", true);
         } catch (DeserializationException fee) {
             assertTrue(fee.getMessage().contains("Attempt to deserialize a field element over the modulus"));
+            assertEquals(DeserializationException.ErrorCode.NON_CANONICAL_FIELD_ELEMENT, fee.getErrorCode());
         }
 
         // Attempt to deserialize a byte array bigger than FIELD_ELEMENT_LENGTH
@@ -103,7 +104,59 @@ public class FieldElementTest {
             assertFalse("Must be unable to deserialize a FieldElement from a byte array bigger than FIELD_ELEMENT_LENGTH", true);
         } catch (DeserializationException fee) {
             assertTrue(fee.getMessage().contains("Field element length exceeded"));
+            assertEquals(DeserializationException.ErrorCode.TRAILING_BYTES, fee.getErrorCode());
         }
 
     }
+
+    @Test
+    public void testDeserializeStrict() throws Exception {
+        try (FieldElement fe = FieldElement.createRandom(42L)) {
+            byte[] feBytes = fe.serializeFieldElement();
+
+            try (FieldElement feDeserialized = FieldElement.deserializeStrict(feBytes)) {
+                assertEquals(fe, feDeserialized);
+            }
+
+            // Padding is not accepted in strict mode
+            try {
+                FieldElement.deserializeStrict(Arrays.copyOf(feBytes, FieldElement.FIELD_ELEMENT_LENGTH - 1));
+                fail("Must be unable to deserialize a FieldElement from a byte array shorter than FIELD_ELEMENT_LENGTH");
+            } catch (DeserializationException fee) {
+                assertEquals(DeserializationException.ErrorCode.NOT_ENOUGH_BYTES, fee.getErrorCode());
+            }
+
+            // Nor are trailing bytes
+            try {
+                FieldElement.deserializeStrict(Arrays.copyOf(feBytes, FieldElement.FIELD_ELEMENT_LENGTH + 1));
+                fail("Must be unable to deserialize a FieldElement from a byte array bigger than FIELD_ELEMENT_LENGTH");
+            } catch (DeserializationException fee) {
+                assertEquals(DeserializationException.ErrorCode.TRAILING_BYTES, fee.getErrorCode());
+            }
+        }
+    }
+
+    @Test
+    public void testTextEncoding() throws Exception {
+        try (FieldElement fe = FieldElement.createRandom(42L)) {
+            String hex = fe.toHex();
+            assertEquals(2 * FieldElement.FIELD_ELEMENT_LENGTH, hex.length());
+            assertEquals("\"" + hex + "\"", fe.toJson());
+
+            try (
+                FieldElement feFromHex = FieldElement.fromHex(hex);
+                FieldElement feFromJson = FieldElement.fromJson(fe.toJson())
+            ) {
+                assertEquals(fe, feFromHex);
+                assertEquals(fe, feFromJson);
+            }
+
+            try {
+                FieldElement.fromHex(hex + "00");
+                fail("Must be unable to decode a FieldElement from a hex string with trailing bytes");
+            } catch (DeserializationException fee) {
+                assertEquals(DeserializationException.ErrorCode.TRAILING_BYTES, fee.getErrorCode());
+            }
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,57 @@
+package io.horizen.common.librustsidechains;
+
+import io.horizen.common.schnorrnative.SchnorrKeyPair;
+import io.horizen.common.schnorrnative.SchnorrPublicKey;
+import io.horizen.common.vrfnative.VRFKeyPair;
+import io.horizen.common.vrfnative.VRFPublicKey;
+import org.junit.Test;
+
+import static org.junit.Assert.*;
+
+public class KeyRotationCertificateTest {
+
+    @Test
+    public void testSchnorrKeyRotationChain() throws Exception {
+        try
+        (
+            SchnorrKeyPair keyPair0 = SchnorrKeyPair.generate();
+            SchnorrKeyPair keyPair1 = SchnorrKeyPair.generate();
+            SchnorrKeyPair keyPair2 = SchnorrKeyPair.generate();
+            KeyRotationCertificate cert1 = KeyRotationCertificate.create(keyPair0, keyPair1.getPublicKey(), 1);
+            KeyRotationCertificate cert2 = KeyRotationCertificate.create(keyPair1, keyPair2.getPublicKey(), 5);
+            KeyRotationCertificate deserializedCert1 = KeyRotationCertificate.deserialize(cert1.serializeCertificate());
+            SchnorrPublicKey currentPublicKey = KeyRotationCertificate.verifyChain(
+                    keyPair0.getPublicKey(), new KeyRotationCertificate[] { deserializedCert1, cert2 })
+        )
+        {
+            assertTrue("Certificate must be verified", cert1.verify());
+            assertEquals("Wrong key type", KeyRotationCertificate.KeyType.SCHNORR, cert1.getKeyType());
+            assertEquals("Wrong epoch", 5, cert2.getEpoch());
+            assertArrayEquals("Chain must end with the last key",
+                    keyPair2.getPublicKey().serializePublicKey(), currentPublicKey.serializePublicKey());
+
+            try {
+                KeyRotationCertificate.verifyChain(keyPair0.getPublicKey(), new KeyRotationCertificate[] { cert2, cert1 });
+                fail("Chain with certificates out of order must be rejected");
+            } catch (KeyRotationException e) {}
+        }
+    }
+
+    @Test
+    public void testVRFKeyRotation() throws Exception {
+        try
+        (
+            VRFKeyPair keyPair0 = VRFKeyPair.generate();
+            VRFKeyPair keyPair1 = VRFKeyPair.generate();
+            KeyRotationCertificate cert = KeyRotationCertificate.create(keyPair0, keyPair1.getPublicKey(), 1);
+            VRFPublicKey currentPublicKey = KeyRotationCertificate.verifyChain(
+                    keyPair0.getPublicKey(), new KeyRotationCertificate[] { cert })
+        )
+        {
+            assertTrue("Certificate must be verified", cert.verify());
+            assertEquals("Wrong key type", KeyRotationCertificate.KeyType.VRF, cert.getKeyType());
+            assertArrayEquals("Chain must end with the last key",
+                    keyPair1.getPublicKey().serializePublicKey(), currentPublicKey.serializePublicKey());
+        }
+    }
+}
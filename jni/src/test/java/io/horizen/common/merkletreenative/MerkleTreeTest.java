@@ -6,6 +6,7 @@ import org.junit.Test;
 import org.junit.Before;
 import org.junit.After;
 
+import java.util.Arrays;
 import java.util.List;
 
 import java.util.ArrayList;
@@ -159,6 +160,70 @@ public class MerkleTreeTest {
         }
     }
 
+    @Test
+    public void testTreeStreamSerializeDeserialize() throws Exception {
+
+        byte[] treeBytes;
+        FieldElement treeRoot;
+
+        try(BaseMerkleTree tree = BaseMerkleTree.init(height, numLeaves)) {
+            for (FieldElement leaf: leaves)
+                tree.append(leaf.clone());
+
+            tree.finalizeTreeInPlace();
+            treeRoot = tree.root();
+
+            ByteArrayOutputStream bos = new ByteArrayOutputStream();
+            tree.serialize(bos);
+            // Data following the tree must be left untouched by the deserialization
+            bos.write(42);
+            treeBytes = bos.toByteArray();
+        }
+
+        ByteArrayInputStream bis = new ByteArrayInputStream(treeBytes);
+        try (BaseMerkleTree treeDeserialized = BaseMerkleTree.deserialize(bis)) {
+            FieldElement expectedRoot = treeDeserialized.root();
+            assertEquals(expectedRoot, treeRoot);
+            assertEquals(42, bis.read());
+            assertEquals(-1, bis.read());
+
+            expectedRoot.freeFieldElement();
+            treeRoot.freeFieldElement();
+        }
+
+        // Truncated stream
+        try {
+            BaseMerkleTree.deserialize(new ByteArrayInputStream(Arrays.copyOf(treeBytes, treeBytes.length / 2)));
+            fail("Must be unable to deserialize a tree from a truncated stream");
+        } catch (DeserializationException ex) {
+            assertEquals(DeserializationException.ErrorCode.NOT_ENOUGH_BYTES, ex.getErrorCode());
+        }
+    }
+
+    @Test
+    public void testTreeFileSerialization() throws Exception {
+
+        File treeFile = File.createTempFile("merkle_tree", ".bin");
+
+        try(BaseMerkleTree tree = BaseMerkleTree.init(height, numLeaves)) {
+            for (FieldElement leaf: leaves)
+                tree.append(leaf.clone());
+            tree.finalizeTreeInPlace();
+
+            tree.writeToFile(treeFile.getPath());
+
+            try (
+                BaseMerkleTree treeDeserialized = BaseMerkleTree.readFromFile(treeFile.getPath());
+                FieldElement treeRoot = tree.root();
+                FieldElement expectedRoot = treeDeserialized.root()
+            ) {
+                assertEquals(expectedRoot, treeRoot);
+            }
+        } finally {
+            treeFile.delete();
+        }
+    }
+
     @Test
     public void testMerklePaths() throws Exception {
         List<FieldElement> testLeaves = new ArrayList<>();
@@ -280,4 +345,158 @@ public class MerkleTreeTest {
             leaf.freeFieldElement();
         expectedRoot.freeFieldElement();
     }
+
+    @Test
+    public void testMerklePathDeserializeStrict() throws Exception {
+        BaseMerkleTree mht = BaseMerkleTree.init(height, numLeaves);
+        for (FieldElement leaf: leaves)
+            mht.append(leaf.clone());
+        mht.finalizeTreeInPlace();
+        FieldElement mhtRoot = mht.root();
+
+        FieldBasedMerklePath path = mht.getMerklePath(0);
+        byte[] pathBytes = path.serialize();
+
+        FieldBasedMerklePath pathDeserialized = FieldBasedMerklePath.deserializeStrict(pathBytes, true);
+        assertTrue("Deserialized Merkle Path must be verified", pathDeserialized.verify(leaves.get(0), mhtRoot));
+        pathDeserialized.freeMerklePath();
+
+        // Trailing bytes are ignored by deserialize() but not by deserializeStrict()
+        byte[] oversizedPathBytes = Arrays.copyOf(pathBytes, pathBytes.length + 1);
+        FieldBasedMerklePath.deserialize(oversizedPathBytes).freeMerklePath();
+        try {
+            FieldBasedMerklePath.deserializeStrict(oversizedPathBytes, true);
+            fail("Must be unable to deserialize a Merkle Path with trailing bytes in strict mode");
+        } catch (DeserializationException ex) {
+            assertEquals(DeserializationException.ErrorCode.TRAILING_BYTES, ex.getErrorCode());
+        }
+
+        path.freeMerklePath();
+        mht.freeMerkleTree();
+        mhtRoot.freeFieldElement();
+    }
+
+    private static int indexOf(byte[] array, byte[] target) {
+        for (int i = 0; i + target.length <= array.length; i++) {
+            if (Arrays.equals(Arrays.copyOfRange(array, i, i + target.length), target))
+                return i;
+        }
+        return -1;
+    }
+
+    @Test
+    public void testTreeDeserializeChecked() throws Exception {
+        byte[] treeBytes;
+        byte[] otherRootBytes;
+
+        try(
+            BaseMerkleTree tree = BaseMerkleTree.init(height, numLeaves);
+            BaseMerkleTree otherTree = BaseMerkleTree.init(height, numLeaves)
+        ) {
+            for (FieldElement leaf: leaves)
+                tree.append(leaf.clone());
+            tree.finalizeTreeInPlace();
+            ByteArrayOutputStream bos = new ByteArrayOutputStream();
+            tree.serialize(bos);
+            treeBytes = bos.toByteArray();
+
+            for (FieldElement leaf: buildLeaves(42L)) {
+                otherTree.append(leaf);
+                leaf.freeFieldElement();
+            }
+            otherTree.finalizeTreeInPlace();
+            try (FieldElement otherRoot = otherTree.root()) {
+                otherRootBytes = otherRoot.serializeFieldElement();
+            }
+
+            try (
+                BaseMerkleTree treeDeserialized = BaseMerkleTree.deserializeStrict(treeBytes, true);
+                FieldElement treeRoot = tree.root();
+                FieldElement deserializedRoot = treeDeserialized.root()
+            ) {
+                assertEquals(treeRoot, deserializedRoot);
+            }
+
+            // Trees higher than the given bound are rejected
+            try {
+                BaseMerkleTree.deserializeStrict(treeBytes, height - 1);
+                fail("Must be unable to deserialize a tree higher than the given bound");
+            } catch (DeserializationException ex) {
+                assertEquals(DeserializationException.ErrorCode.SEMANTIC_CHECKS_FAILED, ex.getErrorCode());
+            }
+
+            // Replace the root stored in the tree with the one of another tree
+            int rootPosition;
+            try (FieldElement treeRoot = tree.root()) {
+                rootPosition = indexOf(treeBytes, treeRoot.serializeFieldElement());
+            }
+            assertTrue(rootPosition >= 0);
+            System.arraycopy(otherRootBytes, 0, treeBytes, rootPosition, otherRootBytes.length);
+        }
+
+        // The inconsistency is detected only by checked deserialization
+        BaseMerkleTree.deserializeStrict(treeBytes, false).freeMerkleTree();
+        try {
+            BaseMerkleTree.deserializeStrict(treeBytes, true);
+            fail("Must be unable to deserialize an inconsistent tree in checked mode");
+        } catch (DeserializationException ex) {
+            assertEquals(DeserializationException.ErrorCode.SEMANTIC_CHECKS_FAILED, ex.getErrorCode());
+        }
+    }
+
+    @Test
+    public void testTreeDeserializeEnvelopedTrailingBytes() throws Exception {
+        byte[] envelope;
+        try (BaseMerkleTree tree = BaseMerkleTree.init(height, numLeaves)) {
+            for (FieldElement leaf: leaves)
+                tree.append(leaf.clone());
+            tree.finalizeTreeInPlace();
+            envelope = tree.serializeEnveloped(false);
+        }
+        BaseMerkleTree.deserializeEnveloped(envelope, true).freeMerkleTree();
+
+        // Append a byte to the payload and update the payload length (little endian, at offset 8)
+        // in the header, so that the envelope itself stays valid
+        byte[] oversizedEnvelope = Arrays.copyOf(envelope, envelope.length + 1);
+        for (int i = 8; i < 12 && ++oversizedEnvelope[i] == 0; i++);
+        try {
+            BaseMerkleTree.deserializeEnveloped(oversizedEnvelope, true);
+            fail("Must be unable to deserialize an enveloped tree with trailing bytes in the payload");
+        } catch (DeserializationException ex) {
+            assertEquals(DeserializationException.ErrorCode.TRAILING_BYTES, ex.getErrorCode());
+        }
+    }
+
+    @Test
+    public void testMerklePathCompactSerialization() throws Exception {
+        BaseMerkleTree mht = BaseMerkleTree.init(height, numLeaves);
+        for (FieldElement leaf: leaves)
+            mht.append(leaf.clone());
+        mht.finalizeTreeInPlace();
+        FieldElement mhtRoot = mht.root();
+
+        for (int i = 0; i < numLeaves; i++) {
+            FieldBasedMerklePath path = mht.getMerklePath(i);
+            byte[] compactPathBytes = path.serializeCompact();
+            assertTrue("Compact path must be smaller", compactPathBytes.length < path.serialize().length);
+
+            FieldBasedMerklePath pathDeserialized = FieldBasedMerklePath.deserializeCompact(compactPathBytes);
+            assertArrayEquals(path.serialize(), pathDeserialized.serialize());
+            assertEquals(i, pathDeserialized.leafIndex());
+            assertTrue("Deserialized Merkle Path must be verified", pathDeserialized.verify(leaves.get(i), mhtRoot));
+
+            pathDeserialized.freeMerklePath();
+            path.freeMerklePath();
+        }
+
+        try {
+            FieldBasedMerklePath.deserializeCompact(new byte[] { (byte) height });
+            fail("Must be unable to deserialize a truncated compact Merkle Path");
+        } catch (DeserializationException ex) {
+            assertEquals(DeserializationException.ErrorCode.NOT_ENOUGH_BYTES, ex.getErrorCode());
+        }
+
+        mht.freeMerkleTree();
+        mhtRoot.freeFieldElement();
+    }
 }
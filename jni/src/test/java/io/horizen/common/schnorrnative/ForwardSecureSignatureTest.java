@@ -0,0 +1,64 @@
+package io.horizen.common.schnorrnative;
+
+import io.horizen.common.librustsidechains.FieldElement;
+import org.junit.Test;
+
+import static org.junit.Assert.*;
+
+public class ForwardSecureSignatureTest {
+
+    @Test
+    public void testSignAndUpdate() throws Exception {
+        try
+        (
+            ForwardSecureKeyPair keyPair = ForwardSecureKeyPair.generate(3);
+            FieldElement message = FieldElement.createRandom();
+            ForwardSecureSignature signature0 = keyPair.getSecretKey().signMessage(message)
+        )
+        {
+            ForwardSecureSecretKey secretKey = keyPair.getSecretKey();
+            ForwardSecurePublicKey publicKey = keyPair.getPublicKey();
+
+            assertEquals("Wrong height", 3, publicKey.getHeight());
+            assertEquals("Wrong period", 0, signature0.getPeriod());
+            assertTrue("Signature must be verified", publicKey.verifySignature(signature0, message, 0));
+            assertFalse("Signature must not be verified for another period", publicKey.verifySignature(signature0, message, 1));
+
+            secretKey.update();
+            secretKey.updateTo(5);
+            assertEquals("Wrong period", 5, secretKey.getPeriod());
+
+            try (
+                ForwardSecureSignature signature5 = secretKey.signMessage(message);
+                ForwardSecureSignature deserializedSignature5 = ForwardSecureSignature.deserialize(signature5.serializeSignature());
+                ForwardSecurePublicKey deserializedPublicKey = ForwardSecurePublicKey.deserialize(publicKey.serializePublicKey());
+                ForwardSecureSecretKey deserializedSecretKey = ForwardSecureSecretKey.deserialize(secretKey.serializeSecretKey())
+            ) {
+                assertTrue("Signature must be verified", deserializedPublicKey.verifySignature(deserializedSignature5, message, 5));
+                assertEquals("Wrong period", 5, deserializedSecretKey.getPeriod());
+            }
+
+            try {
+                secretKey.updateTo(4);
+                fail("Secret key must not be updated to a past period");
+            } catch (SchnorrSignatureException e) {}
+
+            secretKey.updateTo(7);
+            try {
+                secretKey.update();
+                fail("Secret key must not be updated beyond the last period");
+            } catch (SchnorrSignatureException e) {}
+        }
+    }
+
+    @Test
+    public void testGenerateInvalidHeight() {
+        // 300 would be 44 once truncated to a byte
+        for (int height: new int[] { -1, 0, 21, 300 }) {
+            try {
+                ForwardSecureKeyPair.generate(height).close();
+                fail("Key pair must not be generated with height " + height);
+            } catch (SchnorrSignatureException e) {}
+        }
+    }
+}
@@ -1,8 +1,11 @@
 package io.horizen.common.schnorrnative;
 
+import io.horizen.common.librustsidechains.DeserializationException;
 import io.horizen.common.librustsidechains.FieldElement;
 import org.junit.Test;
 
+import java.io.File;
+
 import static org.junit.Assert.*;
 
 public class SchnorrKeyPairTest {
@@ -71,4 +74,212 @@ public class SchnorrKeyPairTest {
             }
         }
     }
-}
+
+    @Test
+    public void testSignVerifyBytesAndFieldElements() throws Exception {
+
+        byte[] message = "Some arbitrary message to be signed".getBytes();
+        byte[] wrongMessage = "Some arbitrary message to be signeD".getBytes();
+
+        try
+        (
+            SchnorrKeyPair keyPair = SchnorrKeyPair.generate();
+            SchnorrSignature bytesSignature = keyPair.signMessage(message);
+            FieldElement fe1 = FieldElement.createRandom();
+            FieldElement fe2 = FieldElement.createRandom()
+        )
+        {
+            assertTrue("Signature must be verified", keyPair.getPublicKey().verifySignature(bytesSignature, message));
+            assertFalse("Signature must not be verified", keyPair.getPublicKey().verifySignature(bytesSignature, wrongMessage));
+
+            FieldElement[] feMessage = {fe1, fe2};
+            FieldElement[] wrongFeMessage = {fe2, fe1};
+
+            try(SchnorrSignature feSignature = keyPair.signMessage(feMessage))
+            {
+                assertTrue("Signature must be verified", keyPair.getPublicKey().verifySignature(feSignature, feMessage));
+                assertFalse("Signature must not be verified", keyPair.getPublicKey().verifySignature(feSignature, wrongFeMessage));
+            }
+        }
+    }
+
+    @Test
+    public void testSignVerifyWithDomainTag() throws Exception {
+
+        byte[] domainTag = "Some protocol".getBytes();
+        byte[] otherDomainTag = "Some other protocol".getBytes();
+
+        try
+        (
+            SchnorrKeyPair keyPair = SchnorrKeyPair.generate();
+            FieldElement message = FieldElement.createRandom();
+            SchnorrSignature signature = keyPair.signMessage(message, domainTag)
+        )
+        {
+            assertTrue("Signature must be verified", keyPair.getPublicKey().verifySignature(signature, message, domainTag));
+            assertFalse("Signature must not be verified", keyPair.getPublicKey().verifySignature(signature, message, otherDomainTag));
+            assertFalse("Signature must not be verified", keyPair.getPublicKey().verifySignature(signature, message));
+        }
+    }
+
+    @Test
+    public void testAdaptorSignature() throws Exception {
+
+        try
+        (
+            SchnorrKeyPair keyPair = SchnorrKeyPair.generate();
+            SchnorrKeyPair adaptor = SchnorrKeyPair.generateAdaptor();
+            SchnorrKeyPair wrongAdaptor = SchnorrKeyPair.generateAdaptor();
+            FieldElement message = FieldElement.createRandom();
+            SchnorrPreSignature preSignature = keyPair.preSignMessage(message, adaptor.getPublicKey())
+        )
+        {
+            assertTrue("Pre-signature must be verified",
+                    keyPair.getPublicKey().verifyPreSignature(preSignature, message, adaptor.getPublicKey()));
+            assertFalse("Pre-signature must not be verified",
+                    keyPair.getPublicKey().verifyPreSignature(preSignature, message, wrongAdaptor.getPublicKey()));
+
+            try
+            (
+                SchnorrSignature signature = preSignature.adapt(adaptor.getSecretKey());
+                SchnorrSecretKey extractedSecret = preSignature.extractSecret(signature, adaptor.getPublicKey())
+            )
+            {
+                assertTrue("Adapted signature must be verified", keyPair.getPublicKey().verifySignature(signature, message));
+                assertEquals("Extracted secret must be the adaptor secret", adaptor.getSecretKey(), extractedSecret);
+            }
+        }
+    }
+
+    @Test
+    public void testCompactSignature() throws Exception {
+
+        int samples = 10;
+        SchnorrKeyPair[] keyPairs = new SchnorrKeyPair[samples];
+        SchnorrPublicKey[] publicKeys = new SchnorrPublicKey[samples];
+        FieldElement[] messages = new FieldElement[samples];
+        SchnorrCompactSignature[] compactSignatures = new SchnorrCompactSignature[samples];
+
+        for (int i = 0; i < samples; i++) {
+            keyPairs[i] = SchnorrKeyPair.generate();
+            publicKeys[i] = keyPairs[i].getPublicKey();
+            messages[i] = FieldElement.createRandom();
+
+            try
+            (
+                SchnorrSignature signature = keyPairs[i].signMessage(messages[i]);
+                SchnorrCompactSignature compactSignature = SchnorrCompactSignature.fromSignature(signature, publicKeys[i]);
+                SchnorrSignature convertedSignature = compactSignature.toSignature(messages[i], publicKeys[i])
+            )
+            {
+                assertTrue("Compact signature must be verified", publicKeys[i].verifyCompactSignature(compactSignature, messages[i]));
+                assertEquals("Converted signature must be equal to the original one", signature, convertedSignature);
+                assertEquals("Equal signatures must have equal hash codes", signature.hashCode(), convertedSignature.hashCode());
+            }
+
+            compactSignatures[i] = keyPairs[i].signMessageCompact(messages[i]);
+        }
+
+        assertTrue("Compact signatures must be batch verified", SchnorrCompactSignature.batchVerify(messages, publicKeys, compactSignatures));
+
+        try (FieldElement wrongMessage = FieldElement.createRandom()) {
+            FieldElement[] wrongMessages = messages.clone();
+            wrongMessages[samples / 2] = wrongMessage;
+            assertFalse("Compact signatures must not be batch verified", SchnorrCompactSignature.batchVerify(wrongMessages, publicKeys, compactSignatures));
+        }
+
+        for (int i = 0; i < samples; i++) {
+            compactSignatures[i].close();
+            messages[i].close();
+            keyPairs[i].close();
+        }
+    }
+
+    @Test
+    public void testEnvelopedSerialization() throws Exception {
+
+        try
+        (
+            SchnorrKeyPair keyPair = SchnorrKeyPair.generate();
+            FieldElement message = FieldElement.createRandom();
+            SchnorrSignature signature = keyPair.signMessage(message)
+        )
+        {
+            byte[] publicKeyEnvelope = keyPair.getPublicKey().serializeEnveloped(false, true);
+            byte[] signatureEnvelope = signature.serializeEnveloped(false);
+
+            try
+            (
+                SchnorrPublicKey publicKey = SchnorrPublicKey.deserializeEnveloped(publicKeyEnvelope);
+                SchnorrSignature deserializedSignature = SchnorrSignature.deserializeEnveloped(signatureEnvelope)
+            )
+            {
+                assertTrue("Signature must be verified", publicKey.verifySignature(deserializedSignature, message));
+            }
+
+            try {
+                SchnorrSignature.deserializeEnveloped(publicKeyEnvelope);
+                fail("Envelope of another object type must be rejected");
+            } catch (DeserializationException e) {
+                assertEquals(DeserializationException.ErrorCode.INVALID_ENVELOPE, e.getErrorCode());
+            }
+
+            publicKeyEnvelope[publicKeyEnvelope.length - 1] ^= 1;
+            try {
+                SchnorrPublicKey.deserializeEnveloped(publicKeyEnvelope);
+                fail("Envelope with invalid checksum must be rejected");
+            } catch (DeserializationException e) {
+                assertEquals(DeserializationException.ErrorCode.INVALID_ENVELOPE, e.getErrorCode());
+            }
+        }
+    }
+
+    @Test
+    public void testFileSerialization() throws Exception {
+
+        File publicKeyFile = File.createTempFile("schnorr_pk", ".bin");
+        File secretKeyFile = File.createTempFile("schnorr_sk", ".bin");
+        File signatureFile = File.createTempFile("schnorr_sig", ".bin");
+
+        try
+        (
+            SchnorrKeyPair keyPair = SchnorrKeyPair.generate();
+            FieldElement message = FieldElement.createRandom();
+            SchnorrSignature signature = keyPair.signMessage(message)
+        )
+        {
+            keyPair.getPublicKey().writeToFile(publicKeyFile.getPath(), true);
+            keyPair.getSecretKey().writeToFile(secretKeyFile.getPath());
+            signature.writeToFile(signatureFile.getPath());
+
+            try
+            (
+                SchnorrPublicKey publicKey = SchnorrPublicKey.readFromFile(publicKeyFile.getPath(), true, true);
+                SchnorrSecretKey secretKey = SchnorrSecretKey.readFromFile(secretKeyFile.getPath());
+                SchnorrSignature deserializedSignature = SchnorrSignature.readFromFile(signatureFile.getPath(), true)
+            )
+            {
+                assertTrue("Signature must be verified", publicKey.verifySignature(deserializedSignature, message));
+                assertArrayEquals(keyPair.getSecretKey().serializeSecretKey(), secretKey.serializeSecretKey());
+            }
+
+            // Overwriting an existing file
+            keyPair.getPublicKey().writeToFile(publicKeyFile.getPath(), false);
+            try (SchnorrPublicKey publicKey = SchnorrPublicKey.readFromFile(publicKeyFile.getPath(), true, false)) {
+                assertTrue("Signature must be verified", publicKey.verifySignature(signature, message));
+            }
+
+            // Not existing file
+            try {
+                SchnorrPublicKey.readFromFile(new File(publicKeyFile.getParentFile(), "not_existing_file").getPath(), true, true);
+                fail("Reading from a not existing file must fail");
+            } catch (DeserializationException e) {
+                assertEquals(DeserializationException.ErrorCode.IO, e.getErrorCode());
+            }
+        } finally {
+            publicKeyFile.delete();
+            secretKeyFile.delete();
+            signatureFile.delete();
+        }
+    }
+}
\ No newline at end of file
@@ -41,4 +41,20 @@ public class SchnorrSecretKeyTest {
             }
         }
     }
+
+    @Test
+    public void testEquals() throws Exception {
+
+        try
+        (
+            SchnorrKeyPair keyPair = SchnorrKeyPair.generate();
+            SchnorrKeyPair otherKeyPair = SchnorrKeyPair.generate();
+            SchnorrSecretKey deserializedSecretKey = SchnorrSecretKey.deserialize(keyPair.getSecretKey().serializeSecretKey())
+        )
+        {
+            assertEquals("Secret keys must be equal", keyPair.getSecretKey(), deserializedSecretKey);
+            assertEquals("Equal secret keys must have equal hash codes", keyPair.getSecretKey().hashCode(), deserializedSecretKey.hashCode());
+            assertNotEquals("Secret keys must be different", keyPair.getSecretKey(), otherKeyPair.getSecretKey());
+        }
+    }
 }
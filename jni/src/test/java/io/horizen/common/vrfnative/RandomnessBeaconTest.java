@@ -0,0 +1,71 @@
+package io.horizen.common.vrfnative;
+
+import io.horizen.common.librustsidechains.FieldElement;
+import org.junit.Test;
+
+import static org.junit.Assert.*;
+
+public class RandomnessBeaconTest {
+
+    @Test
+    public void testEpochs() throws Exception {
+        int epochs = 3;
+        int outputsPerEpoch = 10;
+
+        try
+        (
+            FieldElement initialNonce = FieldElement.createRandom();
+            RandomnessBeacon beacon = RandomnessBeacon.create(initialNonce);
+            RandomnessBeacon otherBeacon = RandomnessBeacon.create(initialNonce)
+        )
+        {
+            for (int epoch = 0; epoch < epochs; epoch++) {
+                assertEquals("Wrong epoch", epoch, beacon.getEpoch());
+
+                for (int i = 0; i < outputsPerEpoch; i++) {
+                    try(FieldElement vrfOutput = FieldElement.createRandom()) {
+                        beacon.absorb(vrfOutput);
+                        // Only the last output differs for the other beacon
+                        if (epoch == epochs - 1 && i == outputsPerEpoch - 1) {
+                            try(FieldElement otherVrfOutput = FieldElement.createRandom()) {
+                                otherBeacon.absorb(otherVrfOutput);
+                            }
+                        } else {
+                            otherBeacon.absorb(vrfOutput);
+                        }
+                    }
+                }
+                assertEquals("Wrong outputs count", outputsPerEpoch, beacon.getOutputsCount());
+
+                // Serialization round trip keeps the whole state
+                try
+                (
+                    RandomnessBeacon deserializedBeacon = RandomnessBeacon.deserialize(beacon.serializeBeacon());
+                    FieldElement rollingNonce = beacon.getRollingNonce();
+                    FieldElement deserializedRollingNonce = deserializedBeacon.getRollingNonce()
+                )
+                {
+                    assertTrue("Deserialized beacon must be valid", deserializedBeacon.isValidBeacon());
+                    assertEquals("Rolling nonces must be equal", rollingNonce, deserializedRollingNonce);
+                    assertEquals("Outputs counts must be equal", beacon.getOutputsCount(), deserializedBeacon.getOutputsCount());
+                }
+
+                try
+                (
+                    FieldElement nonce = beacon.endEpoch();
+                    FieldElement otherNonce = otherBeacon.endEpoch();
+                    FieldElement epochNonce = beacon.getEpochNonce()
+                )
+                {
+                    assertEquals("Returned and stored epoch nonces must be equal", nonce, epochNonce);
+                    if (epoch == epochs - 1) {
+                        assertNotEquals("A different VRF output must change the epoch nonce", nonce, otherNonce);
+                    } else {
+                        assertEquals("Same VRF outputs must give the same epoch nonce", nonce, otherNonce);
+                    }
+                }
+                assertEquals("Outputs count must be reset", 0, beacon.getOutputsCount());
+            }
+        }
+    }
+}
@@ -1,11 +1,16 @@
 package io.horizen.common.vrfnative;
 
+import io.horizen.common.librustsidechains.DeserializationException;
 import io.horizen.common.librustsidechains.FieldElement;
 import org.junit.Test;
 
+import static org.junit.Assert.assertArrayEquals;
+import static org.junit.Assert.assertFalse;
+import static org.junit.Assert.assertNotEquals;
 import static org.junit.Assert.assertNull;
 import static org.junit.Assert.assertTrue;
 import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.fail;
 
 public class VRFKeyPairTest {
 
@@ -85,11 +90,212 @@ public class VRFKeyPairTest {
                     try {
                         keyPair.getPublicKey().proofToHash(proofVRFOutputPair.getVRFProof(), wrongFieldElement);
                         assertTrue("VRF Proof verification must fail", false);
-                    } catch (VRFException ve) {
+                    } catch (VRFInvalidProofException ve) {
                         assertTrue(ve.getMessage().contains("Unable to get vrf output"));
                     }
                 }
             }
         }
     }
-}
+
+    @Test
+    public void testOutputWithLabel() throws Exception {
+        try
+        (
+            VRFKeyPair keyPair = VRFKeyPair.generate();
+            FieldElement message = FieldElement.createRandom();
+            FieldElement labelElement = FieldElement.createFromLong(1);
+            VRFProveResult proveResult = keyPair.prove(message)
+        )
+        {
+            FieldElement[] label = new FieldElement[] { labelElement };
+            VRFProof proof = proveResult.getVRFProof();
+
+            try
+            (
+                FieldElement defaultOutput = proof.outputWithLabel(message, new FieldElement[0]);
+                FieldElement labeledOutput = proof.outputWithLabel(message, label);
+                FieldElement verifiedLabeledOutput = keyPair.getPublicKey().proofToHashWithLabel(proof, message, label)
+            )
+            {
+                assertEquals("Empty label must give the default output", proveResult.getVRFOutput(), defaultOutput);
+                assertNotEquals("Labeled output must differ from the default one", proveResult.getVRFOutput(), labeledOutput);
+                assertEquals("Prover and verifier labeled outputs must be equal", labeledOutput, verifiedLabeledOutput);
+            }
+        }
+    }
+
+    @Test
+    public void testProveWithParams() throws Exception {
+        byte[][] tags = { "Nickel Ni 28".getBytes(), "Silver Ag 47".getBytes() };
+        byte[] personalization = "TestVrfP".getBytes();
+
+        try
+        (
+            VRFKeyPair keyPair = VRFKeyPair.generate();
+            FieldElement message = FieldElement.createRandom();
+            VRFParams defaultParams = VRFParams.getDefault();
+            VRFParams customParams = VRFParams.generate(tags, personalization);
+            VRFProveResult customResult = keyPair.prove(message, customParams);
+            FieldElement customOutput = keyPair.getPublicKey().proofToHash(customResult.getVRFProof(), message, customParams)
+        )
+        {
+            assertEquals("prove() and proofToHash() vrf outputs must be equal", customResult.getVRFOutput(), customOutput);
+
+            try {
+                keyPair.getPublicKey().proofToHash(customResult.getVRFProof(), message, defaultParams);
+                fail("VRF Proof verification with different params must fail");
+            } catch (VRFInvalidProofException ve) {}
+        }
+
+        try {
+            VRFParams.generate(new byte[][] { tags[0], tags[0] }, personalization);
+            fail("Duplicated tags must be rejected");
+        } catch (VRFException ve) {}
+
+        // Small window configuration, taking a tag for each of its windows
+        byte[][] smallWindowTags = {
+            "Nickel Ni 28".getBytes(), "Silver Ag 47".getBytes(), "Copper Cu 29".getBytes(), "Zinc Zn 30".getBytes()
+        };
+        try
+        (
+            VRFKeyPair keyPair = VRFKeyPair.generate();
+            FieldElement message = FieldElement.createRandom();
+            VRFParams smallWindowParams = VRFParams.generate(smallWindowTags, personalization, VRFParams.SMALL_WINDOW_SIZE);
+            VRFProveResult smallWindowResult = keyPair.prove(message, smallWindowParams);
+            FieldElement smallWindowOutput = keyPair.getPublicKey().proofToHash(smallWindowResult.getVRFProof(), message, smallWindowParams)
+        )
+        {
+            assertEquals("prove() and proofToHash() vrf outputs must be equal", smallWindowResult.getVRFOutput(), smallWindowOutput);
+        }
+
+        try {
+            VRFParams.generate(tags, personalization, VRFParams.SMALL_WINDOW_SIZE);
+            fail("A tag for each window must be required");
+        } catch (IllegalArgumentException e) {}
+    }
+
+    @Test
+    public void testProveDeterministic() throws Exception {
+        try
+        (
+            VRFKeyPair keyPair = VRFKeyPair.generate();
+            FieldElement message = FieldElement.createRandom();
+            VRFProveResult result = keyPair.proveDeterministic(message);
+            VRFProveResult resultDup = keyPair.proveDeterministic(message);
+            FieldElement output = keyPair.getPublicKey().proofToHash(result.getVRFProof(), message)
+        )
+        {
+            assertArrayEquals("Same inputs must give the same proof",
+                    result.getVRFProof().serializeProof(), resultDup.getVRFProof().serializeProof());
+            assertEquals("prove() and proofToHash() vrf outputs must be equal", result.getVRFOutput(), output);
+        }
+    }
+
+    @Test
+    public void testProveMultipleMessages() throws Exception {
+        byte[] bytesMessage = "epoch nonce and slot number".getBytes();
+
+        try
+        (
+            VRFKeyPair keyPair = VRFKeyPair.generate();
+            FieldElement fe1 = FieldElement.createRandom();
+            FieldElement fe2 = FieldElement.createRandom();
+            VRFProveResult fesResult = keyPair.prove(new FieldElement[] { fe1, fe2 });
+            FieldElement fesOutput = keyPair.getPublicKey().proofToHash(fesResult.getVRFProof(), new FieldElement[] { fe1, fe2 });
+            VRFProveResult bytesResult = keyPair.prove(bytesMessage);
+            FieldElement bytesOutput = keyPair.getPublicKey().proofToHash(bytesResult.getVRFProof(), bytesMessage)
+        )
+        {
+            assertEquals("prove() and proofToHash() vrf outputs must be equal", fesResult.getVRFOutput(), fesOutput);
+            assertEquals("prove() and proofToHash() vrf outputs must be equal", bytesResult.getVRFOutput(), bytesOutput);
+
+            try {
+                keyPair.getPublicKey().proofToHash(fesResult.getVRFProof(), new FieldElement[] { fe2, fe1 });
+                fail("VRF Proof verification must fail");
+            } catch (VRFInvalidProofException ve) {}
+
+            try {
+                keyPair.getPublicKey().proofToHash(bytesResult.getVRFProof(), "another message".getBytes());
+                fail("VRF Proof verification must fail");
+            } catch (VRFInvalidProofException ve) {}
+        }
+    }
+
+    @Test
+    public void testBatchProofToHash() throws Exception {
+        int samples = 10;
+        VRFKeyPair[] keyPairs = new VRFKeyPair[samples];
+        VRFPublicKey[] publicKeys = new VRFPublicKey[samples];
+        VRFProveResult[] proveResults = new VRFProveResult[samples];
+        VRFProof[] proofs = new VRFProof[samples];
+        FieldElement[] messages = new FieldElement[samples];
+
+        for (int i = 0; i < samples; i++) {
+            keyPairs[i] = VRFKeyPair.generate();
+            publicKeys[i] = keyPairs[i].getPublicKey();
+            messages[i] = FieldElement.createRandom();
+            proveResults[i] = keyPairs[i].prove(messages[i]);
+            proofs[i] = proveResults[i].getVRFProof();
+        }
+
+        try (VRFBatchProofToHashResult result = VRFPublicKey.batchProofToHash(publicKeys, proofs, messages)) {
+            assertTrue("VRF proofs must be verified", result.isValid());
+            for (int i = 0; i < samples; i++)
+                assertEquals("prove() and batchProofToHash() vrf outputs must be equal", proveResults[i].getVRFOutput(), result.getVRFOutputs()[i]);
+        }
+
+        try (FieldElement wrongMessage = FieldElement.createRandom()) {
+            FieldElement[] wrongMessages = messages.clone();
+            wrongMessages[samples / 2] = wrongMessage;
+
+            try (VRFBatchProofToHashResult result = VRFPublicKey.batchProofToHash(publicKeys, proofs, wrongMessages)) {
+                assertFalse("VRF proofs must not be verified", result.isValid());
+                assertArrayEquals(new int[] {samples / 2}, result.getFailingIndices());
+            }
+        }
+
+        for (int i = 0; i < samples; i++) {
+            proveResults[i].close();
+            messages[i].close();
+            keyPairs[i].close();
+        }
+    }
+
+    @Test
+    public void testTextEncoding() throws Exception {
+
+        try
+        (
+            VRFKeyPair keyPair = VRFKeyPair.generate();
+            FieldElement message = FieldElement.createRandom();
+            VRFProveResult proveResult = keyPair.prove(message)
+        )
+        {
+            VRFProof proof = proveResult.getVRFProof();
+            String proofJson = proof.toJson();
+            assertTrue(proofJson.startsWith("{\"gamma\":\""));
+
+            try
+            (
+                VRFPublicKey publicKey = VRFPublicKey.fromHex(keyPair.getPublicKey().toHex());
+                VRFProof proofFromJson = VRFProof.fromJson(proofJson);
+                VRFProof proofFromHex = VRFProof.fromHex(proof.toHex());
+                FieldElement vrfOutputFromJson = publicKey.proofToHash(proofFromJson, message);
+                FieldElement vrfOutputFromHex = publicKey.proofToHash(proofFromHex, message)
+            )
+            {
+                assertEquals(proveResult.getVRFOutput(), vrfOutputFromJson);
+                assertEquals(proveResult.getVRFOutput(), vrfOutputFromHex);
+                assertEquals(proof.toHex(), proofFromJson.toHex());
+            }
+
+            try {
+                VRFProof.fromJson("{\"gamma\":\"zz\"}");
+                fail("Invalid JSON must be rejected");
+            } catch (DeserializationException e) {
+                assertEquals(DeserializationException.ErrorCode.MALFORMED_DATA, e.getErrorCode());
+            }
+        }
+    }
+}
\ No newline at end of file
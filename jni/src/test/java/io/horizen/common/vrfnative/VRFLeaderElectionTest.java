@@ -0,0 +1,50 @@
+package io.horizen.common.vrfnative;
+
+import io.horizen.common.librustsidechains.FieldElement;
+import org.junit.Test;
+
+import static org.junit.Assert.*;
+
+public class VRFLeaderElectionTest {
+
+    @Test
+    public void testThreshold() throws Exception {
+        // {stake, totalStake, activeSlotCoeffNum, activeSlotCoeffDen, threshold}
+        long[][] testVectors = {
+            {1, 1, 1, 2, 140737488355321L},
+            {1, 2, 1, 2, 82442111944221L},
+            {1, 10, 1, 20, 1440081400974L},
+            {3, 4, 1, 20, 10622696671062L},
+            {0, 5, 1, 20, 0},
+            {1000, 1000000, 1, 20, 14437408570L},
+        };
+
+        for (long[] v: testVectors) {
+            assertEquals("Threshold must match the expected one", v[4], VRFLeaderElection.getThreshold(v[0], v[1], v[2], v[3]));
+        }
+
+        try {
+            VRFLeaderElection.getThreshold(2, 1, 1, 20);
+            fail("Stake greater than total stake must be rejected");
+        } catch (IllegalArgumentException e) {}
+
+        try {
+            VRFLeaderElection.getThreshold(1, 1, 20, 20);
+            fail("Active slot coefficient equal to 1 must be rejected");
+        } catch (IllegalArgumentException e) {}
+    }
+
+    @Test
+    public void testIsSlotLeader() throws Exception {
+        try
+        (
+            FieldElement lowest = FieldElement.createFromLong(0);
+            FieldElement randomOutput = FieldElement.createRandom()
+        )
+        {
+            assertTrue("Lowest VRF output must be elected", VRFLeaderElection.isSlotLeader(lowest, 1, 100, 1, 20));
+            assertFalse("No stake must never be elected", VRFLeaderElection.isSlotLeader(lowest, 0, 100, 1, 20));
+            assertFalse("No stake must never be elected", VRFLeaderElection.isSlotLeader(randomOutput, 0, 100, 1, 20));
+        }
+    }
+}